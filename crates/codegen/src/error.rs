@@ -91,6 +91,34 @@ pub enum Error {
         entry_id: String,
         function_id: String,
     },
+
+    #[error("Entry {entry_id} calls function '{function_id}' with invalid arguments: {source}")]
+    InvalidFunctionCall {
+        entry_id: String,
+        function_id: String,
+        source: fluent_static_function::schema::FunctionSchemaError,
+    },
+
+    #[error("Unisolated messages must be set before adding resources")]
+    UnisolatedMessagesAfterResources,
+
+    #[error("Unisolated message '{0}' is not defined in the bundle")]
+    UndefinedUnisolatedMessage(String),
+
+    #[error("Message {message_id} method '{method}' clashes with method of {other}")]
+    MethodNameCollision {
+        message_id: String,
+        method: String,
+        other: String,
+    },
+
+    #[error("Message {message_id} uses elements [{actual}] in language {language}, expected [{expected}]")]
+    MismatchedElements {
+        message_id: String,
+        language: String,
+        expected: String,
+        actual: String,
+    },
 }
 
 #[derive(Debug)]
@@ -1,8 +1,10 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, str::FromStr};
 
-use fluent_static_function::FluentFunctionDescriptor;
+use fluent_static_function::{builtins, FluentFunctionDescriptor};
+pub use fluent_static_function::{FunctionPack, FunctionSchema};
+use fluent_static_value::{Number, Value};
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{parse_str, Ident, Path, PathArguments};
 
 pub trait FunctionCallGenerator {
@@ -12,10 +14,70 @@ pub trait FunctionCallGenerator {
         positional_args: &Ident,
         named_args: &Ident,
     ) -> Option<TokenStream>;
+
+    /// Arguments accepted by the function, calls are validated against it during code generation
+    fn schema(&self, _function_name: &str) -> Option<FunctionSchema> {
+        None
+    }
+
+    /// Statements validating the call at compile time, for functions which schema is known
+    /// only to the compiler, e.g. function packs referenced by `message_bundle` macro
+    fn generate_check(
+        &self,
+        _function_name: &str,
+        _arguments: &FunctionCallArgs,
+    ) -> Option<TokenStream> {
+        None
+    }
+}
+
+/// Arguments of a function call in Fluent resource, option values are known only for literals
+pub struct FunctionCallArgs {
+    pub positional: usize,
+    pub named: Vec<(String, Option<Value<'static>>)>,
+}
+
+impl FunctionCallArgs {
+    /// Named options with `fluent_static::function::schema::OptionLiteral` values,
+    /// see `FunctionSchema::accepts_option_value`
+    pub fn option_literals(&self) -> Vec<(&str, TokenStream)> {
+        self.named
+            .iter()
+            .map(|(name, value)| {
+                let number = |value: &Number, string: bool| {
+                    let integer = match usize::try_from(value) {
+                        Ok(n) => quote! { Some(#n) },
+                        Err(_) => quote! { None },
+                    };
+                    quote! { Number { integer: #integer, string: #string } }
+                };
+                let literal = match value {
+                    Some(Value::Number { value, .. }) => number(value, false),
+                    Some(Value::String(s)) => match Number::from_str(s) {
+                        Ok(value) => number(&value, true),
+                        Err(_) => quote! { String },
+                    },
+                    _ => quote! { Unknown },
+                };
+                (
+                    name.as_str(),
+                    quote! { ::fluent_static::function::schema::OptionLiteral::#literal },
+                )
+            })
+            .collect()
+    }
 }
 
 pub struct FunctionRegistry {
-    fns: HashMap<String, TokenStream>,
+    fns: HashMap<String, RegisteredFunction>,
+}
+
+struct RegisteredFunction {
+    path: TokenStream,
+    schema: FunctionSchema,
+    requires_context: bool,
+    /// Signature of pack functions is not known, see `fluent_static_function::call`
+    resolve_signature: bool,
 }
 
 impl FunctionRegistry {
@@ -24,24 +86,48 @@ impl FunctionRegistry {
         function_id: &str,
         function_descriptor: impl FluentFunctionDescriptor,
     ) -> &Self {
-        self.fns
-            .insert(function_id.to_string(), Self::fqn(function_descriptor));
+        let schema = function_descriptor.schema();
+        let requires_context = function_descriptor.requires_context();
+        self.fns.insert(
+            function_id.to_string(),
+            RegisteredFunction {
+                path: Self::fqn(function_descriptor),
+                schema,
+                requires_context,
+                resolve_signature: false,
+            },
+        );
+        self
+    }
+
+    /// Registers all functions of the pack, functions are called by their `{pack.path}::{name}` paths.
+    /// Functions already defined by built-ins or previously registered packs are not replaced,
+    /// i.e. the first pack defining a function takes precedence
+    pub fn register_pack(&mut self, pack: &FunctionPack) -> &Self {
+        let pack_path: Path = parse_str(pack.path).expect("valid function pack path");
+        for function in pack.functions {
+            let name = format_ident!("{}", function.name);
+            self.fns
+                .entry(function.id.to_string())
+                .or_insert(RegisteredFunction {
+                    path: quote! { #pack_path::#name },
+                    schema: function.schema,
+                    requires_context: false,
+                    resolve_signature: true,
+                });
+        }
         self
     }
 
     fn fqn(function_descriptor: impl FluentFunctionDescriptor) -> TokenStream {
-        let path = function_descriptor.type_name();
-
-        // HACK fluent_static reexports fluent_static_function,
-        // need to patch function name
-        // to avoid fluent_static_function dependency
-        let path = if path.starts_with("fluent_static_function::builtins") {
-            path.replace("fluent_static_function", "fluent_static::function")
-        } else {
-            path.to_string()
-        };
-
-        let mut path: Path = parse_str(&path).expect("vaild function type name");
+        let type_name = function_descriptor.type_name();
+        // generated code can reference built-in functions only by `fluent_static::function` paths
+        if type_name.starts_with("fluent_static_function::") {
+            panic!(
+                "Function '{type_name}' can't be registered by type name, built-in functions are registered by default, see FunctionRegistry::register_pack"
+            );
+        }
+        let mut path: Path = parse_str(type_name).expect("vaild function type name");
         if let Some(name) = path.segments.last_mut() {
             name.arguments = PathArguments::None
         }
@@ -55,7 +141,7 @@ impl Default for FunctionRegistry {
         let fns = HashMap::new();
         let mut result = Self { fns };
 
-        result.register("NUMBER", fluent_static_function::builtins::number);
+        result.register_pack(&builtins::FUNCTIONS);
 
         result
     }
@@ -68,12 +154,25 @@ impl FunctionCallGenerator for FunctionRegistry {
         positional_args: &Ident,
         named_args: &Ident,
     ) -> Option<TokenStream> {
-        if let Some(fn_ident) = self.fns.get(function_name) {
-            Some(quote! {
-                #fn_ident(&#positional_args, &#named_args)
-            })
-        } else {
-            None
-        }
+        self.fns.get(function_name).map(|function| {
+            let path = &function.path;
+            if function.resolve_signature {
+                quote! {
+                    ::fluent_static::function::call(#path, &self._function_context_(), &#positional_args, &#named_args)
+                }
+            } else if function.requires_context {
+                quote! {
+                    #path(&self._function_context_(), &#positional_args, &#named_args)
+                }
+            } else {
+                quote! {
+                    #path(&#positional_args, &#named_args)
+                }
+            }
+        })
+    }
+
+    fn schema(&self, function_name: &str) -> Option<FunctionSchema> {
+        self.fns.get(function_name).map(|function| function.schema)
     }
 }
@@ -1,10 +1,14 @@
 use std::{
     collections::{BTreeMap, BTreeSet},
     rc::Rc,
+    str::FromStr,
 };
 
 use convert_case::{Case, Casing};
-use fluent_static_value::{Number, Value};
+use fluent_static_value::{
+    bidi::{char_direction, language_direction, TextDirection},
+    Number, Value,
+};
 use fluent_syntax::ast;
 use intl_pluralrules::PluralCategory;
 use proc_macro2::{Ident, Literal, TokenStream as TokenStream2};
@@ -13,8 +17,8 @@ use unic_langid::LanguageIdentifier;
 
 use crate::{
     ast::{Node, Visitor},
-    function::FunctionCallGenerator,
-    types::{FluentId, FluentMessage, FluentVariable, PublicFluentId},
+    function::{FunctionCallArgs, FunctionCallGenerator, FunctionSchema},
+    types::{FluentId, FluentMessage, FluentVariable, PublicFluentId, VariableKind},
     Error,
 };
 
@@ -23,16 +27,44 @@ enum ExpressionContext {
     Inline,
     Selector {
         plural_rules: bool,
+        kind: VariableKind,
     },
     TermArguments {
         term: FluentMessage,
     },
     FunctionCall {
+        function_id: String,
         positional_args: Ident,
         named_args: Ident,
     },
 }
 
+/// Literal value of a function option
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OptionLiteral {
+    String(String),
+    Number(String),
+}
+
+impl OptionLiteral {
+    pub fn to_value_tokens(&self) -> Result<TokenStream2, Error> {
+        match self {
+            OptionLiteral::String(value) => {
+                let lit = Literal::string(value);
+                Ok(quote! {
+                    ::fluent_static::value::Value::from(#lit)
+                })
+            }
+            OptionLiteral::Number(value) => {
+                let number = mk_number(value)?;
+                Ok(quote! {
+                    ::fluent_static::value::Value::Number { value: #number, format: None }
+                })
+            }
+        }
+    }
+}
+
 pub struct LanguageBuilder {
     pending_fns: Vec<FluentMessage>,
     expression_contexts: Vec<ExpressionContext>,
@@ -43,6 +75,21 @@ pub struct LanguageBuilder {
     pub prefix: String,
     pub registered_fns: BTreeMap<FluentId, FluentMessage>,
     pub registered_message_fns: BTreeMap<PublicFluentId, FluentMessage>,
+    /// Currency codes used as `NUMBER` function `currency` option literals
+    pub currencies: BTreeSet<String>,
+    /// Unit identifiers used as `NUMBER` function `unit` option literals
+    pub units: BTreeSet<String>,
+    /// Option sets of `NUMBER` function calls with literal options only
+    pub number_formats: BTreeSet<Vec<(String, OptionLiteral)>>,
+    /// Whether messages call `RELATIVE_TIME` function
+    pub relative_time: bool,
+    /// Whether `NUMBER` function is called with `notation: "compact"` option
+    pub compact_notation: bool,
+    /// IDs of messages which placeables are never bidi-isolated
+    pub unisolated_messages: BTreeSet<String>,
+    direction: TextDirection,
+    /// Direction of the literal text around the placeable being visited
+    surrounding_direction: Option<TextDirection>,
 }
 
 impl LanguageBuilder {
@@ -58,7 +105,65 @@ impl LanguageBuilder {
             registered_fns: BTreeMap::new(),
             registered_message_fns: BTreeMap::new(),
             expression_contexts: Vec::new(),
+            currencies: BTreeSet::new(),
+            units: BTreeSet::new(),
+            number_formats: BTreeSet::new(),
+            relative_time: false,
+            compact_notation: false,
+            unisolated_messages: BTreeSet::new(),
+            direction: language_direction(&language_id.to_string()),
+            surrounding_direction: None,
+        }
+    }
+
+    fn collect_number_options<S: ToString>(&mut self, arguments: &ast::CallArguments<S>) {
+        let mut options = Vec::new();
+        for arg in arguments.named.iter() {
+            let name = arg.name.name.to_string();
+            match &arg.value {
+                ast::InlineExpression::StringLiteral { value } => {
+                    match name.as_str() {
+                        "currency" => {
+                            self.currencies.insert(value.to_string());
+                        }
+                        "unit" => {
+                            self.units.insert(value.to_string());
+                        }
+                        "notation" => self.compact_notation |= value.to_string() == "compact",
+                        _ => {}
+                    }
+                    options.push((name, OptionLiteral::String(value.to_string())));
+                }
+                ast::InlineExpression::NumberLiteral { value } => {
+                    options.push((name, OptionLiteral::Number(value.to_string())));
+                }
+                _ => return,
+            }
+        }
+        self.number_formats.insert(options);
+    }
+
+    fn check_function_call(
+        &self,
+        function_id: &str,
+        schema: &FunctionSchema,
+        arguments: &FunctionCallArgs,
+    ) -> Result<(), Error> {
+        let entry_id = self.current_context()?.id().to_string();
+        let invalid_call = |source| Error::InvalidFunctionCall {
+            entry_id: entry_id.clone(),
+            function_id: function_id.to_string(),
+            source,
+        };
+        schema
+            .check_arity(arguments.positional)
+            .map_err(invalid_call)?;
+        for (name, value) in arguments.named.iter() {
+            schema
+                .check_option(name, value.as_ref())
+                .map_err(invalid_call)?;
         }
+        Ok(())
     }
 
     fn push_message<S: ToString>(&mut self, message: &ast::Message<S>) {
@@ -96,11 +201,11 @@ impl LanguageBuilder {
 
         let fn_generics = if var_idents.is_empty() {
             quote! {
-                <W: ::std::fmt::Write>
+                <W: ::fluent_static::MessageWrite>
             }
         } else {
             quote! {
-                <'a, W: ::std::fmt::Write>
+                <'a, W: ::fluent_static::MessageWrite>
             }
         };
 
@@ -135,18 +240,48 @@ impl LanguageBuilder {
         }
     }
 
-    fn append_var<S: ToString>(&mut self, id: &ast::Identifier<S>) -> Result<Ident, Error> {
+    fn append_var<S: ToString>(
+        &mut self,
+        id: &ast::Identifier<S>,
+        kind: VariableKind,
+    ) -> Result<Ident, Error> {
         if let Some(item) = self.pending_fns.last_mut() {
             let var_name = id.name.to_string();
             let var_ident = format_ident!("{}", var_name.to_case(Case::Snake));
             let var = FluentVariable::new(var_name, var_ident.clone());
-            item.add_var(var);
+            item.add_var(var, kind);
             Ok(var_ident)
         } else {
             Err(Error::UnexpectedContextState)
         }
     }
 
+    /// Bundle method writing placeables of the current message and its extra arguments
+    fn write_fn(&self) -> Result<(TokenStream2, TokenStream2), Error> {
+        let id = self.current_context()?.id().to_string();
+        let message_id = id.split('.').next().unwrap_or_default();
+        if self.unisolated_messages.contains(message_id) {
+            Ok((
+                quote! { self._write_with_ },
+                quote! { , ::fluent_static::value::bidi::Isolation::Never, ::fluent_static::value::bidi::TextDirection::Ltr },
+            ))
+        } else {
+            match self.surrounding_direction {
+                Some(direction) if direction != self.direction => {
+                    let direction = match direction {
+                        TextDirection::Ltr => quote! { Ltr },
+                        TextDirection::Rtl => quote! { Rtl },
+                    };
+                    Ok((
+                        quote! { self._write_in_ },
+                        quote! { , ::fluent_static::value::bidi::TextDirection::#direction },
+                    ))
+                }
+                _ => Ok((quote! { self._write_ }, quote! {})),
+            }
+        }
+    }
+
     fn current_context(&self) -> Result<&FluentMessage, Error> {
         self.pending_fns.last().ok_or(Error::UnexpectedContextState)
     }
@@ -219,6 +354,8 @@ impl<S: ToString> Visitor<S> for LanguageBuilder {
 
     fn visit_message(&mut self, message: &ast::Message<S>) -> Self::Output {
         self.push_message(message);
+        self.current_context()?
+            .set_has_value(message.value.is_some());
         let body = message
             .value
             .as_ref()
@@ -258,21 +395,67 @@ impl<S: ToString> Visitor<S> for LanguageBuilder {
     }
 
     fn visit_pattern(&mut self, pattern: &ast::Pattern<S>) -> Self::Output {
-        let elements: Vec<TokenStream2> = pattern
-            .elements
-            .iter()
-            .map(|element| element.accept(self))
-            .collect::<Result<Vec<TokenStream2>, Error>>()?;
+        let mut parts = Vec::new();
+        for element in pattern.elements.iter() {
+            match element {
+                ast::PatternElement::TextElement { value } => {
+                    parts.extend(split_text(&value.to_string()).into_iter().map(Ok))
+                }
+                placeable => parts.push(Err(placeable)),
+            }
+        }
+
+        let parts = match_elements(parts);
+        // placeables of nested patterns, e.g. selector variants, inherit the surrounding
+        // direction of the enclosing placeable
+        let inherited_direction = self.surrounding_direction;
+        let text_direction = |part: &Result<TextPart, _>, rev: bool| match part {
+            Ok(TextPart::Text(text)) if rev => text.chars().rev().find_map(char_direction),
+            Ok(TextPart::Text(text)) => text.chars().find_map(char_direction),
+            _ => None,
+        };
+        let mut elements = Vec::new();
+        for (index, part) in parts.iter().enumerate() {
+            let element = match part {
+                Ok(TextPart::Text(text)) => text_code(text),
+                Ok(TextPart::Start(name)) => {
+                    self.current_context()?.add_element(name);
+                    let name = Literal::string(name);
+                    quote! {
+                        out.start_element(#name)?;
+                    }
+                }
+                Ok(TextPart::End(name)) => {
+                    let name = Literal::string(name);
+                    quote! {
+                        out.end_element(#name)?;
+                    }
+                }
+                Err(placeable) => {
+                    self.surrounding_direction = parts[..index]
+                        .iter()
+                        .rev()
+                        .find_map(|part| text_direction(part, true))
+                        .or(inherited_direction)
+                        .or_else(|| {
+                            parts[index + 1..]
+                                .iter()
+                                .find_map(|part| text_direction(part, false))
+                        });
+                    let element = placeable.accept(self);
+                    self.surrounding_direction = inherited_direction;
+                    element?
+                }
+            };
+            elements.push(element);
+        }
         Ok(quote! {
             #(#elements)*
         })
     }
 
     fn visit_text_element(&mut self, value: &S) -> Self::Output {
-        let text = Literal::string(value.to_string().as_str());
-        Ok(quote! {
-            out.write_str(#text)?;
-        })
+        Ok(text_code(&value.to_string()))
     }
 
     fn visit_attribute(&mut self, attribute: &ast::Attribute<S>) -> Self::Output {
@@ -299,18 +482,18 @@ impl<S: ToString> Visitor<S> for LanguageBuilder {
                     if get_plural_category(variant_key).is_some() {
                         let category_ident = format_ident!("{}", &name.to_uppercase());
                         quote! {
-                           (Some(::std::borrow::Cow::Borrowed(#lit)), _, _) | (_, _, Some(::fluent_static::intl_pluralrules::PluralCategory::#category_ident))
+                           (Some(#lit), _, _) | (_, _, Some(::fluent_static::intl_pluralrules::PluralCategory::#category_ident))
                         }
                     } else {
                         quote! {
-                            (Some(::std::borrow::Cow::Borrowed(#lit)), None, None)
+                            (Some(#lit), None, None)
                         }
                     }
                 }
                 ast::VariantKey::NumberLiteral { value } => {
                     let number = mk_number(value)?;
                     quote! {
-                        (None, Some(n), _) if n == #number
+                        (None, Some(n), _) if n.matches_key(&#number)
                     }
                 }
             }
@@ -462,7 +645,31 @@ impl<S: ToString> Visitor<S> for LanguageBuilder {
             });
         };
 
+        let call_args = function_call_args(arguments);
+        if let Some(schema) = self.fn_call_generator.schema(&function_id) {
+            self.check_function_call(&function_id, &schema, &call_args)?;
+        }
+        let fn_call = match self
+            .fn_call_generator
+            .generate_check(&function_id, &call_args)
+        {
+            Some(check) => quote! {
+                {
+                    #check
+                    #fn_call
+                }
+            },
+            None => fn_call,
+        };
+
+        match function_id.as_str() {
+            "NUMBER" => self.collect_number_options(arguments),
+            "RELATIVE_TIME" => self.relative_time = true,
+            _ => {}
+        }
+
         self.enter_expr_context(ExpressionContext::FunctionCall {
+            function_id,
             positional_args,
             named_args,
         });
@@ -470,19 +677,26 @@ impl<S: ToString> Visitor<S> for LanguageBuilder {
         let args = arguments.accept(self)?;
         self.leave_expr_context()?;
 
+        let (write_fn, write_args) = self.write_fn()?;
         match self.current_expr_context() {
             ExpressionContext::Inline => Ok(quote! {
                 {
                     #args
-                    self._write_(&#fn_call, out)?;
+                    #write_fn(&#fn_call, out #write_args)?;
                 };
             }),
-            ExpressionContext::Selector { plural_rules } => {
+            ExpressionContext::Selector { plural_rules, .. } => {
                 let has_plural_rules = *plural_rules;
+                // number format is only used to select plural category
+                let format_binding = if has_plural_rules {
+                    quote! { format }
+                } else {
+                    quote! { .. }
+                };
                 let number_expr = if has_plural_rules {
                     quote! {
                         {
-                            let plural_category = self.language.plural_rules_cardinal().select(n.as_f64()).ok();
+                            let plural_category = self.language.plural_rules_cardinal().select(n.formatted_plural_operands(format.as_ref())).ok();
                             (None, Some(n), plural_category)
                         }
                     }
@@ -499,7 +713,9 @@ impl<S: ToString> Visitor<S> for LanguageBuilder {
 
                         match fn_result {
                             ::fluent_static::value::Value::String(s) => (Some(s), None, None),
-                            ::fluent_static::value::Value::Number { value: n, .. } => #number_expr,
+                            ::fluent_static::value::Value::Number { value: n, #format_binding } => #number_expr,
+                            ::fluent_static::value::Value::Bool(b) => (Some(::std::borrow::Cow::Borrowed(if b { "true" } else { "false" })), None, None),
+                            ::fluent_static::value::Value::Custom(c) => (c.selector_key().map(|key| ::std::borrow::Cow::Owned(key.into_owned())), None, None),
                             _ => (None, None, None)
                         }
                     }
@@ -638,18 +854,26 @@ impl<S: ToString> Visitor<S> for LanguageBuilder {
     fn visit_variable_reference(&mut self, id: &ast::Identifier<S>) -> Self::Output {
         match self.current_expr_context() {
             ExpressionContext::Inline => {
-                let var_ident = self.append_var(id)?;
+                let var_ident = self.append_var(id, VariableKind::Any)?;
+                let (write_fn, write_args) = self.write_fn()?;
                 Ok(quote! {
-                    self._write_(&#var_ident, out)?;
+                    #write_fn(&#var_ident, out #write_args)?;
                 })
             }
-            ExpressionContext::Selector { plural_rules } => {
+            ExpressionContext::Selector { plural_rules, kind } => {
                 let has_plural_rules = *plural_rules;
-                let var_ident = self.append_var(id)?;
+                let kind = *kind;
+                let var_ident = self.append_var(id, kind)?;
+                // number format is only used to select plural category
+                let format_binding = if has_plural_rules {
+                    quote! { format }
+                } else {
+                    quote! { .. }
+                };
                 let number_expr = if has_plural_rules {
                     quote! {
                         {
-                            let plural_category = self.language.plural_rules_cardinal().select(n.as_f64()).ok();
+                            let plural_category = self.language.plural_rules_cardinal().select(n.formatted_plural_operands(format.as_ref())).ok();
                             (None, Some(n.clone()), plural_category)
                         }
                     }
@@ -662,7 +886,9 @@ impl<S: ToString> Visitor<S> for LanguageBuilder {
                     {
                         match &#var_ident {
                             ::fluent_static::value::Value::String(s) => (Some(s.clone()), None, None),
-                            ::fluent_static::value::Value::Number { value: n, .. } => #number_expr,
+                            ::fluent_static::value::Value::Number { value: n, #format_binding } => #number_expr,
+                            ::fluent_static::value::Value::Bool(b) => (Some(::std::borrow::Cow::Borrowed(if *b { "true" } else { "false" })), None, None),
+                            ::fluent_static::value::Value::Custom(c) => (c.selector_key(), None, None),
                             _ => (None, None, None)
                         }
                     }
@@ -672,8 +898,13 @@ impl<S: ToString> Visitor<S> for LanguageBuilder {
                 feature: "Usage of variable reference as a term argument".to_string(),
                 id: self.current_context()?.id().to_string(),
             }),
-            ExpressionContext::FunctionCall { .. } => {
-                let var_ident = self.append_var(id)?;
+            ExpressionContext::FunctionCall { function_id, .. } => {
+                let kind = if function_id == "NUMBER" {
+                    VariableKind::Number
+                } else {
+                    VariableKind::Any
+                };
+                let var_ident = self.append_var(id, kind)?;
                 // TODO should value be cloned? just in case if it already used
                 Ok(quote! {
                     #var_ident
@@ -708,8 +939,24 @@ impl<S: ToString> Visitor<S> for LanguageBuilder {
                 .iter()
                 .find(|variant| get_plural_category(&variant.key).is_some())
                 .is_some();
-
-            self.enter_expr_context(ExpressionContext::Selector { plural_rules });
+            // `other` is commonly used as a default key of string selectors too,
+            // so `other`-only selectors carry no type information
+            let kind = if variants.iter().any(|variant| match &variant.key {
+                ast::VariantKey::NumberLiteral { .. } => true,
+                key => get_plural_category(key)
+                    .is_some_and(|category| category != PluralCategory::OTHER),
+            }) {
+                VariableKind::Number
+            } else if variants
+                .iter()
+                .any(|variant| get_plural_category(&variant.key).is_none())
+            {
+                VariableKind::String
+            } else {
+                VariableKind::Any
+            };
+
+            self.enter_expr_context(ExpressionContext::Selector { plural_rules, kind });
             let selector_expr = selector.accept(self)?;
             self.leave_expr_context()?;
             let selector_variants = variants
@@ -717,11 +964,18 @@ impl<S: ToString> Visitor<S> for LanguageBuilder {
                 .map(|variant| variant.accept(self))
                 .collect::<Result<Vec<TokenStream2>, Error>>()?;
 
-            //as (Option<&str>, Option<::fluent_static::value::Number>, Option<::fluent_static::intl_pluralrules::PluralCategory>)
-
+            // selector key is matched as `&str`, so owned keys (e.g. returned by functions
+            // or custom values) are matched the same way as borrowed ones
             Ok(quote! {
-                match #selector_expr {
-                    #(#selector_variants),*
+                {
+                    let _selector_: (
+                        ::std::option::Option<::std::borrow::Cow<'_, str>>,
+                        ::std::option::Option<::fluent_static::value::Number>,
+                        ::std::option::Option<::fluent_static::intl_pluralrules::PluralCategory>,
+                    ) = #selector_expr;
+                    match (_selector_.0.as_deref(), _selector_.1, _selector_.2) {
+                        #(#selector_variants),*
+                    }
                 }
             })
         }
@@ -744,6 +998,109 @@ fn get_plural_category<S: ToString>(key: &ast::VariantKey<S>) -> Option<PluralCa
     }
 }
 
+fn text_code(text: &str) -> TokenStream2 {
+    let text = Literal::string(text);
+    quote! {
+        out.write_str(#text)?;
+    }
+}
+
+/// Piece of pattern text, rich-text element tags are `<name>` and `</name>`
+#[derive(Debug, Clone, PartialEq)]
+enum TextPart {
+    Text(String),
+    Start(String),
+    End(String),
+}
+
+fn split_text(text: &str) -> Vec<TextPart> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut rest = text;
+    while let Some(idx) = rest.find('<') {
+        let offset = text.len() - rest.len() + idx;
+        match parse_tag(&rest[idx..]) {
+            Some((part, len)) => {
+                if offset > start {
+                    parts.push(TextPart::Text(text[start..offset].to_string()));
+                }
+                parts.push(part);
+                start = offset + len;
+                rest = &text[start..];
+            }
+            None => rest = &rest[idx + 1..],
+        }
+    }
+    if start < text.len() {
+        parts.push(TextPart::Text(text[start..].to_string()));
+    }
+    parts
+}
+
+fn parse_tag(s: &str) -> Option<(TextPart, usize)> {
+    let (is_end, name_start) = if s.starts_with("</") {
+        (true, 2)
+    } else {
+        (false, 1)
+    };
+    let name_len = s[name_start..]
+        .char_indices()
+        .find(|(idx, c)| {
+            !(c.is_ascii_alphabetic()
+                || (*idx > 0 && (c.is_ascii_digit() || *c == '-' || *c == '_')))
+        })
+        .map(|(idx, _)| idx)?;
+    let name_end = name_start + name_len;
+    if name_len == 0 || !s[name_end..].starts_with('>') {
+        return None;
+    }
+    let name = s[name_start..name_end].to_string();
+    let part = if is_end {
+        TextPart::End(name)
+    } else {
+        TextPart::Start(name)
+    };
+    Some((part, name_end + 1))
+}
+
+/// Keeps tags of properly nested elements, unmatched tags become text
+fn match_elements<T>(parts: Vec<Result<TextPart, T>>) -> Vec<Result<TextPart, T>> {
+    let mut open: Vec<(usize, &str)> = Vec::new();
+    let mut matched = BTreeSet::new();
+    for (idx, part) in parts.iter().enumerate() {
+        match part {
+            Ok(TextPart::Start(name)) => open.push((idx, name)),
+            Ok(TextPart::End(name)) => {
+                if let Some(pos) = open.iter().rposition(|(_, open_name)| open_name == name) {
+                    matched.insert(open[pos].0);
+                    matched.insert(idx);
+                    // elements left open inside the closed one are not elements
+                    open.truncate(pos);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    let mut result: Vec<Result<TextPart, T>> = Vec::with_capacity(parts.len());
+    for (idx, part) in parts.into_iter().enumerate() {
+        let part = match part {
+            Ok(TextPart::Start(name)) if !matched.contains(&idx) => {
+                Ok(TextPart::Text(format!("<{}>", name)))
+            }
+            Ok(TextPart::End(name)) if !matched.contains(&idx) => {
+                Ok(TextPart::Text(format!("</{}>", name)))
+            }
+            part => part,
+        };
+        match (result.last_mut(), part) {
+            (Some(Ok(TextPart::Text(text))), Ok(TextPart::Text(next))) => text.push_str(&next),
+            (_, part) => result.push(part),
+        }
+    }
+    result
+}
+
 fn mk_number<S: ToString>(value: &S) -> Result<TokenStream2, Error> {
     let value = value.to_string();
     match Value::try_number(&value) {
@@ -763,7 +1120,39 @@ fn mk_number<S: ToString>(value: &S) -> Result<TokenStream2, Error> {
             Number::F64(n) => Ok(quote! {
                 ::fluent_static::value::Number::F64(#n)
             }),
+            Number::Decimal(d) => {
+                let mantissa = d.mantissa();
+                let scale = d.scale();
+                Ok(quote! {
+                    ::fluent_static::value::Number::Decimal(::fluent_static::value::number::Decimal::new(#mantissa, #scale))
+                })
+            }
         },
         _ => Err(Error::InvalidLiteral(value)),
     }
 }
+
+fn function_call_args<S: ToString>(arguments: &ast::CallArguments<S>) -> FunctionCallArgs {
+    FunctionCallArgs {
+        positional: arguments.positional.len(),
+        named: arguments
+            .named
+            .iter()
+            .map(|arg| {
+                // only literal option values are known at compile time
+                let value = match &arg.value {
+                    ast::InlineExpression::StringLiteral { value } => {
+                        Some(Value::from(value.to_string()))
+                    }
+                    ast::InlineExpression::NumberLiteral { value } => Some(
+                        Number::from_str(&value.to_string())
+                            .map(Value::from)
+                            .unwrap_or(Value::Error),
+                    ),
+                    _ => None,
+                };
+                (arg.name.name.to_string(), value)
+            })
+            .collect(),
+    }
+}
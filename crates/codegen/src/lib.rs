@@ -1,6 +1,8 @@
 pub mod ast;
 mod error;
 mod language;
+#[cfg(feature = "cldr")]
+mod locale_data;
 mod message;
 mod types;
 pub use error::Error;
@@ -0,0 +1,296 @@
+use std::{collections::BTreeSet, str::FromStr};
+
+use fluent_static_formatter::{
+    cldr::{
+        CompactPattern, CurrencyData, CurrencyPatterns, ListData, ListPatterns, ListStyles,
+        LocaleData, NumberSymbols, PluralForms, RelativeTimeData, RelativeTimePatterns, UnitData,
+    },
+    icu4x,
+};
+use fluent_static_value::number::format::{CurrencyCode, UnitIdentifier};
+use proc_macro2::{Literal, TokenStream as TokenStream2};
+use quote::quote;
+use unic_langid::LanguageIdentifier;
+
+/// Generates `LocaleData` expression with only the listed currencies and units included,
+/// relative time and compact notation patterns are included only if `relative_time`
+/// and `compact_notation` are set, falls back to the root locale data if CLDR has no data
+/// for the language
+pub fn generate_locale_data(
+    language_id: &LanguageIdentifier,
+    currencies: &BTreeSet<String>,
+    units: &BTreeSet<String>,
+    relative_time: bool,
+    compact_notation: bool,
+) -> TokenStream2 {
+    let currencies: Vec<CurrencyCode> = currencies
+        .iter()
+        .filter_map(|code| CurrencyCode::from_str(code).ok())
+        .collect();
+    let mut simple_units = Vec::new();
+    for unit in units.iter() {
+        if let Ok(unit) = UnitIdentifier::from_str(unit) {
+            flatten_unit(unit, &mut simple_units);
+        }
+    }
+
+    match icu4x::load(&language_id.to_string(), &currencies, &simple_units) {
+        Some(data) => locale_data(&data, relative_time, compact_notation),
+        None => quote! {
+            ::fluent_static::formatter::cldr::ROOT
+        },
+    }
+}
+
+fn flatten_unit(unit: UnitIdentifier, out: &mut Vec<UnitIdentifier>) {
+    match unit {
+        UnitIdentifier::Derived(numerator, denominator) => {
+            flatten_unit(*numerator, out);
+            flatten_unit(*denominator, out);
+        }
+        unit => {
+            if !out.contains(&unit) {
+                out.push(unit)
+            }
+        }
+    }
+}
+
+fn locale_data(data: &LocaleData, relative_time: bool, compact_notation: bool) -> TokenStream2 {
+    let language_id = Literal::string(data.language_id);
+    let symbols = number_symbols(&data.symbols);
+    let percent_pattern = Literal::string(data.percent_pattern);
+    let currency_patterns = currency_patterns(&data.currency_patterns);
+    let currency_name_patterns = plural_forms(&data.currency_name_patterns);
+    let currencies = data.currencies.iter().map(currency_data);
+    let units = data.units.iter().map(unit_data);
+    let lists = list_data(&data.lists);
+    let relative_times = data
+        .relative_times
+        .iter()
+        .filter(|_| relative_time)
+        .map(relative_time_data);
+    let compact_short = data
+        .compact_short
+        .iter()
+        .filter(|_| compact_notation)
+        .map(compact_pattern);
+    let compact_long = data
+        .compact_long
+        .iter()
+        .filter(|_| compact_notation)
+        .map(compact_pattern);
+    quote! {
+        ::fluent_static::formatter::cldr::LocaleData {
+            language_id: #language_id,
+            symbols: #symbols,
+            percent_pattern: #percent_pattern,
+            currency_patterns: #currency_patterns,
+            currency_name_patterns: #currency_name_patterns,
+            currencies: &[#(#currencies),*],
+            units: &[#(#units),*],
+            lists: #lists,
+            relative_times: &[#(#relative_times),*],
+            compact_short: &[#(#compact_short),*],
+            compact_long: &[#(#compact_long),*],
+        }
+    }
+}
+
+fn number_symbols(symbols: &NumberSymbols) -> TokenStream2 {
+    let decimal_separator = Literal::string(symbols.decimal_separator);
+    let grouping_separator = Literal::string(symbols.grouping_separator);
+    let minus_sign_prefix = Literal::string(symbols.minus_sign_prefix);
+    let minus_sign_suffix = Literal::string(symbols.minus_sign_suffix);
+    let plus_sign_prefix = Literal::string(symbols.plus_sign_prefix);
+    let plus_sign_suffix = Literal::string(symbols.plus_sign_suffix);
+    let digits = symbols
+        .digits
+        .iter()
+        .map(|digit| Literal::character(*digit));
+    let primary_grouping = Literal::u8_unsuffixed(symbols.primary_grouping);
+    let secondary_grouping = Literal::u8_unsuffixed(symbols.secondary_grouping);
+    let min_grouping = Literal::u8_unsuffixed(symbols.min_grouping);
+    quote! {
+        ::fluent_static::formatter::cldr::NumberSymbols {
+            decimal_separator: #decimal_separator,
+            grouping_separator: #grouping_separator,
+            minus_sign_prefix: #minus_sign_prefix,
+            minus_sign_suffix: #minus_sign_suffix,
+            plus_sign_prefix: #plus_sign_prefix,
+            plus_sign_suffix: #plus_sign_suffix,
+            digits: [#(#digits),*],
+            primary_grouping: #primary_grouping,
+            secondary_grouping: #secondary_grouping,
+            min_grouping: #min_grouping,
+        }
+    }
+}
+
+fn currency_patterns(patterns: &CurrencyPatterns) -> TokenStream2 {
+    let standard = Literal::string(patterns.standard);
+    let standard_negative = optional_str(patterns.standard_negative);
+    let standard_alpha = Literal::string(patterns.standard_alpha);
+    let standard_alpha_negative = optional_str(patterns.standard_alpha_negative);
+    let accounting = Literal::string(patterns.accounting);
+    let accounting_negative = optional_str(patterns.accounting_negative);
+    let accounting_alpha = Literal::string(patterns.accounting_alpha);
+    let accounting_alpha_negative = optional_str(patterns.accounting_alpha_negative);
+    quote! {
+        ::fluent_static::formatter::cldr::CurrencyPatterns {
+            standard: #standard,
+            standard_negative: #standard_negative,
+            standard_alpha: #standard_alpha,
+            standard_alpha_negative: #standard_alpha_negative,
+            accounting: #accounting,
+            accounting_negative: #accounting_negative,
+            accounting_alpha: #accounting_alpha,
+            accounting_alpha_negative: #accounting_alpha_negative,
+        }
+    }
+}
+
+fn currency_data(currency: &CurrencyData) -> TokenStream2 {
+    let code = Literal::string(currency.code);
+    let symbol = Literal::string(currency.symbol);
+    let narrow_symbol = Literal::string(currency.narrow_symbol);
+    let fraction_digits = Literal::u8_unsuffixed(currency.fraction_digits);
+    let names = plural_forms(&currency.names);
+    quote! {
+        ::fluent_static::formatter::cldr::CurrencyData {
+            code: #code,
+            symbol: #symbol,
+            narrow_symbol: #narrow_symbol,
+            fraction_digits: #fraction_digits,
+            names: #names,
+        }
+    }
+}
+
+fn unit_data(unit: &UnitData) -> TokenStream2 {
+    let id = Literal::string(unit.id);
+    let long = plural_forms(&unit.long);
+    let short = plural_forms(&unit.short);
+    let narrow = plural_forms(&unit.narrow);
+    quote! {
+        ::fluent_static::formatter::cldr::UnitData {
+            id: #id,
+            long: #long,
+            short: #short,
+            narrow: #narrow,
+        }
+    }
+}
+
+fn list_data(data: &ListData) -> TokenStream2 {
+    let conjunction = list_styles(&data.conjunction);
+    let disjunction = list_styles(&data.disjunction);
+    let unit = list_styles(&data.unit);
+    quote! {
+        ::fluent_static::formatter::cldr::ListData {
+            conjunction: #conjunction,
+            disjunction: #disjunction,
+            unit: #unit,
+        }
+    }
+}
+
+fn list_styles(styles: &ListStyles) -> TokenStream2 {
+    let long = list_patterns(&styles.long);
+    let short = list_patterns(&styles.short);
+    let narrow = list_patterns(&styles.narrow);
+    quote! {
+        ::fluent_static::formatter::cldr::ListStyles {
+            long: #long,
+            short: #short,
+            narrow: #narrow,
+        }
+    }
+}
+
+fn list_patterns(patterns: &ListPatterns) -> TokenStream2 {
+    let start = Literal::string(patterns.start);
+    let middle = Literal::string(patterns.middle);
+    let end = Literal::string(patterns.end);
+    let pair = Literal::string(patterns.pair);
+    quote! {
+        ::fluent_static::formatter::cldr::ListPatterns {
+            start: #start,
+            middle: #middle,
+            end: #end,
+            pair: #pair,
+        }
+    }
+}
+
+fn relative_time_data(data: &RelativeTimeData) -> TokenStream2 {
+    let long = relative_time_patterns(&data.long);
+    let short = relative_time_patterns(&data.short);
+    let narrow = relative_time_patterns(&data.narrow);
+    quote! {
+        ::fluent_static::formatter::cldr::RelativeTimeData {
+            long: #long,
+            short: #short,
+            narrow: #narrow,
+        }
+    }
+}
+
+fn relative_time_patterns(patterns: &RelativeTimePatterns) -> TokenStream2 {
+    let relatives = patterns.relatives.iter().map(|(offset, name)| {
+        let offset = Literal::i8_unsuffixed(*offset);
+        let name = Literal::string(name);
+        quote! { (#offset, #name) }
+    });
+    let past = plural_forms(&patterns.past);
+    let future = plural_forms(&patterns.future);
+    quote! {
+        ::fluent_static::formatter::cldr::RelativeTimePatterns {
+            relatives: &[#(#relatives),*],
+            past: #past,
+            future: #future,
+        }
+    }
+}
+
+fn compact_pattern(pattern: &CompactPattern) -> TokenStream2 {
+    let magnitude = Literal::u8_unsuffixed(pattern.magnitude);
+    let exponent = Literal::u8_unsuffixed(pattern.exponent);
+    let patterns = plural_forms(&pattern.patterns);
+    quote! {
+        ::fluent_static::formatter::cldr::CompactPattern {
+            magnitude: #magnitude,
+            exponent: #exponent,
+            patterns: #patterns,
+        }
+    }
+}
+
+fn plural_forms(forms: &PluralForms) -> TokenStream2 {
+    let zero = optional_str(forms.zero);
+    let one = optional_str(forms.one);
+    let two = optional_str(forms.two);
+    let few = optional_str(forms.few);
+    let many = optional_str(forms.many);
+    let other = Literal::string(forms.other);
+    quote! {
+        ::fluent_static::formatter::cldr::PluralForms {
+            zero: #zero,
+            one: #one,
+            two: #two,
+            few: #few,
+            many: #many,
+            other: #other,
+        }
+    }
+}
+
+fn optional_str(value: Option<&str>) -> TokenStream2 {
+    match value {
+        Some(s) => {
+            let s = Literal::string(s);
+            quote! { ::std::option::Option::Some(#s) }
+        }
+        None => quote! { ::std::option::Option::None },
+    }
+}
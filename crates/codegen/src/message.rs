@@ -6,6 +6,7 @@ use std::{
 };
 
 use convert_case::{Case, Casing};
+use fluent_static_value::bidi::{language_direction, Isolation, TextDirection};
 use fluent_syntax::parser;
 use proc_macro2::{Ident, Literal, TokenStream as TokenStream2};
 use quote::{format_ident, quote};
@@ -15,7 +16,7 @@ use crate::{
     ast::Visitor,
     function::{FunctionCallGenerator, FunctionRegistry},
     language::LanguageBuilder,
-    types::{FluentMessage, PublicFluentId},
+    types::{FluentMessage, FluentVariable, PublicFluentId, VariableKind},
     Error,
 };
 
@@ -48,9 +49,14 @@ pub struct MessageBundleBuilder {
     base_dir: Option<PathBuf>,
     fn_call_generator: Rc<dyn FunctionCallGenerator>,
     formatter_fn: TokenStream2,
+    embed_locale_data: bool,
+    html: bool,
+    unisolated_messages: BTreeSet<String>,
+    isolation: Isolation,
     language_bundles: BTreeMap<LanguageIdentifier, LanguageBuilder>,
     language_idents: BTreeMap<LanguageIdentifier, Ident>,
     language_bundles_code: Vec<TokenStream2>,
+    message_sources: BTreeMap<String, BTreeMap<LanguageIdentifier, (String, usize)>>,
 }
 
 impl MessageBundleBuilder {
@@ -63,9 +69,14 @@ impl MessageBundleBuilder {
             formatter_fn: quote! {
                 ::fluent_static::formatter::format
             },
+            embed_locale_data: false,
+            html: false,
+            unisolated_messages: BTreeSet::new(),
+            isolation: Isolation::default(),
             language_idents: BTreeMap::new(),
             language_bundles: BTreeMap::new(),
             language_bundles_code: Vec::new(),
+            message_sources: BTreeMap::new(),
         }
     }
 
@@ -85,6 +96,43 @@ impl MessageBundleBuilder {
         Ok(self)
     }
 
+    /// Embeds CLDR number formatting data for the bundle languages into the generated code.
+    ///
+    /// Only currencies and units used as `NUMBER` options in Fluent resources are included.
+    /// Embedded data is used instead of the message formatter function
+    #[cfg(feature = "cldr")]
+    pub fn set_embed_locale_data(&mut self, value: bool) -> &mut Self {
+        self.embed_locale_data = value;
+        self
+    }
+
+    /// Generates messages for HTML output: Fluent resources text is trusted markup
+    /// and interpolated values are HTML-escaped
+    pub fn set_html(&mut self, value: bool) -> &mut Self {
+        self.html = value;
+        self
+    }
+
+    /// Default bidi isolation strategy of the bundle, could be changed with `set_isolation`
+    pub fn set_isolation(&mut self, isolation: Isolation) -> &mut Self {
+        self.isolation = isolation;
+        self
+    }
+
+    /// Disables bidi isolation of placeables of the messages regardless of the bundle
+    /// isolation strategy, e.g. for log lines. Must be set before adding resources,
+    /// message IDs are validated when the bundle is built
+    pub fn set_unisolated_messages<I: IntoIterator<Item = S>, S: ToString>(
+        &mut self,
+        message_ids: I,
+    ) -> Result<&mut Self, Error> {
+        if !self.language_bundles.is_empty() {
+            return Err(Error::UnisolatedMessagesAfterResources);
+        }
+        self.unisolated_messages = message_ids.into_iter().map(|id| id.to_string()).collect();
+        Ok(self)
+    }
+
     pub fn set_default_language(&mut self, language_id: &str) -> Result<&mut Self, Error> {
         self.default_language = Some(LanguageIdentifier::from_str(language_id)?);
         Ok(self)
@@ -115,6 +163,7 @@ impl MessageBundleBuilder {
         lang_id: &str,
         path: impl AsRef<Path>,
     ) -> Result<&mut Self, crate::Error> {
+        let source_path = path.as_ref().display().to_string();
         let resource_path = if path.as_ref().is_absolute() {
             path.as_ref().to_path_buf()
         } else if let Some(base_dir) = self.base_dir.as_ref() {
@@ -136,6 +185,13 @@ impl MessageBundleBuilder {
                 source: e,
             })?;
 
+        for (message_id, line) in find_message_lines(&src) {
+            self.message_sources
+                .entry(message_id)
+                .or_default()
+                .insert(language_id.clone(), (source_path.clone(), line));
+        }
+
         let ast =
             parser::parse(src).map_err(|(_, errors)| crate::Error::FluentResourceParseError {
                 errors,
@@ -146,7 +202,9 @@ impl MessageBundleBuilder {
             .language_bundles
             .entry(language_id)
             .or_insert_with_key(|lang_id| {
-                LanguageBuilder::new(lang_id, self.fn_call_generator.clone())
+                let mut builder = LanguageBuilder::new(lang_id, self.fn_call_generator.clone());
+                builder.unisolated_messages = self.unisolated_messages.clone();
+                builder
             });
 
         self.language_bundles_code
@@ -205,14 +263,95 @@ impl MessageBundleBuilder {
             .collect();
 
         if !validation_errors.is_empty() {
-            Err(crate::Error::MessageBundleValidationError {
+            return Err(crate::Error::MessageBundleValidationError {
                 bundle: self.bundle_name.clone(),
                 path: None,
                 entries: validation_errors,
-            })
-        } else {
-            Ok(self)
+            });
+        }
+
+        self.validate_elements()?;
+        self.validate_unisolated_messages()?;
+        self.validate_method_names()?;
+        Ok(self)
+    }
+
+    /// Checks that methods generated for messages don't clash with each other
+    /// or with the bundle methods, e.g. message `format`
+    fn validate_method_names(&self) -> Result<(), Error> {
+        let messages = self.default_language_messages();
+        let mut methods: BTreeMap<String, String> = RESERVED_METHODS
+            .iter()
+            .map(|method| (method.to_string(), String::new()))
+            .collect();
+        for (id, msg) in messages.iter() {
+            let fn_ident = message_fn_ident(msg).to_string();
+            let mut names = vec![fn_ident.clone()];
+            if msg.has_elements() {
+                names.push(format!("{fn_ident}_segments"));
+            }
+            if !id.contains('.') && message_attributes(&messages, id).next().is_some() {
+                names.push(format!("{fn_ident}_entry"));
+            }
+            for method in names {
+                if let Some(other_id) = methods.insert(method.clone(), id.clone()) {
+                    return Err(Error::MethodNameCollision {
+                        message_id: id.clone(),
+                        method,
+                        other: if other_id.is_empty() {
+                            "the bundle".to_string()
+                        } else {
+                            format!("message {other_id}")
+                        },
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that messages excluded from bidi isolation are defined in the bundle
+    fn validate_unisolated_messages(&self) -> Result<(), Error> {
+        let message_ids: BTreeSet<String> = self
+            .language_bundles
+            .values()
+            .flat_map(|bundle| bundle.registered_message_fns.values())
+            .filter_map(|msg| msg.id().split('.').next().map(str::to_string))
+            .collect();
+        match self
+            .unisolated_messages
+            .iter()
+            .find(|id| !message_ids.contains(*id))
+        {
+            Some(id) => Err(Error::UndefinedUnisolatedMessage(id.clone())),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks that rich-text elements of each message are the same in all languages
+    fn validate_elements(&self) -> Result<(), Error> {
+        let Some(default_bundle) = self.language_bundles.get(self.default_language()) else {
+            return Ok(());
+        };
+        let join = |elements: BTreeSet<String>| elements.into_iter().collect::<Vec<_>>().join(", ");
+
+        for (id, msg) in default_bundle.registered_message_fns.iter() {
+            let expected = msg.elements();
+            for (lang, bundle) in self.language_bundles.iter() {
+                if let Some(lang_msg) = bundle.registered_message_fns.get(id) {
+                    let actual = lang_msg.elements();
+                    if actual != expected {
+                        return Err(Error::MismatchedElements {
+                            message_id: msg.id().to_string(),
+                            language: lang.to_string(),
+                            expected: join(expected),
+                            actual: join(actual),
+                        });
+                    }
+                }
+            }
         }
+        Ok(())
     }
 
     fn generate(&self) -> Result<TokenStream2, Error> {
@@ -224,8 +363,42 @@ impl MessageBundleBuilder {
 
         let language_bundles_code = &self.language_bundles_code;
         let message_fns = self.generate_message_fns(&bundle_languages_enum);
+        let messages_info = self.generate_messages_info();
+        let (message_id_enum, message_id_format_fn) =
+            self.generate_message_id_enum(&formatted_bundle_name);
+        let (entry_structs, entry_fns): (Vec<TokenStream2>, Vec<TokenStream2>) = self
+            .generate_message_entries(&formatted_bundle_name)?
+            .into_iter()
+            .unzip();
+        let dyn_bundle_impl = self.generate_dyn_bundle_impl(&bundle_ident, &bundle_languages_enum);
+        let prewarm_fn = self.generate_prewarm_fn()?;
         let default_language_literal = Literal::string(&self.default_language().to_string());
         let formatter_fn_ident = &self.formatter_fn;
+        let default_format_call = if self.embed_locale_data {
+            quote! {
+                let language = self::#bundle_languages_enum::get(language_id).unwrap_or_default();
+                ::fluent_static::formatter::cldr::format(language.locale_data(), value, &mut out)
+            }
+        } else {
+            quote! {
+                #formatter_fn_ident(language_id, value, &mut out)
+            }
+        };
+
+        let default_isolation = match self.isolation {
+            Isolation::Always => quote! { Always },
+            Isolation::Auto => quote! { Auto },
+            Isolation::Never => quote! { Never },
+        };
+
+        let write_escaping = if self.html {
+            quote! {
+                let mut out = ::fluent_static::html::Escape(out);
+                let out = &mut out;
+            }
+        } else {
+            quote! {}
+        };
 
         Ok(quote! {
             #bundle_languages_code
@@ -233,14 +406,19 @@ impl MessageBundleBuilder {
             #[derive(Debug, Clone)]
             pub struct #bundle_ident {
                 language: self::#bundle_languages_enum,
-                formatter: Option<::fluent_static::formatter::FormatterFn>,
-                use_isolating: bool,
+                formatter: Option<::std::sync::Arc<dyn ::fluent_static::formatter::ValueFormatter>>,
+                isolation: ::fluent_static::value::bidi::Isolation,
+                extensions: ::fluent_static::function::Extensions,
             }
 
             impl ::fluent_static::LanguageAware for self::#bundle_ident {
                 fn language_id(&self) -> &str {
                     self.language.language_id()
                 }
+
+                fn text_direction(&self) -> ::fluent_static::value::bidi::TextDirection {
+                    ::fluent_static::LanguageAware::text_direction(&self.language)
+                }
             }
 
             impl ::fluent_static::MessageBundle for self::#bundle_ident {
@@ -262,38 +440,103 @@ impl MessageBundleBuilder {
                     Self {
                         language: self::#bundle_languages_enum::default(),
                         formatter: None,
-                        use_isolating: true,
+                        isolation: ::fluent_static::value::bidi::Isolation::#default_isolation,
+                        extensions: ::fluent_static::function::Extensions::default(),
                     }
                 }
             }
 
+            #dyn_bundle_impl
+
+            #(#entry_structs)*
+
+            #message_id_enum
+
             impl #bundle_ident {
-                fn _write_<W: ::std::fmt::Write>(&self, value: & ::fluent_static::value::Value, out: &mut W) -> ::std::fmt::Result {
-                    if self.use_isolating {
-                        out.write_char('\u{2068}')?;
-                    };
-                    if let Some(formatter) = self.formatter.as_ref() {
-                        (formatter)(::fluent_static::LanguageAware::language_id(self), value, out)?;
-                    } else {
-                        #formatter_fn_ident(::fluent_static::LanguageAware::language_id(self), value, out)?;
+                fn _write_<W: ::fluent_static::MessageWrite>(&self, value: & ::fluent_static::value::Value, out: &mut W) -> ::std::fmt::Result {
+                    self._write_with_(value, out, self.isolation, ::fluent_static::LanguageAware::text_direction(&self.language))
+                }
+
+                /// Writes placeable surrounded by literal text which direction differs from the language direction
+                fn _write_in_<W: ::fluent_static::MessageWrite>(&self, value: & ::fluent_static::value::Value, out: &mut W, direction: ::fluent_static::value::bidi::TextDirection) -> ::std::fmt::Result {
+                    self._write_with_(value, out, self.isolation, direction)
+                }
+
+                fn _write_with_<W: ::fluent_static::MessageWrite>(&self, value: & ::fluent_static::value::Value, out: &mut W, isolation: ::fluent_static::value::bidi::Isolation, direction: ::fluent_static::value::bidi::TextDirection) -> ::std::fmt::Result {
+                    if let ::fluent_static::value::Value::Markup(fragment) = value {
+                        return out.write_fragment(fragment);
                     }
-                    if self.use_isolating {
-                        out.write_char('\u{2069}')?;
-                    };
-                    Ok(())
+                    #write_escaping
+                    let language_id = ::fluent_static::LanguageAware::language_id(self);
+                    let formatter = self._formatter_();
+                    ::fluent_static::value::bidi::write_isolated(isolation, direction, out, |out| ::fluent_static::formatter::ValueFormatter::format(formatter, language_id, value, out))
                 }
 
+                /// Shortcut for `set_isolation(Isolation::Always)` or `set_isolation(Isolation::Never)`
                 pub fn set_use_isolating(&mut self, value: bool) {
-                    self.use_isolating = value;
+                    self.isolation = if value {
+                        ::fluent_static::value::bidi::Isolation::Always
+                    } else {
+                        ::fluent_static::value::bidi::Isolation::Never
+                    };
+                }
+
+                /// Sets bidi isolation strategy of placeables
+                pub fn set_isolation(&mut self, isolation: ::fluent_static::value::bidi::Isolation) {
+                    self.isolation = isolation;
+                }
+
+                fn _format_(language_id: &str, value: & ::fluent_static::value::Value, out: &mut dyn ::std::fmt::Write) -> ::std::fmt::Result {
+                    let mut out = out;
+                    #default_format_call
+                }
+
+                const _DEFAULT_FORMATTER_: ::fluent_static::formatter::FormatterFn = Self::_format_;
+
+                fn _formatter_(&self) -> &dyn ::fluent_static::formatter::ValueFormatter {
+                    match &self.formatter {
+                        Some(formatter) => formatter.as_ref(),
+                        None => &Self::_DEFAULT_FORMATTER_,
+                    }
+                }
+
+                fn _function_context_(&self) -> ::fluent_static::function::FunctionContext<'_> {
+                    ::fluent_static::function::FunctionContext::new(
+                        ::fluent_static::LanguageAware::language_id(self),
+                        self.language.plural_rules_cardinal(),
+                        self._formatter_(),
+                        &self.extensions,
+                    )
+                }
+
+                /// Replaces the default value formatter, the formatter is initialized for the bundle language
+                pub fn set_value_formatter(&mut self, formatter: Option<::std::sync::Arc<dyn ::fluent_static::formatter::ValueFormatter>>) {
+                    if let Some(formatter) = formatter.as_ref() {
+                        ::fluent_static::formatter::ValueFormatter::init(formatter.as_ref(), ::fluent_static::LanguageAware::language_id(self));
+                    }
+                    self.formatter = formatter;
+                }
+
+                #prewarm_fn
+
+                /// Application values available to functions registered with context
+                pub fn extensions(&self) -> &::fluent_static::function::Extensions {
+                    &self.extensions
                 }
 
-                pub fn set_value_formatter(&mut self, formatter_fn: Option<::fluent_static::formatter::FormatterFn>) {
-                    self.formatter = formatter_fn;
+                pub fn extensions_mut(&mut self) -> &mut ::fluent_static::function::Extensions {
+                    &mut self.extensions
                 }
             }
 
             impl #bundle_ident {
+                pub const MESSAGES: &'static [::fluent_static::MessageInfo] = &[#(#messages_info),*];
+
                 #(#message_fns)*
+
+                #(#entry_fns)*
+
+                #message_id_format_fn
             }
 
             impl #bundle_ident {
@@ -334,6 +577,20 @@ impl MessageBundleBuilder {
             })
             .collect();
 
+        let direction_mappings: Vec<TokenStream2> = self
+            .language_idents
+            .iter()
+            .map(|(lang_id, ident)| {
+                let direction = match language_direction(&lang_id.to_string()) {
+                    TextDirection::Ltr => quote! { Ltr },
+                    TextDirection::Rtl => quote! { Rtl },
+                };
+                quote! {
+                    Self::#ident => ::fluent_static::value::bidi::TextDirection::#direction
+                }
+            })
+            .collect();
+
         let plural_rules_cardinal_mappings: Vec<TokenStream2> = language_idents
             .iter()
             .map(|(lang_id, ident)| {
@@ -369,6 +626,8 @@ impl MessageBundleBuilder {
             })
             .collect();
 
+        let locale_data_fn = self.generate_locale_data_fn();
+
         let total_langs = Literal::usize_unsuffixed(language_idents.len());
 
         let (bundle_languages_literals, bundle_languages_enum_members): (
@@ -404,6 +663,8 @@ impl MessageBundleBuilder {
                         }
                     }
 
+                    #locale_data_fn
+
                 }
 
                 impl ::fluent_static::LanguageAware for self::#bundle_languages_enum_ident {
@@ -412,6 +673,12 @@ impl MessageBundleBuilder {
                             #(#ident_mappings),*
                         }
                     }
+
+                    fn text_direction(&self) -> ::fluent_static::value::bidi::TextDirection {
+                        match self {
+                            #(#direction_mappings),*
+                        }
+                    }
                 }
 
                 impl ::core::default::Default for self::#bundle_languages_enum_ident {
@@ -423,6 +690,106 @@ impl MessageBundleBuilder {
         )
     }
 
+    fn generate_prewarm_fn(&self) -> Result<TokenStream2, Error> {
+        let prewarm_calls = self
+            .language_bundles
+            .iter()
+            .filter(|(_, bundle)| !bundle.number_formats.is_empty())
+            .map(|(lang_id, bundle)| {
+                let lang_id = Literal::string(&lang_id.to_string());
+                let formats = bundle
+                    .number_formats
+                    .iter()
+                    .map(|options| {
+                        let options = options
+                            .iter()
+                            .map(|(name, value)| {
+                                let name = Literal::string(name);
+                                let value = value.to_value_tokens()?;
+                                Ok(quote! { (#name, #value) })
+                            })
+                            .collect::<Result<Vec<_>, Error>>()?;
+                        Ok(quote! {
+                            ::fluent_static::function::builtins::parse_number_format(None, &[#(#options),*])
+                        })
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+                Ok(quote! {
+                    // formats with invalid options are reported when messages are formatted
+                    let formats: Vec<::fluent_static::value::NumberFormat> = [#(#formats),*]
+                        .into_iter()
+                        .filter_map(Result::ok)
+                        .collect();
+                    ::fluent_static::formatter::prewarm(#lang_id, &formats)?;
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok(quote! {
+            /// Creates formatters of all bundle languages for `NUMBER` calls with literal options ahead of use
+            pub fn prewarm_formatters() -> ::std::fmt::Result {
+                #({ #prewarm_calls })*
+                Ok(())
+            }
+        })
+    }
+
+    #[cfg(feature = "cldr")]
+    fn generate_locale_data_fn(&self) -> Option<TokenStream2> {
+        if !self.embed_locale_data {
+            return None;
+        }
+
+        // messages are expected to use the same currencies and units in all languages
+        let currencies: BTreeSet<String> = self
+            .language_bundles
+            .values()
+            .flat_map(|bundle| bundle.currencies.iter().cloned())
+            .collect();
+        let units: BTreeSet<String> = self
+            .language_bundles
+            .values()
+            .flat_map(|bundle| bundle.units.iter().cloned())
+            .collect();
+        let relative_time = self
+            .language_bundles
+            .values()
+            .any(|bundle| bundle.relative_time);
+        let compact_notation = self
+            .language_bundles
+            .values()
+            .any(|bundle| bundle.compact_notation);
+
+        let locale_data_mappings = self.language_idents.iter().map(|(lang_id, ident)| {
+            let locale_data = crate::locale_data::generate_locale_data(
+                lang_id,
+                &currencies,
+                &units,
+                relative_time,
+                compact_notation,
+            );
+            quote! {
+                Self::#ident => {
+                    static DATA: ::fluent_static::formatter::cldr::LocaleData = #locale_data;
+                    &DATA
+                }
+            }
+        });
+
+        Some(quote! {
+            fn locale_data(&self) -> &'static ::fluent_static::formatter::cldr::LocaleData {
+                match self {
+                    #(#locale_data_mappings),*
+                }
+            }
+        })
+    }
+
+    #[cfg(not(feature = "cldr"))]
+    fn generate_locale_data_fn(&self) -> Option<TokenStream2> {
+        None
+    }
+
     fn generate_message_fns(&self, languages_enum: &Ident) -> Vec<TokenStream2> {
         self.language_bundles
             .get(self.default_language())
@@ -436,16 +803,420 @@ impl MessageBundleBuilder {
             .collect()
     }
 
+    fn default_language_messages(&self) -> BTreeMap<String, &FluentMessage> {
+        self.language_bundles
+            .get(self.default_language())
+            .iter()
+            .flat_map(|bundle| bundle.registered_message_fns.values())
+            .map(|msg| (msg.id().to_string(), msg))
+            .collect()
+    }
+
+    fn generate_messages_info(&self) -> Vec<TokenStream2> {
+        let messages = self.default_language_messages();
+
+        messages
+            .iter()
+            .filter(|(id, _)| !id.contains('.'))
+            .map(|(id, msg)| {
+                let variables = self.generate_variables_info(msg);
+                let attributes: Vec<TokenStream2> = message_attributes(&messages, id)
+                    .map(|(attr_id, attr)| {
+                        let attr_id = Literal::string(attr_id);
+                        let variables = self.generate_variables_info(attr);
+                        quote! {
+                            ::fluent_static::AttributeInfo {
+                                id: #attr_id,
+                                variables: &[#(#variables),*],
+                            }
+                        }
+                    })
+                    .collect();
+
+                let sources: Vec<TokenStream2> = self
+                    .language_bundles
+                    .iter()
+                    .filter(|(_, bundle)| bundle.registered_fns.contains_key(id.as_str()))
+                    .map(|(lang, _)| {
+                        let language_id = Literal::string(&lang.to_string());
+                        let (path, line) = self
+                            .message_sources
+                            .get(id)
+                            .and_then(|sources| sources.get(lang))
+                            .map(|(path, line)| (path.as_str(), *line))
+                            .unwrap_or_default();
+                        let path = Literal::string(path);
+                        let line = Literal::usize_unsuffixed(line);
+                        quote! {
+                            ::fluent_static::MessageSource {
+                                language_id: #language_id,
+                                path: #path,
+                                line: #line,
+                            }
+                        }
+                    })
+                    .collect();
+
+                let id = Literal::string(id);
+                quote! {
+                    ::fluent_static::MessageInfo {
+                        id: #id,
+                        variables: &[#(#variables),*],
+                        attributes: &[#(#attributes),*],
+                        sources: &[#(#sources),*],
+                    }
+                }
+            })
+            .collect()
+    }
+
+    fn generate_message_id_enum(&self, bundle_name: &str) -> (TokenStream2, TokenStream2) {
+        let enum_ident = format_ident!("{}MessageId", bundle_name);
+        let messages = self.default_language_messages();
+
+        // fields are typed by variable kinds, numbers are owned values
+        let field_type = |msg: &FluentMessage, var: &FluentVariable| match self
+            .variable_kind(msg, &var.var_name)
+        {
+            VariableKind::Any => quote! { ::fluent_static::value::Value<'a> },
+            VariableKind::String => quote! { ::std::borrow::Cow<'a, str> },
+            VariableKind::Number => quote! { ::fluent_static::value::Number },
+        };
+        let has_borrowed_fields = messages.values().any(|msg| {
+            msg.declared_vars()
+                .iter()
+                .any(|var| self.variable_kind(msg, &var.var_name) != VariableKind::Number)
+        });
+        let generics = if has_borrowed_fields {
+            quote! {<'a>}
+        } else {
+            quote! {}
+        };
+        let anonymous_generics = if has_borrowed_fields {
+            quote! {<'_>}
+        } else {
+            quote! {}
+        };
+
+        let (variants, (id_mappings, format_mappings)): (
+            Vec<TokenStream2>,
+            (Vec<TokenStream2>, Vec<TokenStream2>),
+        ) = messages
+            .iter()
+            .map(|(id, msg)| {
+                let variant_ident =
+                    format_ident!("{}", id.replace('.', "_").to_case(Case::Pascal));
+                let id = Literal::string(id);
+                let fn_ident = message_fn_ident(msg);
+                if msg.has_vars() {
+                    let declared_vars = msg.declared_vars();
+                    let types: Vec<TokenStream2> = declared_vars
+                        .iter()
+                        .map(|var| field_type(msg, var))
+                        .collect();
+                    let vars: Vec<Ident> = declared_vars
+                        .into_iter()
+                        .map(|var| var.var_ident)
+                        .collect();
+                    (
+                        quote! {
+                            #variant_ident {
+                                #(#vars: #types),*
+                            }
+                        },
+                        (
+                            quote! {
+                                Self::#variant_ident { .. } => #id
+                            },
+                            quote! {
+                                self::#enum_ident::#variant_ident { #(#vars),* } => self.#fn_ident(#(#vars),*)
+                            },
+                        ),
+                    )
+                } else {
+                    (
+                        quote! {
+                            #variant_ident
+                        },
+                        (
+                            quote! {
+                                Self::#variant_ident => #id
+                            },
+                            quote! {
+                                self::#enum_ident::#variant_ident => self.#fn_ident()
+                            },
+                        ),
+                    )
+                }
+            })
+            .unzip();
+
+        (
+            quote! {
+                #[derive(Debug, Clone, PartialEq)]
+                pub enum #enum_ident #generics {
+                    #(#variants),*
+                }
+
+                impl #enum_ident #anonymous_generics {
+                    pub fn id(&self) -> &'static str {
+                        match *self {
+                            #(#id_mappings),*
+                        }
+                    }
+                }
+            },
+            quote! {
+                pub fn format(&self, message_id: self::#enum_ident #anonymous_generics) -> ::fluent_static::Message {
+                    match message_id {
+                        #(#format_mappings),*
+                    }
+                }
+            },
+        )
+    }
+
+    fn generate_message_entries(
+        &self,
+        bundle_name: &str,
+    ) -> Result<Vec<(TokenStream2, TokenStream2)>, Error> {
+        let messages = self.default_language_messages();
+
+        messages
+            .iter()
+            .filter(|(id, _)| !id.contains('.'))
+            .filter_map(|(id, msg)| {
+                let attributes: Vec<(&str, &FluentMessage)> =
+                    message_attributes(&messages, id).collect();
+                if attributes.is_empty() {
+                    None
+                } else {
+                    Some(self.generate_message_entry(bundle_name, msg, attributes))
+                }
+            })
+            .collect()
+    }
+
+    fn generate_message_entry(
+        &self,
+        bundle_name: &str,
+        msg: &FluentMessage,
+        attributes: Vec<(&str, &FluentMessage)>,
+    ) -> Result<(TokenStream2, TokenStream2), Error> {
+        let entry_ident = format_ident!("{}{}Entry", bundle_name, msg.id().to_case(Case::Pascal));
+        let fn_ident = format_ident!("{}_entry", message_fn_ident(msg));
+
+        let mut vars: Vec<Ident> = Vec::new();
+        let mut add_vars = |msg: &FluentMessage| {
+            let var_idents: Vec<Ident> = msg
+                .declared_vars()
+                .into_iter()
+                .map(|var| var.var_ident)
+                .collect();
+            for var_ident in var_idents.iter() {
+                if !vars.contains(var_ident) {
+                    vars.push(var_ident.clone());
+                }
+            }
+            var_idents
+        };
+
+        let mut fields = Vec::new();
+        let mut values = Vec::new();
+
+        if msg.has_value() {
+            let msg_fn_ident = message_fn_ident(msg);
+            let msg_vars = add_vars(msg);
+            fields.push(quote! {
+                pub value: ::fluent_static::Message
+            });
+            values.push(quote! {
+                value: self.#msg_fn_ident(#(#msg_vars.clone()),*)
+            });
+        }
+
+        for (attr_id, attr) in attributes {
+            let field_ident = format_ident!("{}", attr_id.to_case(Case::Snake));
+            if msg.has_value() && field_ident == "value" {
+                return Err(Error::UnsupportedFeature {
+                    feature: "Attribute named 'value' on a message with value".to_string(),
+                    id: attr.id().to_string(),
+                });
+            }
+            let attr_fn_ident = message_fn_ident(attr);
+            let attr_vars = add_vars(attr);
+            fields.push(quote! {
+                pub #field_ident: ::fluent_static::Message
+            });
+            values.push(quote! {
+                #field_ident: self.#attr_fn_ident(#(#attr_vars.clone()),*)
+            });
+        }
+
+        let fn_generics = if vars.is_empty() {
+            quote! {}
+        } else {
+            quote! {<'a>}
+        };
+
+        Ok((
+            quote! {
+                #[derive(Debug, Clone, PartialEq, Eq)]
+                pub struct #entry_ident {
+                    #(#fields),*
+                }
+            },
+            quote! {
+                pub fn #fn_ident #fn_generics(&self, #(#vars: impl Into<::fluent_static::value::Value<'a>>),*) -> self::#entry_ident {
+                    #(let #vars = #vars.into();)*
+                    self::#entry_ident {
+                        #(#values),*
+                    }
+                }
+            },
+        ))
+    }
+
+    /// Kind of the message variable merged across the bundle languages
+    fn variable_kind(&self, msg: &FluentMessage, var_name: &str) -> VariableKind {
+        self.language_bundles
+            .values()
+            .filter_map(|bundle| bundle.registered_fns.get(&msg.id()))
+            .fold(VariableKind::Any, |kind, lang_msg| {
+                kind.merge(lang_msg.var_kind(var_name))
+            })
+    }
+
+    fn generate_variables_info(&self, msg: &FluentMessage) -> Vec<TokenStream2> {
+        msg.declared_vars()
+            .iter()
+            .map(|var| {
+                let kind = match self.variable_kind(msg, &var.var_name) {
+                    VariableKind::Any => quote! { ::fluent_static::VariableKind::Any },
+                    VariableKind::String => quote! { ::fluent_static::VariableKind::String },
+                    VariableKind::Number => quote! { ::fluent_static::VariableKind::Number },
+                };
+                let name = Literal::string(&var.var_name);
+                quote! {
+                    ::fluent_static::VariableInfo {
+                        name: #name,
+                        kind: #kind,
+                    }
+                }
+            })
+            .collect()
+    }
+
+    fn generate_dyn_bundle_impl(
+        &self,
+        bundle_ident: &Ident,
+        languages_enum: &Ident,
+    ) -> TokenStream2 {
+        let messages: Vec<&FluentMessage> = self
+            .language_bundles
+            .get(self.default_language())
+            .iter()
+            .flat_map(|bundle| bundle.registered_message_fns.values())
+            .collect();
+
+        let has_vars = messages.iter().any(|msg| msg.has_vars());
+        let arg_lookup = if has_vars {
+            quote! {
+                let arg = |name: &str| {
+                    args.iter()
+                        .find(|(arg_name, _)| *arg_name == name)
+                        .map(|(_, value)| value.clone())
+                        .unwrap_or(::fluent_static::value::Value::Error)
+                };
+            }
+        } else {
+            quote! {
+                let _ = args;
+            }
+        };
+
+        let format_mappings: Vec<TokenStream2> = messages
+            .iter()
+            .map(|msg| {
+                let msg_id = Literal::string(&msg.id());
+                let fn_ident = message_fn_ident(msg);
+                let var_names: Vec<Literal> = msg
+                    .declared_vars()
+                    .iter()
+                    .map(|var| Literal::string(&var.var_name))
+                    .collect();
+                quote! {
+                    #msg_id => Some(self.#fn_ident(#(arg(#var_names)),*))
+                }
+            })
+            .collect();
+
+        let attributes = messages.iter().fold(
+            BTreeMap::<String, Vec<String>>::new(),
+            |mut attributes, msg| {
+                let id = msg.id();
+                if let Some((msg_id, attribute_id)) = id.split_once('.') {
+                    attributes
+                        .entry(msg_id.to_string())
+                        .or_default()
+                        .push(attribute_id.to_string());
+                } else {
+                    attributes.entry(id.to_string()).or_default();
+                }
+                attributes
+            },
+        );
+
+        let attribute_mappings: Vec<TokenStream2> = attributes
+            .iter()
+            .map(|(msg_id, attribute_ids)| {
+                let msg_id = Literal::string(msg_id);
+                let attribute_ids = attribute_ids.iter().map(|id| Literal::string(id));
+                quote! {
+                    #msg_id => Some(&[#(#attribute_ids),*])
+                }
+            })
+            .collect();
+
+        quote! {
+            impl ::fluent_static::DynMessageBundle for self::#bundle_ident {
+                fn supported_languages(&self) -> &'static [&'static str] {
+                    self::#languages_enum::language_ids()
+                }
+
+                fn format_message(
+                    &self,
+                    message_id: &str,
+                    args: &[(&str, ::fluent_static::value::Value<'_>)],
+                ) -> Option<::fluent_static::Message> {
+                    #arg_lookup
+                    match message_id {
+                        #(#format_mappings,)*
+                        _ => None,
+                    }
+                }
+
+                fn message_attributes(&self, message_id: &str) -> Option<&'static [&'static str]> {
+                    match message_id {
+                        #(#attribute_mappings,)*
+                        _ => None,
+                    }
+                }
+
+                fn messages(&self) -> &'static [::fluent_static::MessageInfo] {
+                    Self::MESSAGES
+                }
+            }
+        }
+    }
+
     fn generate_message_fn(
         &self,
         languages_enum: &Ident,
         msg_fn_id: &PublicFluentId,
         msg: &FluentMessage,
     ) -> TokenStream2 {
-        let fn_ident = format_ident!(
-            "{}",
-            msg.id().to_string().replace('.', "_").to_case(Case::Snake)
-        );
+        let fn_ident = message_fn_ident(msg);
 
         let vars = msg.declared_vars();
         let fn_generics = if msg.has_vars() {
@@ -454,6 +1225,11 @@ impl MessageBundleBuilder {
             quote! {}
         };
         let var: Vec<&Ident> = vars.iter().map(|var| &var.var_ident).collect();
+        let message_buffer = if self.html {
+            quote! { ::fluent_static::MessageBuffer::html() }
+        } else {
+            quote! { ::fluent_static::MessageBuffer::default() }
+        };
 
         let lang_selectors: Vec<TokenStream2> = self
             .language_bundles
@@ -478,16 +1254,35 @@ impl MessageBundleBuilder {
             })
             .collect();
 
+        let segments_fn = if msg.has_elements() {
+            let segments_fn_ident = format_ident!("{}_segments", fn_ident);
+            quote! {
+                pub fn #segments_fn_ident #fn_generics(&self, #(#var: impl Into<::fluent_static::value::Value<'a>>),*) -> Vec<::fluent_static::rich::Segment> {
+                    #(let #var = #var.into();)*
+                    let mut out = ::fluent_static::rich::SegmentBuffer::default();
+                    match self.language {
+                        #(#lang_selectors),*,
+                    }.unwrap();
+
+                    out.into_segments()
+                }
+            }
+        } else {
+            quote! {}
+        };
+
         quote! {
             pub fn #fn_ident #fn_generics(&self, #(#var: impl Into<::fluent_static::value::Value<'a>>),*) -> ::fluent_static::Message {
                 #(let #var = #var.into();)*
-                let mut out = String::new();
+                let mut out = #message_buffer;
                 match self.language {
                     #(#lang_selectors),*,
                 }.unwrap();
 
-                ::fluent_static::Message::from(out)
+                out.into_message()
             }
+
+            #segments_fn
         }
     }
 
@@ -500,6 +1295,53 @@ impl MessageBundleBuilder {
     }
 }
 
+fn find_message_lines(src: &str) -> BTreeMap<String, usize> {
+    src.lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let (id, _) = line.split_once('=')?;
+            let id = id.trim_end();
+            let mut chars = id.chars();
+            if chars.next()?.is_ascii_alphabetic()
+                && chars.all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            {
+                Some((id.to_string(), index + 1))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn message_attributes<'a>(
+    messages: &'a BTreeMap<String, &'a FluentMessage>,
+    message_id: &str,
+) -> impl Iterator<Item = (&'a str, &'a FluentMessage)> {
+    let prefix = format!("{}.", message_id);
+    messages
+        .range(prefix.clone()..)
+        .take_while(move |(id, _)| id.starts_with(&prefix))
+        .map(|(id, msg)| (&id[id.find('.').unwrap_or_default() + 1..], *msg))
+}
+
+/// Public methods of the generated bundle struct
+const RESERVED_METHODS: &[&str] = &[
+    "format",
+    "set_use_isolating",
+    "set_isolation",
+    "set_value_formatter",
+    "extensions",
+    "extensions_mut",
+    "prewarm_formatters",
+];
+
+fn message_fn_ident(msg: &FluentMessage) -> Ident {
+    format_ident!(
+        "{}",
+        msg.id().to_string().replace('.', "_").to_case(Case::Snake)
+    )
+}
+
 impl Default for MessageBundleBuilder {
     fn default() -> Self {
         Self::new("Message")
@@ -1,4 +1,10 @@
-use std::{cell::RefCell, collections::BTreeSet, fmt::Display, ops::Deref, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    ops::Deref,
+    rc::Rc,
+};
 
 use fluent_syntax::ast;
 use syn::Ident;
@@ -85,10 +91,13 @@ impl Display for PublicFluentId {
 struct FluentMessageAttrs {
     id: FluentId,
     private: bool,
+    has_value: bool,
     fn_ident: Ident,
     vars: Vec<FluentVariable>,
     var_idents: BTreeSet<Ident>,
     unique_vars: BTreeSet<FluentVariable>,
+    var_kinds: BTreeMap<String, VariableKind>,
+    elements: BTreeSet<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -102,9 +111,12 @@ impl FluentMessage {
             id: id.into(),
             fn_ident,
             private,
+            has_value: true,
             vars: Vec::new(),
             var_idents: BTreeSet::new(),
             unique_vars: BTreeSet::new(),
+            var_kinds: BTreeMap::new(),
+            elements: BTreeSet::new(),
         }));
         Self { attrs }
     }
@@ -121,8 +133,21 @@ impl FluentMessage {
         self.attrs.borrow().private
     }
 
-    pub fn add_var(&self, var: FluentVariable) {
+    pub fn has_value(&self) -> bool {
+        self.attrs.borrow().has_value
+    }
+
+    pub fn set_has_value(&self, value: bool) {
+        self.attrs.borrow_mut().has_value = value;
+    }
+
+    pub fn add_var(&self, var: FluentVariable, kind: VariableKind) {
         let mut attrs = self.attrs.borrow_mut();
+        attrs
+            .var_kinds
+            .entry(var.var_name.clone())
+            .and_modify(|existing| *existing = existing.merge(kind))
+            .or_insert(kind);
         if !attrs.unique_vars.contains(&var) {
             attrs.unique_vars.insert(var.clone());
             attrs.var_idents.insert(var.var_ident.clone());
@@ -145,6 +170,24 @@ impl FluentMessage {
         attrs.vars.iter().cloned().collect()
     }
 
+    pub fn var_kind(&self, var_name: &str) -> VariableKind {
+        let attrs = self.attrs.borrow();
+        attrs.var_kinds.get(var_name).copied().unwrap_or_default()
+    }
+
+    pub fn add_element(&self, name: &str) {
+        self.attrs.borrow_mut().elements.insert(name.to_string());
+    }
+
+    /// Names of rich-text elements used in the message text, e.g. `link` for `<link>here</link>`
+    pub fn elements(&self) -> BTreeSet<String> {
+        self.attrs.borrow().elements.clone()
+    }
+
+    pub fn has_elements(&self) -> bool {
+        !self.attrs.borrow().elements.is_empty()
+    }
+
     pub fn public_id(&self) -> PublicFluentId {
         let attrs = self.attrs.borrow();
         if attrs.unique_vars.is_empty() {
@@ -176,3 +219,21 @@ impl FluentVariable {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VariableKind {
+    #[default]
+    Any,
+    String,
+    Number,
+}
+
+impl VariableKind {
+    pub fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (VariableKind::Any, kind) | (kind, VariableKind::Any) => kind,
+            (kind, other) if kind == other => kind,
+            _ => VariableKind::Any,
+        }
+    }
+}
@@ -0,0 +1,219 @@
+//! Bounded least recently used cache of formatters shared between threads
+
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+
+pub const DEFAULT_CAPACITY: usize = 256;
+
+/// Snapshot of cache usage counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheMetrics {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+    pub size: usize,
+    pub capacity: usize,
+}
+
+pub struct FormatterCache<K, V> {
+    state: Mutex<CacheState<K, V>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
+}
+
+struct CacheState<K, V> {
+    entries: HashMap<K, (Arc<V>, u64)>,
+    capacity: usize,
+    tick: u64,
+}
+
+impl<K: Eq + Hash + Clone, V> FormatterCache<K, V> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            state: Mutex::new(CacheState {
+                entries: HashMap::new(),
+                capacity,
+                tick: 0,
+            }),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns cached formatter or the one created by `create`, errors are not cached.
+    ///
+    /// Formatters are created outside the cache lock, so concurrent misses
+    /// of the same key could create the formatter more than once
+    pub fn get_or_try_insert_with<E>(
+        &self,
+        key: &K,
+        create: impl FnOnce() -> Result<V, E>,
+    ) -> Result<Arc<V>, E> {
+        if let Some(value) = self.get(key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(value);
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let value = Arc::new(create()?);
+        self.insert(key.clone(), value.clone());
+        Ok(value)
+    }
+
+    fn get(&self, key: &K) -> Option<Arc<V>> {
+        let mut state = self.lock();
+        state.tick += 1;
+        let tick = state.tick;
+        state.entries.get_mut(key).map(|(value, used)| {
+            *used = tick;
+            value.clone()
+        })
+    }
+
+    fn insert(&self, key: K, value: Arc<V>) {
+        let mut state = self.lock();
+        state.tick += 1;
+        let tick = state.tick;
+        state.entries.insert(key, (value, tick));
+        self.evict(&mut state);
+    }
+
+    fn evict(&self, state: &mut CacheState<K, V>) {
+        while state.entries.len() > state.capacity {
+            let lru_key = state
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, used))| *used)
+                .map(|(key, _)| key.clone());
+            if let Some(key) = lru_key {
+                state.entries.remove(&key);
+                self.evictions.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Changes maximum number of cached formatters, evicting least recently used ones
+    pub fn set_capacity(&self, capacity: usize) {
+        let mut state = self.lock();
+        state.capacity = capacity;
+        self.evict(&mut state);
+    }
+
+    pub fn clear(&self) {
+        self.lock().entries.clear();
+    }
+
+    pub fn metrics(&self) -> CacheMetrics {
+        let state = self.lock();
+        CacheMetrics {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            evictions: self.evictions.load(Ordering::Relaxed),
+            size: state.entries.len(),
+            capacity: state.capacity,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, CacheState<K, V>> {
+        // cache state is consistent even if a thread panicked while holding the lock
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<K: Eq + Hash + Clone, V> Default for FormatterCache<K, V> {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use super::{CacheMetrics, FormatterCache};
+
+    fn create(key: &str) -> Result<String, ()> {
+        Ok(key.to_uppercase())
+    }
+
+    #[test]
+    fn test_lru() {
+        let cache = FormatterCache::new(2);
+        assert_eq!(
+            "EN",
+            *cache
+                .get_or_try_insert_with(&"en", || create("en"))
+                .unwrap()
+        );
+        cache
+            .get_or_try_insert_with(&"fr", || create("fr"))
+            .unwrap();
+        // "en" becomes most recently used, "fr" is evicted
+        cache
+            .get_or_try_insert_with(&"en", || create("xx"))
+            .unwrap();
+        cache
+            .get_or_try_insert_with(&"de", || create("de"))
+            .unwrap();
+        assert_eq!(
+            "EN",
+            *cache
+                .get_or_try_insert_with(&"en", || create("xx"))
+                .unwrap()
+        );
+        assert_eq!(
+            "FR",
+            *cache
+                .get_or_try_insert_with(&"fr", || create("fr"))
+                .unwrap()
+        );
+        assert!(cache
+            .get_or_try_insert_with(&"it", || Err::<String, _>(()))
+            .is_err());
+
+        assert_eq!(
+            CacheMetrics {
+                hits: 2,
+                misses: 5,
+                evictions: 2,
+                size: 2,
+                capacity: 2,
+            },
+            cache.metrics()
+        );
+
+        cache.set_capacity(1);
+        assert_eq!(1, cache.metrics().size);
+        cache.clear();
+        assert_eq!(0, cache.metrics().size);
+    }
+
+    #[test]
+    fn test_shared() {
+        let cache = Arc::new(FormatterCache::<String, String>::default());
+        let threads: Vec<_> = (0..4)
+            .map(|_| {
+                let cache = cache.clone();
+                std::thread::spawn(move || {
+                    for key in ["en", "fr", "en", "fr"] {
+                        cache
+                            .get_or_try_insert_with(&key.to_string(), || create(key))
+                            .unwrap();
+                    }
+                })
+            })
+            .collect();
+        threads.into_iter().for_each(|t| t.join().unwrap());
+
+        let metrics = cache.metrics();
+        assert_eq!(16, metrics.hits + metrics.misses);
+        assert_eq!(2, metrics.size);
+    }
+}
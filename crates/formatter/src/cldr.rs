@@ -0,0 +1,994 @@
+//! Pure Rust, table driven number formatter.
+//!
+//! All locale specific information is provided by [`LocaleData`], which is either
+//! loaded at runtime (see `icu4x` feature) or embedded as static tables.
+
+use std::{
+    convert::Infallible,
+    fmt::Write,
+    sync::{Arc, OnceLock},
+};
+
+use fluent_static_value::{
+    list::{ListStyle, ListType},
+    number::{
+        format::{
+            CompactDisplay, CurrencyCode, CurrencyDisplayStyle, CurrencySignMode, GroupingStyle,
+            Notation, NumberStyle, SignDisplay, TrailingZeroDisplay, UnitDisplayStyle,
+            UnitIdentifier,
+        },
+        Decimal,
+    },
+    relative_time::{RelativeTimeNumeric, RelativeTimeStyle, RelativeTimeUnit},
+    ListFormat, Number, NumberFormat, RelativeTimeFormat, Value,
+};
+use intl_pluralrules::{operands::PluralOperands, PluralCategory, PluralRuleType, PluralRules};
+use unic_langid::LanguageIdentifier;
+
+use crate::cache::FormatterCache;
+
+/// Locale specific data required to format numbers.
+///
+/// Patterns use `{0}` as a placeholder for the formatted number and `{1}`
+/// for the currency symbol or name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocaleData {
+    pub language_id: &'static str,
+    pub symbols: NumberSymbols,
+    pub percent_pattern: &'static str,
+    pub currency_patterns: CurrencyPatterns,
+    pub currency_name_patterns: PluralForms,
+    /// Sorted by currency code
+    pub currencies: &'static [CurrencyData],
+    /// Sorted by unit identifier
+    pub units: &'static [UnitData],
+    pub lists: ListData,
+    /// Empty if relative time is not used, see [`RelativeTimeUnit::VALUES`] for the units order
+    pub relative_times: &'static [RelativeTimeData],
+    /// Sorted by magnitude, empty if compact notation is not used
+    pub compact_short: &'static [CompactPattern],
+    /// Sorted by magnitude, empty if compact notation is not used
+    pub compact_long: &'static [CompactPattern],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberSymbols {
+    pub decimal_separator: &'static str,
+    pub grouping_separator: &'static str,
+    pub minus_sign_prefix: &'static str,
+    pub minus_sign_suffix: &'static str,
+    pub plus_sign_prefix: &'static str,
+    pub plus_sign_suffix: &'static str,
+    pub digits: [char; 10],
+    pub primary_grouping: u8,
+    pub secondary_grouping: u8,
+    pub min_grouping: u8,
+}
+
+/// Currency patterns, `alpha` variants are used when a currency symbol
+/// starting or ending with a letter is placed next to the number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurrencyPatterns {
+    pub standard: &'static str,
+    pub standard_negative: Option<&'static str>,
+    pub standard_alpha: &'static str,
+    pub standard_alpha_negative: Option<&'static str>,
+    pub accounting: &'static str,
+    pub accounting_negative: Option<&'static str>,
+    pub accounting_alpha: &'static str,
+    pub accounting_alpha_negative: Option<&'static str>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurrencyData {
+    pub code: &'static str,
+    pub symbol: &'static str,
+    pub narrow_symbol: &'static str,
+    pub fraction_digits: u8,
+    pub names: PluralForms,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnitData {
+    pub id: &'static str,
+    pub long: PluralForms,
+    pub short: PluralForms,
+    pub narrow: PluralForms,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PluralForms {
+    pub zero: Option<&'static str>,
+    pub one: Option<&'static str>,
+    pub two: Option<&'static str>,
+    pub few: Option<&'static str>,
+    pub many: Option<&'static str>,
+    pub other: &'static str,
+}
+
+impl PluralForms {
+    pub fn get(&self, category: &PluralCategory) -> &'static str {
+        match category {
+            PluralCategory::ZERO => self.zero,
+            PluralCategory::ONE => self.one,
+            PluralCategory::TWO => self.two,
+            PluralCategory::FEW => self.few,
+            PluralCategory::MANY => self.many,
+            PluralCategory::OTHER => None,
+        }
+        .unwrap_or(self.other)
+    }
+}
+
+/// List patterns use `{0}` and `{1}` as placeholders for the list items
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListPatterns {
+    pub start: &'static str,
+    pub middle: &'static str,
+    pub end: &'static str,
+    pub pair: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListStyles {
+    pub long: ListPatterns,
+    pub short: ListPatterns,
+    pub narrow: ListPatterns,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListData {
+    pub conjunction: ListStyles,
+    pub disjunction: ListStyles,
+    pub unit: ListStyles,
+}
+
+impl ListData {
+    pub fn patterns(&self, format: &ListFormat) -> &ListPatterns {
+        let styles = match format.list_type {
+            ListType::Conjunction => &self.conjunction,
+            ListType::Disjunction => &self.disjunction,
+            ListType::Unit => &self.unit,
+        };
+        match format.style {
+            ListStyle::Long => &styles.long,
+            ListStyle::Short => &styles.short,
+            ListStyle::Narrow => &styles.narrow,
+        }
+    }
+}
+
+/// Relative time patterns use `{0}` as a placeholder for the formatted number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelativeTimePatterns {
+    /// Offsets with special names, e.g. `(-1, "yesterday")`
+    pub relatives: &'static [(i8, &'static str)],
+    pub past: PluralForms,
+    pub future: PluralForms,
+}
+
+/// CLDR compact decimal patterns of numbers starting at the magnitude, patterns
+/// use `{0}` as a placeholder for the number divided by 10<sup>exponent</sup>,
+/// e.g. `{0}K` with exponent `3` for magnitudes `3` to `5` in English
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompactPattern {
+    pub magnitude: u8,
+    pub exponent: u8,
+    pub patterns: PluralForms,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelativeTimeData {
+    pub long: RelativeTimePatterns,
+    pub short: RelativeTimePatterns,
+    pub narrow: RelativeTimePatterns,
+}
+
+impl LocaleData {
+    pub fn currency(&self, code: &str) -> Option<&'static CurrencyData> {
+        self.currencies
+            .binary_search_by(|currency| currency.code.cmp(code))
+            .ok()
+            .map(|index| &self.currencies[index])
+    }
+
+    pub fn relative_time(
+        &self,
+        unit: &RelativeTimeUnit,
+        style: &RelativeTimeStyle,
+    ) -> Option<&'static RelativeTimePatterns> {
+        let index = RelativeTimeUnit::VALUES.iter().position(|u| u == unit)?;
+        self.relative_times.get(index).map(|data| match style {
+            RelativeTimeStyle::Long => &data.long,
+            RelativeTimeStyle::Short => &data.short,
+            RelativeTimeStyle::Narrow => &data.narrow,
+        })
+    }
+
+    pub fn compact_patterns(&self, display: &CompactDisplay) -> &'static [CompactPattern] {
+        match display {
+            CompactDisplay::Short => self.compact_short,
+            CompactDisplay::Long => self.compact_long,
+        }
+    }
+
+    /// Compact pattern for numbers of the magnitude, `None` if numbers of the magnitude
+    /// are formatted without compact notation
+    pub fn compact_pattern(
+        &self,
+        display: &CompactDisplay,
+        magnitude: i32,
+    ) -> Option<&'static CompactPattern> {
+        self.compact_patterns(display)
+            .iter()
+            .rev()
+            .find(|pattern| magnitude >= pattern.magnitude as i32)
+    }
+
+    pub fn unit(&self, id: &str) -> Option<&'static UnitData> {
+        self.units
+            .binary_search_by(|unit| unit.id.cmp(id))
+            .ok()
+            .map(|index| &self.units[index])
+    }
+
+    fn plural_category(&self, formatted_number: &str) -> PluralCategory {
+        plural_rules(self.language_id)
+            .as_ref()
+            .as_ref()
+            .zip(PluralOperands::try_from(formatted_number).ok())
+            .and_then(|(rules, operands)| rules.select(operands).ok())
+            .unwrap_or(PluralCategory::OTHER)
+    }
+}
+
+/// Locale independent data, used when no data is available for the requested locale
+pub const ROOT: LocaleData = LocaleData {
+    language_id: "und",
+    symbols: NumberSymbols {
+        decimal_separator: ".",
+        grouping_separator: ",",
+        minus_sign_prefix: "-",
+        minus_sign_suffix: "",
+        plus_sign_prefix: "+",
+        plus_sign_suffix: "",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        primary_grouping: 3,
+        secondary_grouping: 3,
+        min_grouping: 1,
+    },
+    percent_pattern: "{0}%",
+    currency_patterns: CurrencyPatterns {
+        standard: "{1}\u{a0}{0}",
+        standard_negative: None,
+        standard_alpha: "{1}\u{a0}{0}",
+        standard_alpha_negative: None,
+        accounting: "{1}\u{a0}{0}",
+        accounting_negative: None,
+        accounting_alpha: "{1}\u{a0}{0}",
+        accounting_alpha_negative: None,
+    },
+    currency_name_patterns: PluralForms {
+        zero: None,
+        one: None,
+        two: None,
+        few: None,
+        many: None,
+        other: "{0} {1}",
+    },
+    currencies: &[],
+    units: &[],
+    lists: ListData {
+        conjunction: ROOT_LIST_STYLES,
+        disjunction: ROOT_LIST_STYLES,
+        unit: ROOT_LIST_STYLES,
+    },
+    relative_times: &[],
+    compact_short: &[],
+    compact_long: &[],
+};
+
+const ROOT_LIST_STYLES: ListStyles = ListStyles {
+    long: ROOT_LIST_PATTERNS,
+    short: ROOT_LIST_PATTERNS,
+    narrow: ROOT_LIST_PATTERNS,
+};
+
+const ROOT_LIST_PATTERNS: ListPatterns = ListPatterns {
+    start: "{0}, {1}",
+    middle: "{0}, {1}",
+    end: "{0}, {1}",
+    pair: "{0}, {1}",
+};
+
+pub fn format(data: &LocaleData, value: &Value, out: &mut impl Write) -> std::fmt::Result {
+    match value {
+        Value::String(s) => out.write_str(s),
+        Value::Number {
+            value,
+            format: Some(format),
+        } => format_number(data, value, Some(format), out),
+        // numbers without explicit format are written as is, same as ICU4C formatter does
+        Value::Number {
+            value,
+            format: None,
+        } => out.write_str(&value.as_string()),
+        Value::RelativeTime { value, format } => format_relative_time(data, value, format, out),
+        Value::Bool(b) => write!(out, "{}", b),
+        Value::List(items) => format_list(data, items, &ListFormat::default(), out),
+        Value::FormattedList {
+            items,
+            format: list_format,
+        } => format_list(data, items, list_format, out),
+        Value::Custom(custom) => out.write_str(&custom.as_string(data.language_id)),
+        Value::Markup(fragment) => out.write_str(fragment),
+        Value::Empty => Ok(()),
+        Value::Error => write!(out, "#error#"),
+    }
+}
+
+fn format_list(
+    data: &LocaleData,
+    items: &[Value],
+    list_format: &ListFormat,
+    out: &mut impl Write,
+) -> std::fmt::Result {
+    let items = items
+        .iter()
+        .map(|item| {
+            let mut s = String::new();
+            format(data, item, &mut s).map(|_| s)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    write_list(out, data.lists.patterns(list_format), &items)
+}
+
+pub fn format_number(
+    data: &LocaleData,
+    value: &Number,
+    format: Option<&NumberFormat>,
+    out: &mut impl Write,
+) -> std::fmt::Result {
+    let default_format = NumberFormat::default();
+    let format = format.unwrap_or(&default_format);
+    if format.notation == Notation::Compact
+        && data.compact_patterns(&format.compact_display).is_empty()
+    {
+        // CLDR compact patterns are not loaded or embedded
+        return Err(std::fmt::Error);
+    }
+
+    let Some((negative, decimal)) = to_display_decimal(value) else {
+        return write_unformatted(data, value, out);
+    };
+
+    let default_fraction = format.style.default_fraction_digits();
+    let formatted = match &format.style {
+        NumberStyle::Decimal => format_decimal(data, negative, decimal, format, default_fraction)
+            .map(|number| out.write_str(&number.localized)),
+        NumberStyle::Percent => decimal
+            .mul_pow10(2)
+            .and_then(|decimal| format_decimal(data, negative, decimal, format, default_fraction))
+            .map(|number| write_pattern(out, data.percent_pattern, &number.localized, "")),
+        NumberStyle::Currency { code, style, sign } => {
+            format_currency(data, negative, decimal, format, code, style, sign, out)
+        }
+        NumberStyle::Unit { identifier, style } => {
+            format_decimal(data, negative, decimal, format, default_fraction).map(|number| {
+                let category = data.plural_category(&number.plain);
+                write_unit(data, identifier, style, category, &number.localized, out)
+            })
+        }
+    };
+    match formatted {
+        Some(result) => result,
+        None => write_unformatted(data, value, out),
+    }
+}
+
+/// Sign and decimal value of the number as it is displayed, the sign is separate
+/// so negative zero is displayed with sign.
+/// `None` for non-finite numbers and numbers out of [`Decimal`] range
+fn to_display_decimal(value: &Number) -> Option<(bool, Decimal)> {
+    let decimal = value.to_decimal()?;
+    let negative = match value {
+        Number::F64(n) => n.is_sign_negative(),
+        _ => decimal.is_negative(),
+    };
+    Some((negative, decimal))
+}
+
+/// Formats relative time, falls back to CLDR root patterns, i.e. `+1 d`,
+/// if there is no data for the unit
+pub fn format_relative_time(
+    data: &LocaleData,
+    value: &Number,
+    format: &RelativeTimeFormat,
+    out: &mut impl Write,
+) -> std::fmt::Result {
+    let Some(patterns) = data.relative_time(&format.unit, &format.style) else {
+        out.write_char(if value.as_f64().is_sign_negative() {
+            '-'
+        } else {
+            '+'
+        })?;
+        format_number(data, &value.abs(), None, out)?;
+        return write!(out, " {}", format.unit.root_symbol());
+    };
+
+    if format.numeric == RelativeTimeNumeric::Auto {
+        if let Some((_, name)) = i8::try_from(value)
+            .ok()
+            .and_then(|offset| patterns.relatives.iter().find(|(o, _)| *o == offset))
+        {
+            return out.write_str(name);
+        }
+    }
+
+    let number_format = NumberFormat::default();
+    let Some((past, number)) = to_display_decimal(value).and_then(|(past, decimal)| {
+        let default_fraction = number_format.style.default_fraction_digits();
+        format_decimal(
+            data,
+            false,
+            decimal.checked_abs()?,
+            &number_format,
+            default_fraction,
+        )
+        .map(|number| (past, number))
+    }) else {
+        return write_unformatted(data, value, out);
+    };
+    let category = data.plural_category(&number.plain);
+    let forms = if past {
+        &patterns.past
+    } else {
+        &patterns.future
+    };
+    write_pattern(out, forms.get(&category), &number.localized, "")
+}
+
+/// Formats currency amount, `None` if the rounded amount is out of [`Decimal`] range
+#[allow(clippy::too_many_arguments)]
+fn format_currency(
+    data: &LocaleData,
+    negative: bool,
+    decimal: Decimal,
+    format: &NumberFormat,
+    code: &CurrencyCode,
+    display_style: &CurrencyDisplayStyle,
+    sign: &CurrencySignMode,
+    out: &mut impl Write,
+) -> Option<std::fmt::Result> {
+    let code = code.to_string();
+    let currency = data.currency(&code);
+    let fraction_digits = currency
+        .map(|currency| currency.fraction_digits)
+        .map(usize::from)
+        .unwrap_or(format.style.default_fraction_digits().1);
+
+    let default_fraction = (fraction_digits, fraction_digits);
+    if *display_style == CurrencyDisplayStyle::Name {
+        let number = format_decimal(data, negative, decimal, format, default_fraction)?;
+        let category = data.plural_category(&number.plain);
+        let name = currency
+            .map(|currency| currency.names.get(&category))
+            .unwrap_or(&code);
+        return Some(write_pattern(
+            out,
+            data.currency_name_patterns.get(&category),
+            &number.localized,
+            name,
+        ));
+    }
+
+    let number = format_unsigned_decimal(data, decimal, format, default_fraction)?;
+
+    let symbol = match (display_style, currency) {
+        (CurrencyDisplayStyle::Symbol, Some(currency)) => currency.symbol,
+        (CurrencyDisplayStyle::NarrowSymbol, Some(currency)) => currency.narrow_symbol,
+        _ => &code,
+    };
+
+    let patterns = &data.currency_patterns;
+    let (positive, negative_pattern) = match sign {
+        CurrencySignMode::Standard => (patterns.standard, patterns.standard_negative),
+        CurrencySignMode::Accounting => (patterns.accounting, patterns.accounting_negative),
+    };
+    let (positive, negative_pattern) = if is_alpha_next_to_number(positive, symbol) {
+        match sign {
+            CurrencySignMode::Standard => {
+                (patterns.standard_alpha, patterns.standard_alpha_negative)
+            }
+            CurrencySignMode::Accounting => (
+                patterns.accounting_alpha,
+                patterns.accounting_alpha_negative,
+            ),
+        }
+    } else {
+        (positive, negative_pattern)
+    };
+
+    let (number, sign) = (
+        &number.localized,
+        display_sign(negative, &number, format.sign_display),
+    );
+    Some(match (sign, negative_pattern) {
+        (Some(Sign::Minus), Some(pattern)) => write_pattern(out, pattern, number, symbol),
+        (Some(Sign::Minus), None) => out
+            .write_str(data.symbols.minus_sign_prefix)
+            .and_then(|_| write_pattern(out, positive, number, symbol))
+            .and_then(|_| out.write_str(data.symbols.minus_sign_suffix)),
+        (Some(Sign::Plus), _) => out
+            .write_str(data.symbols.plus_sign_prefix)
+            .and_then(|_| write_pattern(out, positive, number, symbol))
+            .and_then(|_| out.write_str(data.symbols.plus_sign_suffix)),
+        (None, _) => write_pattern(out, positive, number, symbol),
+    })
+}
+
+fn write_unit(
+    data: &LocaleData,
+    identifier: &UnitIdentifier,
+    style: &UnitDisplayStyle,
+    category: PluralCategory,
+    number: &str,
+    out: &mut impl Write,
+) -> std::fmt::Result {
+    match identifier {
+        UnitIdentifier::Derived(numerator, denominator) => {
+            let mut numerator_str = String::new();
+            write_unit(data, numerator, style, category, number, &mut numerator_str)?;
+            let mut denominator_str = String::new();
+            write_unit(
+                data,
+                denominator,
+                style,
+                PluralCategory::ONE,
+                "",
+                &mut denominator_str,
+            )?;
+            write!(out, "{}/{}", numerator_str, denominator_str.trim())
+        }
+        unit => {
+            let unit_id = unit.to_string();
+            if let Some(unit_data) = data.unit(&unit_id) {
+                let forms = match style {
+                    UnitDisplayStyle::Long => &unit_data.long,
+                    UnitDisplayStyle::Short => &unit_data.short,
+                    UnitDisplayStyle::Narrow => &unit_data.narrow,
+                };
+                write_pattern(out, forms.get(&category), number, "")
+            } else if number.is_empty() {
+                out.write_str(&unit_id)
+            } else {
+                write!(out, "{} {}", number, unit_id)
+            }
+        }
+    }
+}
+
+enum Sign {
+    Minus,
+    Plus,
+}
+
+/// Formatted number without sign
+struct Formatted {
+    /// Localized digits and separators
+    localized: String,
+    /// ASCII digits with `.` separator, used to select plural forms
+    plain: String,
+    /// Whether the rounded number is zero
+    is_zero: bool,
+}
+
+/// Sign of the rounded number to display
+fn display_sign(negative: bool, number: &Formatted, display: SignDisplay) -> Option<Sign> {
+    let sign = if negative { Sign::Minus } else { Sign::Plus };
+    match display {
+        SignDisplay::Auto => negative.then_some(sign),
+        SignDisplay::Always => Some(sign),
+        SignDisplay::ExceptZero => (!number.is_zero).then_some(sign),
+        SignDisplay::Negative => (negative && !number.is_zero).then_some(sign),
+        SignDisplay::Never => None,
+    }
+}
+
+/// Formats the decimal according to the number format, fraction digits defaults
+/// are provided by the number style. `negative` is separate from the decimal sign,
+/// so negative zero is displayed with sign.
+///
+/// `None` if the rounded decimal is out of [`Decimal`] range
+fn format_decimal(
+    data: &LocaleData,
+    negative: bool,
+    decimal: Decimal,
+    format: &NumberFormat,
+    default_fraction: (usize, usize),
+) -> Option<Formatted> {
+    let number = format_unsigned_decimal(data, decimal, format, default_fraction)?;
+    let symbols = &data.symbols;
+    let localized = match display_sign(negative, &number, format.sign_display) {
+        Some(Sign::Minus) => format!(
+            "{}{}{}",
+            symbols.minus_sign_prefix, number.localized, symbols.minus_sign_suffix
+        ),
+        Some(Sign::Plus) => format!(
+            "{}{}{}",
+            symbols.plus_sign_prefix, number.localized, symbols.plus_sign_suffix
+        ),
+        None => number.localized,
+    };
+    Some(Formatted {
+        localized,
+        ..number
+    })
+}
+
+/// Same as [`format_decimal`], but without sign
+fn format_unsigned_decimal(
+    data: &LocaleData,
+    decimal: Decimal,
+    format: &NumberFormat,
+    default_fraction: (usize, usize),
+) -> Option<Formatted> {
+    let (decimal, affix, default_fraction) =
+        apply_notation(data, decimal, format, default_fraction)?;
+    let mode = format.rounding_mode;
+    let (decimal, min_fraction) = if has_significant_digits(format) {
+        let min_significant = format.minimum_significant_digits.unwrap_or(1);
+        let decimal = decimal
+            .round_significant_with(max_significant_digits(format) as u32, mode)?
+            .normalize();
+        let min_fraction = fraction_digits_for_significant(&decimal, min_significant);
+        (decimal, min_fraction)
+    } else {
+        let (min_fraction, max_fraction) = fraction_digits(format, default_fraction);
+        let increment = u32::try_from(format.rounding_increment).ok()?;
+        let scale = u32::try_from(max_fraction).unwrap_or(u32::MAX);
+        let decimal = decimal
+            .round_increment_with(increment, scale, mode)?
+            .normalize();
+        (decimal, min_fraction)
+    };
+    let min_fraction = if format.trailing_zero_display == TrailingZeroDisplay::StripIfInteger
+        && decimal.is_integer()
+    {
+        0
+    } else {
+        min_fraction
+    };
+
+    // zeros are padded as text, fraction digits are not limited by the decimal scale
+    let digits = decimal.mantissa().unsigned_abs().to_string();
+    let scale = decimal.scale() as usize;
+    let (integer, fraction) = if digits.len() > scale {
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+        (
+            integer.trim_start_matches('0').to_string(),
+            fraction.to_string(),
+        )
+    } else {
+        (String::new(), format!("{:0>scale$}", digits))
+    };
+    let min_integer = format.minimum_integer_digits.unwrap_or(1);
+    let integer = format!("{:0>min_integer$}", integer);
+    let fraction = format!("{:0<min_fraction$}", fraction);
+
+    let symbols = &data.symbols;
+    let min_grouping = match format.use_grouping {
+        GroupingStyle::Off => None,
+        GroupingStyle::Always => Some(1),
+        // same as ICU, compact notation groups only 5+ digit numbers by default
+        GroupingStyle::Auto if format.notation == Notation::Compact => {
+            Some(symbols.min_grouping.max(2))
+        }
+        GroupingStyle::Auto => Some(symbols.min_grouping.max(1)),
+        GroupingStyle::Min2 => Some(symbols.min_grouping.max(2)),
+    };
+
+    let mut localized = String::new();
+    let primary = symbols.primary_grouping as usize;
+    let secondary = symbols.secondary_grouping.max(1) as usize;
+    let grouping = primary > 0
+        && min_grouping
+            .is_some_and(|min_grouping| integer.len() >= primary + min_grouping as usize);
+    for (index, digit) in integer.bytes().enumerate() {
+        let position = integer.len() - index;
+        if grouping
+            && index > 0
+            && position >= primary
+            && (position - primary).is_multiple_of(secondary)
+        {
+            localized.push_str(symbols.grouping_separator);
+        }
+        localized.push(symbols.digits[(digit - b'0') as usize]);
+    }
+
+    if !fraction.is_empty() {
+        localized.push_str(symbols.decimal_separator);
+        localized.extend(
+            fraction
+                .bytes()
+                .map(|digit| symbols.digits[(digit - b'0') as usize]),
+        );
+    }
+
+    let plain = match (integer.is_empty(), fraction.is_empty()) {
+        (true, true) => "0".to_string(),
+        (true, false) => format!("0.{}", fraction),
+        (false, true) => integer,
+        (false, false) => format!("{}.{}", integer, fraction),
+    };
+    let localized = match affix {
+        NotationAffix::None => localized,
+        NotationAffix::Exponent(suffix) => localized + &suffix,
+        NotationAffix::Compact(patterns) => {
+            let mut compact = String::new();
+            let pattern = patterns.get(&data.plural_category(&plain));
+            write_pattern(&mut compact, pattern, &localized, "").ok()?;
+            compact
+        }
+    };
+    Some(Formatted {
+        localized,
+        plain,
+        is_zero: decimal.mantissa() == 0,
+    })
+}
+
+fn has_significant_digits(format: &NumberFormat) -> bool {
+    format.minimum_significant_digits.is_some() || format.maximum_significant_digits.is_some()
+}
+
+fn max_significant_digits(format: &NumberFormat) -> usize {
+    format
+        .maximum_significant_digits
+        .unwrap_or(21)
+        .max(format.minimum_significant_digits.unwrap_or(1))
+}
+
+/// Number of fraction digits required to display at least `min_significant` digits
+fn fraction_digits_for_significant(decimal: &Decimal, min_significant: usize) -> usize {
+    match decimal.magnitude() {
+        Some(magnitude) => (min_significant as i64 - 1 - magnitude as i64).max(0) as usize,
+        None => min_significant.saturating_sub(1),
+    }
+}
+
+/// Minimum and maximum fraction digits of the format, unspecified bounds are taken from defaults
+fn fraction_digits(
+    format: &NumberFormat,
+    (default_min_fraction, default_max_fraction): (usize, usize),
+) -> (usize, usize) {
+    match (
+        format.minimum_fraction_digits,
+        format.maximum_fraction_digits,
+    ) {
+        (Some(min), Some(max)) => (min, max.max(min)),
+        (Some(min), None) => (min, default_max_fraction.max(min)),
+        (None, Some(max)) => (default_min_fraction.min(max), max),
+        (None, None) => (default_min_fraction, default_max_fraction),
+    }
+}
+
+/// Notation specific part of the formatted number
+enum NotationAffix {
+    None,
+    /// Exponent of scientific and engineering notations, i.e. `E3`
+    Exponent(String),
+    /// Compact patterns, selected by plural category of the scaled number
+    Compact(&'static PluralForms),
+}
+
+/// Scales the decimal to the mantissa of the format notation, returns the scaled decimal,
+/// the notation affix and fraction digits defaults for the mantissa.
+///
+/// The decimal is rounded before scaling, so rounding can't push the mantissa out of its range,
+/// e.g. `9.9996` is `1E1` rather than `10E0`. Without digit options compact notation
+/// rounds to integers above 100 and to 2 significant digits otherwise, like ICU does
+fn apply_notation(
+    data: &LocaleData,
+    decimal: Decimal,
+    format: &NumberFormat,
+    default_fraction: (usize, usize),
+) -> Option<(Decimal, NotationAffix, (usize, usize))> {
+    let exponent_of = |magnitude: i32| match format.notation {
+        Notation::Standard => 0,
+        Notation::Scientific => magnitude,
+        Notation::Engineering => magnitude.div_euclid(3) * 3,
+        Notation::Compact => data
+            .compact_pattern(&format.compact_display, magnitude)
+            .map_or(0, |pattern| pattern.exponent as i32),
+    };
+    if format.notation == Notation::Standard {
+        return Some((decimal, NotationAffix::None, default_fraction));
+    }
+    let compact_rounding = format.notation == Notation::Compact
+        && !has_significant_digits(format)
+        && format.minimum_fraction_digits.is_none()
+        && format.maximum_fraction_digits.is_none();
+
+    let mode = format.rounding_mode;
+    let decimal = match decimal.magnitude() {
+        Some(magnitude) => {
+            let exponent = exponent_of(magnitude);
+            if has_significant_digits(format) {
+                decimal.round_significant_with(max_significant_digits(format) as u32, mode)?
+            } else if compact_rounding {
+                let integer_digits = (magnitude - exponent + 1).max(1) as u32;
+                decimal.round_significant_with(integer_digits.max(2), mode)?
+            } else {
+                let (_, max_fraction) = fraction_digits(format, default_fraction);
+                decimal.round_at(max_fraction as i64 - exponent as i64, mode)?
+            }
+        }
+        None => decimal,
+    };
+
+    let magnitude = decimal.magnitude();
+    let exponent = magnitude.map(exponent_of).unwrap_or(0);
+    let decimal = if exponent >= 0 {
+        decimal.div_pow10(exponent as u32)?
+    } else {
+        decimal.mul_pow10(exponent.unsigned_abs())?
+    };
+
+    let affix = if format.notation == Notation::Compact {
+        magnitude
+            .and_then(|magnitude| data.compact_pattern(&format.compact_display, magnitude))
+            .map_or(NotationAffix::None, |pattern| {
+                NotationAffix::Compact(&pattern.patterns)
+            })
+    } else {
+        let symbols = &data.symbols;
+        let mut suffix = String::from("E");
+        if exponent < 0 {
+            suffix.push_str(symbols.minus_sign_prefix);
+        }
+        suffix.extend(
+            exponent
+                .unsigned_abs()
+                .to_string()
+                .bytes()
+                .map(|digit| symbols.digits[(digit - b'0') as usize]),
+        );
+        if exponent < 0 {
+            suffix.push_str(symbols.minus_sign_suffix);
+        }
+        NotationAffix::Exponent(suffix)
+    };
+
+    let default_fraction = if compact_rounding {
+        (0, decimal.normalize().scale() as usize)
+    } else {
+        default_fraction
+    };
+    Some((decimal, affix, default_fraction))
+}
+
+/// Writes numbers which can't be formatted, i.e. non-finite numbers and numbers
+/// out of [`Decimal`] range
+fn write_unformatted(data: &LocaleData, value: &Number, out: &mut impl Write) -> std::fmt::Result {
+    if value.as_f64().is_finite() {
+        out.write_str(&value.as_string())
+    } else {
+        write_non_finite(data, value, out)
+    }
+}
+
+fn write_non_finite(data: &LocaleData, value: &Number, out: &mut impl Write) -> std::fmt::Result {
+    let value = value.as_f64();
+    if value.is_nan() {
+        out.write_str("NaN")
+    } else if value.is_sign_negative() {
+        write!(
+            out,
+            "{}∞{}",
+            data.symbols.minus_sign_prefix, data.symbols.minus_sign_suffix
+        )
+    } else {
+        out.write_str("∞")
+    }
+}
+
+fn write_pattern(out: &mut impl Write, pattern: &str, arg0: &str, arg1: &str) -> std::fmt::Result {
+    let mut rest = pattern;
+    while let Some(start) = rest.find('{') {
+        out.write_str(&rest[..start])?;
+        let placeholder = &rest[start..];
+        if placeholder.starts_with("{0}") {
+            out.write_str(arg0)?;
+        } else if placeholder.starts_with("{1}") {
+            out.write_str(arg1)?;
+        } else {
+            out.write_char('{')?;
+            rest = &placeholder[1..];
+            continue;
+        }
+        rest = &placeholder[3..];
+    }
+    out.write_str(rest)
+}
+
+/// Joins items applying `end` pattern to the last two items, then `middle` patterns
+/// and `start` pattern to the first item, or `pair` pattern if there are only two items
+fn write_list(out: &mut impl Write, patterns: &ListPatterns, items: &[String]) -> std::fmt::Result {
+    match items {
+        [] => Ok(()),
+        [item] => out.write_str(item),
+        [first, second] => write_pattern(out, patterns.pair, first, second),
+        [first, middle @ .., second_last, last] => {
+            let mut tail = String::new();
+            write_pattern(&mut tail, patterns.end, second_last, last)?;
+            for item in middle.iter().rev() {
+                let mut s = String::new();
+                write_pattern(&mut s, patterns.middle, item, &tail)?;
+                tail = s;
+            }
+            write_pattern(out, patterns.start, first, &tail)
+        }
+    }
+}
+
+fn is_alpha_next_to_number(pattern: &str, symbol: &str) -> bool {
+    if pattern.contains("{0}{1}") {
+        symbol.chars().next().is_some_and(char::is_alphabetic)
+    } else if pattern.contains("{1}{0}") {
+        symbol.chars().last().is_some_and(char::is_alphabetic)
+    } else {
+        false
+    }
+}
+
+/// Cardinal plural rules of the language, created once per language identifier
+fn plural_rules(language_id: &'static str) -> Arc<Option<PluralRules>> {
+    static CACHE: OnceLock<FormatterCache<&'static str, Option<PluralRules>>> = OnceLock::new();
+    CACHE
+        .get_or_init(FormatterCache::default)
+        .get_or_try_insert_with(&language_id, || {
+            Ok::<_, Infallible>(create_plural_rules(language_id))
+        })
+        .unwrap_or_else(|never| match never {})
+}
+
+fn create_plural_rules(language_id: &str) -> Option<PluralRules> {
+    let langid: LanguageIdentifier = language_id.parse().ok()?;
+    let rule_type = PluralRuleType::CARDINAL;
+    PluralRules::create(langid.clone(), rule_type)
+        .or_else(|_| {
+            PluralRules::create(
+                LanguageIdentifier::from_parts(langid.language, langid.script, None, &[]),
+                rule_type,
+            )
+        })
+        .or_else(|_| {
+            PluralRules::create(
+                LanguageIdentifier::from_parts(langid.language, None, None, &[]),
+                rule_type,
+            )
+        })
+        .ok()
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use intl_pluralrules::PluralCategory;
+
+    use super::{plural_rules, LocaleData, ROOT};
+
+    #[test]
+    fn test_plural_rules_cache() {
+        assert!(Arc::ptr_eq(&plural_rules("pl"), &plural_rules("pl")));
+        assert!(plural_rules("und").is_none());
+        let polish = LocaleData {
+            language_id: "pl",
+            ..ROOT
+        };
+        assert_eq!(PluralCategory::FEW, polish.plural_category("2"));
+        assert_eq!(PluralCategory::OTHER, ROOT.plural_category("2"));
+    }
+}
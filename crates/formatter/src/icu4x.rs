@@ -0,0 +1,1199 @@
+//! [`LocaleData`] loader backed by ICU4X compiled CLDR data.
+
+use std::{
+    collections::HashMap,
+    fmt::Write,
+    sync::{Mutex, OnceLock},
+};
+
+use fixed_decimal::Decimal;
+use fluent_static_value::{
+    number::format::{CurrencyCode, UnitIdentifier},
+    NumberFormat, Value,
+};
+use icu_decimal::provider::{
+    CompactPatterns, DecimalCompactLongV1, DecimalCompactShortV1, DecimalDigitsV1, DecimalSymbolsV1,
+};
+use icu_experimental::dimension::provider::{
+    currency::{
+        essentials::CurrencyEssentialsV1, extended::CurrencyExtendedDataV1,
+        fractions::CurrencyFractionsV1, patterns::CurrencyPatternsDataV1,
+        symbols::CurrencySymbolsV1,
+    },
+    percent::PercentEssentialsV1,
+    units::categorized_display_names::*,
+};
+use icu_experimental::relativetime::provider::*;
+use icu_list::{
+    options::{ListFormatterOptions, ListLength},
+    ListFormatter,
+};
+use icu_locale_core::Locale;
+use icu_pattern::{DoublePlaceholderKey, PatternItem, SinglePlaceholder, SinglePlaceholderPattern};
+use icu_plurals::{provider::PluralElementsPackedCow, PluralCategory, PluralOperands, PluralRules};
+use icu_provider::{prelude::*, DataMarkerAttributes, DataRequestMetadata};
+
+use crate::cldr::{
+    self, CompactPattern, CurrencyData, CurrencyPatterns, ListData, ListPatterns, ListStyles,
+    LocaleData, NumberSymbols, PluralForms, RelativeTimeData, RelativeTimePatterns, UnitData,
+};
+
+pub fn format(locale: &str, value: &Value, out: &mut impl Write) -> std::fmt::Result {
+    cldr::format(locale_data(locale).unwrap_or(&cldr::ROOT), value, out)
+}
+
+/// Loads locale data ahead of use, number formats need no preparation with ICU4X
+pub fn prewarm(locale: &str, _formats: &[NumberFormat]) -> std::fmt::Result {
+    locale_data(locale).map(|_| ()).ok_or(std::fmt::Error)
+}
+
+/// Returns data for all known currencies and units, loaded once per locale
+pub fn locale_data(locale: &str) -> Option<&'static LocaleData> {
+    static CACHE: OnceLock<Mutex<HashMap<String, Option<&'static LocaleData>>>> = OnceLock::new();
+    let mut cache = CACHE
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    *cache.entry(locale.to_string()).or_insert_with(|| {
+        load(locale, CurrencyCode::VALUES, UnitIdentifier::SIMPLE)
+            .map(|data| &*Box::leak(Box::new(data)))
+    })
+}
+
+/// Loads data for the locale, including only the listed currencies and units.
+///
+/// Loaded strings are leaked to get `'static` data
+pub fn load(
+    locale: &str,
+    currencies: &[CurrencyCode],
+    units: &[UnitIdentifier],
+) -> Option<LocaleData> {
+    let locale: Locale = locale.parse().ok()?;
+    let data_locale = DataLocale::from(&locale);
+    let plural_rules = PluralRules::try_new_cardinal((&locale).into()).ok()?;
+
+    let symbols: DataResponse<DecimalSymbolsV1> = icu_decimal::provider::Baked
+        .load(request(&data_locale, None))
+        .ok()?;
+    let symbols = symbols.payload.get();
+    let digits: DataResponse<DecimalDigitsV1> = icu_decimal::provider::Baked
+        .load(request(&Default::default(), Some(symbols.numsys())))
+        .ok()?;
+
+    let percent: DataResponse<PercentEssentialsV1> = icu_experimental::provider::Baked
+        .load(request(&data_locale, None))
+        .ok()?;
+    let percent_pattern = percent
+        .payload
+        .get()
+        .unsigned_pattern
+        .iter()
+        .map(|item| match item {
+            PatternItem::Literal(s) => s.to_string(),
+            PatternItem::Placeholder(_) => "{0}".to_string(),
+        })
+        .collect::<String>();
+
+    let currency: DataResponse<CurrencyEssentialsV1> = icu_experimental::provider::Baked
+        .load(request(&data_locale, None))
+        .ok()?;
+    let currency = currency.payload.get();
+    let currency_pattern = |index: u8| {
+        currency
+            .patterns
+            .get(index as usize)
+            .map(|pattern| leak(double_placeholder_pattern(pattern.iter())))
+    };
+    let currency_patterns = CurrencyPatterns {
+        standard: currency_pattern(currency.indices.standard)?,
+        standard_negative: currency
+            .indices
+            .standard_negative
+            .and_then(currency_pattern),
+        standard_alpha: currency_pattern(currency.indices.standard_alpha_next_to_number)?,
+        standard_alpha_negative: currency
+            .indices
+            .standard_alpha_next_to_number_negative
+            .and_then(currency_pattern),
+        accounting: currency_pattern(currency.indices.accounting_positive)?,
+        accounting_negative: currency
+            .indices
+            .accounting_negative
+            .and_then(currency_pattern),
+        accounting_alpha: currency_pattern(
+            currency.indices.accounting_alpha_next_to_number_positive,
+        )?,
+        accounting_alpha_negative: currency
+            .indices
+            .accounting_alpha_next_to_number_negative
+            .and_then(currency_pattern),
+    };
+
+    let name_patterns: DataResponse<CurrencyPatternsDataV1> = icu_experimental::provider::Baked
+        .load(request(&data_locale, None))
+        .ok()?;
+    let currency_name_patterns = plural_forms(&plural_rules, |operands| {
+        double_placeholder_pattern(
+            name_patterns
+                .payload
+                .get()
+                .get(operands, &plural_rules)
+                .iter(),
+        )
+    });
+
+    let mut currencies = currencies
+        .iter()
+        .map(|code| load_currency(&data_locale, &plural_rules, code))
+        .collect::<Vec<_>>();
+    currencies.sort_by_key(|currency| currency.code);
+
+    let mut units = units
+        .iter()
+        .filter_map(|unit| load_unit(&data_locale, &plural_rules, unit))
+        .collect::<Vec<_>>();
+    units.sort_by_key(|unit| unit.id);
+
+    Some(LocaleData {
+        language_id: leak(locale.id.to_string()),
+        symbols: NumberSymbols {
+            decimal_separator: leak(symbols.decimal_separator().to_string()),
+            grouping_separator: leak(symbols.grouping_separator().to_string()),
+            minus_sign_prefix: leak(symbols.minus_sign_prefix().to_string()),
+            minus_sign_suffix: leak(symbols.minus_sign_suffix().to_string()),
+            plus_sign_prefix: leak(symbols.plus_sign_prefix().to_string()),
+            plus_sign_suffix: leak(symbols.plus_sign_suffix().to_string()),
+            digits: *digits.payload.get(),
+            primary_grouping: symbols.grouping_sizes.primary,
+            secondary_grouping: symbols.grouping_sizes.secondary,
+            min_grouping: symbols.grouping_sizes.min_grouping,
+        },
+        percent_pattern: leak(percent_pattern),
+        currency_patterns,
+        currency_name_patterns,
+        currencies: Box::leak(currencies.into_boxed_slice()),
+        units: Box::leak(units.into_boxed_slice()),
+        lists: ListData {
+            conjunction: load_list_styles(&locale, ListFormatter::try_new_and)?,
+            disjunction: load_list_styles(&locale, ListFormatter::try_new_or)?,
+            unit: load_list_styles(&locale, ListFormatter::try_new_unit)?,
+        },
+        relative_times: Box::leak(
+            load_relative_times(&data_locale, &plural_rules)?.into_boxed_slice(),
+        ),
+        compact_short: Box::leak(
+            load_compact_patterns::<DecimalCompactShortV1>(&data_locale, &plural_rules)?
+                .into_boxed_slice(),
+        ),
+        compact_long: Box::leak(
+            load_compact_patterns::<DecimalCompactLongV1>(&data_locale, &plural_rules)?
+                .into_boxed_slice(),
+        ),
+    })
+}
+
+fn load_compact_patterns<M>(
+    data_locale: &DataLocale,
+    plural_rules: &PluralRules,
+) -> Option<Vec<CompactPattern>>
+where
+    M: DataMarker<DataStruct = CompactPatterns<'static, SinglePlaceholder>>,
+    icu_decimal::provider::Baked: DataProvider<M>,
+{
+    let response: DataResponse<M> = icu_decimal::provider::Baked
+        .load(request(data_locale, None))
+        .ok()?;
+    let patterns = response
+        .payload
+        .get()
+        .0
+        .iter()
+        .map(|entry| CompactPattern {
+            magnitude: entry.sized,
+            // the metadata is the number of zeros in the pattern minus 1
+            exponent: entry.sized - entry.variable.get_default().0.get(),
+            patterns: plural_forms(plural_rules, |operands| {
+                single_placeholder_pattern(entry.variable.get(operands, plural_rules).1)
+            }),
+        })
+        .collect();
+    Some(patterns)
+}
+
+/// Loads relative time patterns for all units in [`RelativeTimeUnit::VALUES`] order
+///
+/// [`RelativeTimeUnit::VALUES`]: fluent_static_value::relative_time::RelativeTimeUnit::VALUES
+fn load_relative_times(
+    data_locale: &DataLocale,
+    plural_rules: &PluralRules,
+) -> Option<Vec<RelativeTimeData>> {
+    macro_rules! load {
+        ($(($long:ty, $short:ty, $narrow:ty)),*) => {
+            vec![$(
+                RelativeTimeData {
+                    long: load_relative_time_patterns::<$long>(data_locale, plural_rules)?,
+                    short: load_relative_time_patterns::<$short>(data_locale, plural_rules)?,
+                    narrow: load_relative_time_patterns::<$narrow>(data_locale, plural_rules)?,
+                }
+            ),*]
+        };
+    }
+
+    Some(load!(
+        (
+            LongSecondRelativeV1,
+            ShortSecondRelativeV1,
+            NarrowSecondRelativeV1
+        ),
+        (
+            LongMinuteRelativeV1,
+            ShortMinuteRelativeV1,
+            NarrowMinuteRelativeV1
+        ),
+        (
+            LongHourRelativeV1,
+            ShortHourRelativeV1,
+            NarrowHourRelativeV1
+        ),
+        (LongDayRelativeV1, ShortDayRelativeV1, NarrowDayRelativeV1),
+        (
+            LongWeekRelativeV1,
+            ShortWeekRelativeV1,
+            NarrowWeekRelativeV1
+        ),
+        (
+            LongMonthRelativeV1,
+            ShortMonthRelativeV1,
+            NarrowMonthRelativeV1
+        ),
+        (
+            LongQuarterRelativeV1,
+            ShortQuarterRelativeV1,
+            NarrowQuarterRelativeV1
+        ),
+        (
+            LongYearRelativeV1,
+            ShortYearRelativeV1,
+            NarrowYearRelativeV1
+        )
+    ))
+}
+
+fn load_relative_time_patterns<M>(
+    data_locale: &DataLocale,
+    plural_rules: &PluralRules,
+) -> Option<RelativeTimePatterns>
+where
+    M: DataMarker<DataStruct = RelativeTimePatternData<'static>>,
+    icu_experimental::provider::Baked: DataProvider<M>,
+{
+    let response: DataResponse<M> = icu_experimental::provider::Baked
+        .load(request(data_locale, None))
+        .ok()?;
+    let data = response.payload.get();
+    let relatives = data
+        .relatives
+        .iter()
+        .map(|(offset, name)| (*offset, leak(name.to_string())))
+        .collect::<Vec<_>>();
+    Some(RelativeTimePatterns {
+        relatives: Box::leak(relatives.into_boxed_slice()),
+        past: single_placeholder_plural_forms(plural_rules, &data.past),
+        future: single_placeholder_plural_forms(plural_rules, &data.future),
+    })
+}
+
+fn single_placeholder_plural_forms(
+    plural_rules: &PluralRules,
+    patterns: &PluralElementsPackedCow<'_, SinglePlaceholderPattern>,
+) -> PluralForms {
+    plural_forms(plural_rules, |operands| {
+        single_placeholder_pattern(patterns.get(operands, plural_rules))
+    })
+}
+
+fn single_placeholder_pattern(pattern: &SinglePlaceholderPattern) -> String {
+    pattern
+        .iter()
+        .map(|item| match item {
+            PatternItem::Literal(s) => s.to_string(),
+            PatternItem::Placeholder(_) => "{0}".to_string(),
+        })
+        .collect()
+}
+
+type ListFormatterConstructor = fn(
+    icu_list::ListFormatterPreferences,
+    ListFormatterOptions,
+) -> Result<ListFormatter, DataError>;
+
+fn load_list_styles(locale: &Locale, constructor: ListFormatterConstructor) -> Option<ListStyles> {
+    let patterns = |length: ListLength| {
+        constructor(
+            locale.into(),
+            ListFormatterOptions::default().with_length(length),
+        )
+        .ok()
+        .map(|formatter| list_patterns(&formatter))
+    };
+    Some(ListStyles {
+        long: patterns(ListLength::Wide)?,
+        short: patterns(ListLength::Short)?,
+        narrow: patterns(ListLength::Narrow)?,
+    })
+}
+
+/// Extracts patterns by formatting lists of placeholder characters,
+/// conditional patterns (e.g. Spanish "y" before "i") are reduced to their default form
+fn list_patterns(formatter: &ListFormatter) -> ListPatterns {
+    const ITEMS: [&str; 4] = ["\u{e000}", "\u{e001}", "\u{e002}", "\u{e003}"];
+    let pattern = |separator: &str| leak(format!("{{0}}{}{{1}}", separator));
+
+    let pair = formatter.format_to_string(ITEMS[..2].iter());
+    let list = formatter.format_to_string(ITEMS.iter());
+    let separators: Vec<&str> = list
+        .split(|c| ('\u{e000}'..='\u{e003}').contains(&c))
+        .collect();
+    ListPatterns {
+        start: pattern(separators[1]),
+        middle: pattern(separators[2]),
+        end: pattern(separators[3]),
+        pair: pattern(pair.trim_start_matches(ITEMS[0]).trim_end_matches(ITEMS[1])),
+    }
+}
+
+fn load_currency(
+    data_locale: &DataLocale,
+    plural_rules: &PluralRules,
+    code: &CurrencyCode,
+) -> CurrencyData {
+    let code = code.to_string();
+    let symbol = |width: &str| {
+        let response: Option<DataResponse<CurrencySymbolsV1>> = icu_experimental::provider::Baked
+            .load(request(data_locale, Some(&format!("{}/{}", width, code))))
+            .ok();
+        response.map(|response| response.payload.get().as_str().to_string())
+    };
+    let short_symbol = symbol("s");
+    let narrow_symbol = symbol("n");
+
+    let fractions: Option<DataResponse<CurrencyFractionsV1>> = icu_experimental::provider::Baked
+        .load(Default::default())
+        .ok();
+    let fraction_digits = fractions
+        .and_then(|fractions| {
+            let fractions = fractions.payload.get();
+            fractions
+                .fractions
+                .iter_copied_values()
+                .find(|(key, _)| key.try_into_tinystr().is_ok_and(|key| key.as_str() == code))
+                .map(|(_, info)| info)
+                .or(Some(fractions.default))
+        })
+        .map(|info| info.digits)
+        .unwrap_or(2);
+
+    let names: Option<DataResponse<CurrencyExtendedDataV1>> = icu_experimental::provider::Baked
+        .load(request(data_locale, Some(&code)))
+        .ok();
+    let names = match names {
+        Some(names) => plural_forms(plural_rules, |operands| {
+            names.payload.get().get(operands, plural_rules).to_string()
+        }),
+        None => PluralForms {
+            other: leak(code.clone()),
+            ..EMPTY_PLURAL_FORMS
+        },
+    };
+
+    CurrencyData {
+        symbol: leak(short_symbol.clone().unwrap_or_else(|| code.clone())),
+        narrow_symbol: leak(
+            narrow_symbol
+                .or(short_symbol)
+                .unwrap_or_else(|| code.clone()),
+        ),
+        code: leak(code),
+        fraction_digits,
+        names,
+    }
+}
+
+fn load_unit(
+    data_locale: &DataLocale,
+    plural_rules: &PluralRules,
+    unit: &UnitIdentifier,
+) -> Option<UnitData> {
+    let id = unit.to_string();
+    let forms = |width: &str| {
+        let display_names = load_unit_display_names(data_locale, &format!("{}-{}", width, id))?;
+        Some(plural_forms(plural_rules, |operands| {
+            display_names
+                .get()
+                .get(operands, plural_rules)
+                .iter()
+                .map(|item| match item {
+                    PatternItem::Literal(s) => s.to_string(),
+                    PatternItem::Placeholder(_) => "{0}".to_string(),
+                })
+                .collect()
+        }))
+    };
+    Some(UnitData {
+        long: forms("long")?,
+        short: forms("short")?,
+        narrow: forms("narrow")?,
+        id: leak(id),
+    })
+}
+
+type UnitsDisplayNamesPayload = DataPayload<UnitsNamesLengthCoreV1>;
+
+/// Unit names are split by category and unit popularity, `core` data falls back to the root
+/// locale for units not used in the region, so the first locale specific result is preferred
+fn load_unit_display_names(
+    data_locale: &DataLocale,
+    attributes: &str,
+) -> Option<UnitsDisplayNamesPayload> {
+    let req = request(data_locale, Some(attributes));
+    let provider = icu_experimental::provider::Baked;
+
+    macro_rules! load {
+        ($($marker:ty),*) => {
+            [$(
+                DataProvider::<$marker>::load(&provider, req)
+                    .ok()
+                    .map(|response| (response.metadata.locale, response.payload.cast::<UnitsNamesLengthCoreV1>()))
+            ),*]
+        };
+    }
+
+    let responses = load!(
+        UnitsNamesLengthCoreV1,
+        UnitsNamesLengthExtendedV1,
+        UnitsNamesLengthOutlierV1,
+        UnitsNamesAreaCoreV1,
+        UnitsNamesAreaExtendedV1,
+        UnitsNamesAreaOutlierV1,
+        UnitsNamesDurationCoreV1,
+        UnitsNamesDurationExtendedV1,
+        UnitsNamesDurationOutlierV1,
+        UnitsNamesMassCoreV1,
+        UnitsNamesMassExtendedV1,
+        UnitsNamesMassOutlierV1,
+        UnitsNamesVolumeCoreV1,
+        UnitsNamesVolumeExtendedV1,
+        UnitsNamesVolumeOutlierV1
+    );
+
+    let mut fallback = None;
+    for (locale, payload) in responses.into_iter().flatten() {
+        if locale.is_some_and(|locale| locale.is_unknown()) {
+            fallback.get_or_insert(payload);
+        } else {
+            return Some(payload);
+        }
+    }
+    fallback
+}
+
+const EMPTY_PLURAL_FORMS: PluralForms = PluralForms {
+    zero: None,
+    one: None,
+    two: None,
+    few: None,
+    many: None,
+    other: "",
+};
+
+/// Numbers used to find the value for each plural category of a locale
+const PLURAL_SAMPLES: &[&str] = &[
+    "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17", "18",
+    "19", "20", "21", "22", "23", "24", "25", "100", "101", "102", "103", "111", "1000000", "0.5",
+    "1.5", "2.5", "0.1", "1.1", "1", "0",
+];
+
+fn plural_forms(
+    plural_rules: &PluralRules,
+    value: impl Fn(PluralOperands) -> String,
+) -> PluralForms {
+    let mut forms = EMPTY_PLURAL_FORMS;
+    for category in plural_rules.categories() {
+        let sample = PLURAL_SAMPLES
+            .iter()
+            .filter_map(|sample| sample.parse::<Decimal>().ok())
+            .map(|sample| PluralOperands::from(&sample))
+            .find(|operands| plural_rules.category_for(*operands) == category);
+        let Some(operands) = sample else {
+            continue;
+        };
+        let value = leak(value(operands));
+        match category {
+            PluralCategory::Zero => forms.zero = Some(value),
+            PluralCategory::One => forms.one = Some(value),
+            PluralCategory::Two => forms.two = Some(value),
+            PluralCategory::Few => forms.few = Some(value),
+            PluralCategory::Many => forms.many = Some(value),
+            PluralCategory::Other => forms.other = value,
+        }
+    }
+    forms
+}
+
+fn double_placeholder_pattern<'a>(
+    items: impl Iterator<Item = PatternItem<'a, DoublePlaceholderKey>>,
+) -> String {
+    items
+        .map(|item| match item {
+            PatternItem::Literal(s) => s.to_string(),
+            PatternItem::Placeholder(DoublePlaceholderKey::Place0) => "{0}".to_string(),
+            PatternItem::Placeholder(DoublePlaceholderKey::Place1) => "{1}".to_string(),
+        })
+        .collect()
+}
+
+fn request<'a>(locale: &'a DataLocale, attributes: Option<&'a str>) -> DataRequest<'a> {
+    let id = match attributes
+        .and_then(|attributes| DataMarkerAttributes::try_from_str(attributes).ok())
+    {
+        Some(attributes) => {
+            DataIdentifierBorrowed::for_marker_attributes_and_locale(attributes, locale)
+        }
+        None => DataIdentifierBorrowed::for_locale(locale),
+    };
+    let mut metadata = DataRequestMetadata::default();
+    // missing currency symbols and unit names are expected
+    metadata.silent = true;
+    DataRequest { id, metadata }
+}
+
+fn leak(s: String) -> &'static str {
+    Box::leak(s.into_boxed_str())
+}
+
+#[cfg(test)]
+mod test {
+    use fluent_static_value::{
+        list::{ListStyle, ListType},
+        number::{
+            format::{
+                CompactDisplay::{Long, Short},
+                CurrencyCode, CurrencyDisplayStyle, CurrencySignMode, GroupingStyle,
+                Notation::{Compact, Engineering, Scientific},
+                NumberStyle, SignDisplay, TrailingZeroDisplay, UnitDisplayStyle, UnitIdentifier,
+            },
+            RoundingMode::{Ceil, Floor, HalfCeil, HalfEven, HalfExpand},
+        },
+        relative_time::{RelativeTimeNumeric, RelativeTimeStyle, RelativeTimeUnit},
+        ListFormat, Number, NumberFormat, RelativeTimeFormat, Value,
+    };
+
+    use super::format;
+
+    fn format_number(locale: &str, value: impl Into<Number>, format: NumberFormat) -> String {
+        let mut s = String::new();
+        super::format(
+            locale,
+            &Value::Number {
+                value: value.into(),
+                format: Some(format),
+            },
+            &mut s,
+        )
+        .expect("Number to be formatted");
+        s
+    }
+
+    fn currency(
+        code: CurrencyCode,
+        style: CurrencyDisplayStyle,
+        sign: CurrencySignMode,
+    ) -> NumberFormat {
+        NumberFormat {
+            style: NumberStyle::Currency { code, style, sign },
+            ..Default::default()
+        }
+    }
+
+    fn unit(identifier: UnitIdentifier, style: UnitDisplayStyle) -> NumberFormat {
+        NumberFormat {
+            style: NumberStyle::Unit { identifier, style },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_decimal() {
+        let test_data: Vec<(&str, f64, NumberFormat, &str)> = vec![
+            ("en", 1234567.891, NumberFormat::default(), "1,234,567.891"),
+            ("en", -1234.5678, NumberFormat::default(), "-1,234.568"),
+            ("de", 1234567.891, NumberFormat::default(), "1.234.567,891"),
+            (
+                "fr",
+                1234567.891,
+                NumberFormat::default(),
+                "1\u{202f}234\u{202f}567,891",
+            ),
+            ("pl", 1234.0, NumberFormat::default(), "1234"),
+            ("pl", 12345.0, NumberFormat::default(), "12\u{a0}345"),
+            ("en-IN", 12345678.0, NumberFormat::default(), "1,23,45,678"),
+            ("ar-EG", 1234.5, NumberFormat::default(), "١٬٢٣٤٫٥"),
+            (
+                "en",
+                1234.0,
+                NumberFormat {
+                    use_grouping: GroupingStyle::Off,
+                    ..Default::default()
+                },
+                "1234",
+            ),
+            (
+                "en",
+                1234.0,
+                NumberFormat {
+                    use_grouping: GroupingStyle::Min2,
+                    ..Default::default()
+                },
+                "1234",
+            ),
+            (
+                "en",
+                3.5,
+                NumberFormat {
+                    minimum_integer_digits: Some(3),
+                    minimum_fraction_digits: Some(2),
+                    ..Default::default()
+                },
+                "003.50",
+            ),
+            (
+                "en",
+                2.71525,
+                NumberFormat {
+                    maximum_fraction_digits: Some(2),
+                    ..Default::default()
+                },
+                "2.72",
+            ),
+            (
+                "en",
+                123456.0,
+                NumberFormat {
+                    maximum_significant_digits: Some(2),
+                    ..Default::default()
+                },
+                "120,000",
+            ),
+            (
+                "en",
+                1.5,
+                NumberFormat {
+                    minimum_significant_digits: Some(3),
+                    ..Default::default()
+                },
+                "1.50",
+            ),
+            ("en", 0.256, NumberFormat::percent(), "26%"),
+            ("de", 0.256, NumberFormat::percent(), "26\u{a0}%"),
+        ];
+
+        for (locale, n, format, expected) in test_data {
+            assert_eq!(
+                expected,
+                format_number(locale, n, format),
+                "{} {}",
+                locale,
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn test_notation() {
+        let notation = |notation, compact_display| NumberFormat {
+            notation,
+            compact_display,
+            ..Default::default()
+        };
+        let test_data: Vec<(&str, f64, NumberFormat, &str)> = vec![
+            ("en", 1234.0, notation(Scientific, Short), "1.234E3"),
+            ("en", 0.00012, notation(Scientific, Short), "1.2E-4"),
+            ("en", 9.9996, notation(Scientific, Short), "1E1"),
+            ("de", -1234.5, notation(Scientific, Short), "-1,234E3"),
+            ("en", 12345.0, notation(Engineering, Short), "12.345E3"),
+            ("en", 0.0123, notation(Engineering, Short), "12.3E-3"),
+            ("en", 999.0, notation(Compact, Short), "999"),
+            ("en", 1234.0, notation(Compact, Short), "1.2K"),
+            ("en", 12345.0, notation(Compact, Short), "12K"),
+            ("en", 123456.0, notation(Compact, Short), "123K"),
+            ("en", 999999.0, notation(Compact, Short), "1M"),
+            ("en", 3400000.0, notation(Compact, Short), "3.4M"),
+            ("en", 1234.0, notation(Compact, Long), "1.2 thousand"),
+            ("en", 1.5e15, notation(Compact, Short), "1500T"),
+            ("en", 1.234, notation(Compact, Short), "1.2"),
+            ("de", 1234.0, notation(Compact, Short), "1234"),
+            ("de", 3400000.0, notation(Compact, Short), "3,4\u{a0}Mio."),
+            ("de", 1000000.0, notation(Compact, Long), "1 Million"),
+            ("de", 2000000.0, notation(Compact, Long), "2 Millionen"),
+            ("ja", 123456.0, notation(Compact, Short), "12万"),
+            (
+                "en",
+                1234.0,
+                NumberFormat {
+                    maximum_fraction_digits: Some(2),
+                    ..notation(Compact, Short)
+                },
+                "1.23K",
+            ),
+            (
+                "en",
+                1500.0,
+                NumberFormat {
+                    notation: Compact,
+                    ..NumberFormat::currency(CurrencyCode::USD)
+                },
+                "$1.5K",
+            ),
+        ];
+
+        for (locale, n, format, expected) in test_data {
+            assert_eq!(
+                expected,
+                format_number(locale, n, format),
+                "{} {}",
+                locale,
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn test_compact_without_data() {
+        let format = NumberFormat {
+            notation: Compact,
+            ..Default::default()
+        };
+        let mut s = String::new();
+        assert!(super::cldr::format(
+            &super::cldr::ROOT,
+            &Value::Number {
+                value: Number::from(1234),
+                format: Some(format),
+            },
+            &mut s,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_rounding() {
+        let rounding = |rounding_mode, rounding_increment, max_fraction| NumberFormat {
+            rounding_mode,
+            rounding_increment,
+            minimum_fraction_digits: Some(max_fraction),
+            maximum_fraction_digits: Some(max_fraction),
+            ..Default::default()
+        };
+        let test_data: Vec<(f64, NumberFormat, &str)> = vec![
+            (2.5, rounding(HalfEven, 1, 0), "2"),
+            (2.5, rounding(HalfExpand, 1, 0), "3"),
+            (-2.5, rounding(HalfExpand, 1, 0), "-3"),
+            (-2.5, rounding(HalfCeil, 1, 0), "-2"),
+            (1.01, rounding(Ceil, 1, 1), "1.1"),
+            (-1.09, rounding(Ceil, 1, 1), "-1.0"),
+            (1.09, rounding(Floor, 1, 1), "1.0"),
+            (1.23, rounding(HalfExpand, 5, 2), "1.25"),
+            (1.225, rounding(HalfEven, 5, 2), "1.20"),
+            (1.21, rounding(Ceil, 25, 2), "1.25"),
+            (1234.0, rounding(HalfExpand, 50, 0), "1,250"),
+        ];
+
+        for (n, format, expected) in test_data {
+            assert_eq!(
+                expected,
+                format_number("en", n, format.clone()),
+                "{:?}",
+                format
+            );
+        }
+    }
+
+    #[test]
+    fn test_sign_display() {
+        use SignDisplay::*;
+
+        let sign = |sign_display| NumberFormat {
+            sign_display,
+            maximum_fraction_digits: Some(0),
+            ..Default::default()
+        };
+        let currency_sign = |sign_display, sign| NumberFormat {
+            sign_display,
+            ..currency(CurrencyCode::USD, CurrencyDisplayStyle::Symbol, sign)
+        };
+        let test_data: Vec<(f64, NumberFormat, &str)> = vec![
+            (1.0, sign(Auto), "1"),
+            (-0.1, sign(Auto), "-0"),
+            (1.0, sign(Always), "+1"),
+            (0.0, sign(Always), "+0"),
+            (0.0, sign(ExceptZero), "0"),
+            (-0.1, sign(ExceptZero), "0"),
+            (-1.0, sign(ExceptZero), "-1"),
+            (1.0, sign(ExceptZero), "+1"),
+            (-1.0, sign(Negative), "-1"),
+            (-0.1, sign(Negative), "0"),
+            (1.0, sign(Negative), "1"),
+            (-1.0, sign(Never), "1"),
+            (
+                1.0,
+                currency_sign(Always, CurrencySignMode::Standard),
+                "+$1.00",
+            ),
+            (
+                -1.0,
+                currency_sign(Never, CurrencySignMode::Accounting),
+                "$1.00",
+            ),
+            (
+                -1.0,
+                currency_sign(ExceptZero, CurrencySignMode::Accounting),
+                "($1.00)",
+            ),
+            (
+                0.25,
+                NumberFormat {
+                    sign_display: Always,
+                    ..NumberFormat::percent()
+                },
+                "+25%",
+            ),
+        ];
+
+        for (n, format, expected) in test_data {
+            assert_eq!(
+                expected,
+                format_number("en", n, format.clone()),
+                "{:?}",
+                format
+            );
+        }
+    }
+
+    #[test]
+    fn test_trailing_zero_display() {
+        let strip = |format| NumberFormat {
+            trailing_zero_display: TrailingZeroDisplay::StripIfInteger,
+            ..format
+        };
+        let usd = currency(
+            CurrencyCode::USD,
+            CurrencyDisplayStyle::Symbol,
+            CurrencySignMode::Standard,
+        );
+        assert_eq!("$20", format_number("en", 20.0, strip(usd.clone())));
+        assert_eq!("$20.50", format_number("en", 20.5, strip(usd.clone())));
+        assert_eq!("$20", format_number("en", 20.001, strip(usd)));
+        assert_eq!(
+            "1",
+            format_number(
+                "en",
+                1.0,
+                strip(NumberFormat {
+                    minimum_fraction_digits: Some(2),
+                    ..Default::default()
+                })
+            )
+        );
+    }
+
+    #[test]
+    fn test_non_finite() {
+        assert_eq!(
+            "∞",
+            format_number("en", f64::INFINITY, NumberFormat::default())
+        );
+        assert_eq!(
+            "-∞",
+            format_number("en", f64::NEG_INFINITY, NumberFormat::default())
+        );
+        assert_eq!(
+            "NaN",
+            format_number("en", f64::NAN, NumberFormat::default())
+        );
+    }
+
+    #[test]
+    fn test_out_of_decimal_range() {
+        assert_eq!(
+            u128::MAX.to_string(),
+            format_number("en", u128::MAX, NumberFormat::default())
+        );
+        assert_eq!(
+            format!("0.{:0<40}", 5),
+            format_number(
+                "en",
+                0.5,
+                NumberFormat {
+                    minimum_fraction_digits: Some(40),
+                    ..Default::default()
+                }
+            )
+        );
+        assert_eq!(
+            "-0",
+            format_number(
+                "en",
+                -0.1,
+                NumberFormat {
+                    rounding_mode: Ceil,
+                    maximum_fraction_digits: Some(0),
+                    ..Default::default()
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        use CurrencyDisplayStyle::*;
+        use CurrencySignMode::*;
+
+        let test_data: Vec<(&str, f64, NumberFormat, &str)> = vec![
+            (
+                "en",
+                20.0,
+                currency(CurrencyCode::USD, Symbol, Standard),
+                "$20.00",
+            ),
+            (
+                "en",
+                -20.0,
+                currency(CurrencyCode::USD, Symbol, Standard),
+                "-$20.00",
+            ),
+            (
+                "en",
+                -20.0,
+                currency(CurrencyCode::USD, Symbol, Accounting),
+                "($20.00)",
+            ),
+            (
+                "en",
+                20.0,
+                currency(CurrencyCode::USD, Code, Standard),
+                "USD\u{a0}20.00",
+            ),
+            (
+                "en",
+                20.0,
+                currency(CurrencyCode::USD, Name, Standard),
+                "20.00 US dollars",
+            ),
+            (
+                "en",
+                1.0,
+                currency(CurrencyCode::USD, Name, Standard),
+                "1.00 US dollars",
+            ),
+            (
+                "en",
+                1234.5,
+                currency(CurrencyCode::JPY, Symbol, Standard),
+                "¥1,234",
+            ),
+            (
+                "en",
+                5.0,
+                currency(CurrencyCode::CAD, Symbol, Standard),
+                "CA$5.00",
+            ),
+            (
+                "en",
+                5.0,
+                currency(CurrencyCode::CAD, NarrowSymbol, Standard),
+                "$5.00",
+            ),
+            (
+                "de",
+                1234.5,
+                currency(CurrencyCode::EUR, Symbol, Standard),
+                "1.234,50\u{a0}€",
+            ),
+            (
+                "fr",
+                -3.0,
+                currency(CurrencyCode::EUR, Symbol, Standard),
+                "-3,00\u{a0}€",
+            ),
+            (
+                "pl",
+                5.0,
+                currency(CurrencyCode::PLN, Name, Standard),
+                "5,00 złotego polskiego",
+            ),
+        ];
+
+        for (locale, n, format, expected) in test_data {
+            assert_eq!(
+                expected,
+                format_number(locale, n, format),
+                "{} {}",
+                locale,
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn test_unit() {
+        use UnitDisplayStyle::*;
+
+        let test_data: Vec<(&str, f64, NumberFormat, &str)> = vec![
+            ("en", 20.0, unit(UnitIdentifier::Meter, Short), "20 m"),
+            ("en", 1.0, unit(UnitIdentifier::Meter, Long), "1 meter"),
+            ("en", 20.0, unit(UnitIdentifier::Meter, Long), "20 meters"),
+            ("en", 5.0, unit(UnitIdentifier::Kilogram, Narrow), "5kg"),
+            ("de", 2.0, unit(UnitIdentifier::Hour, Long), "2 Stunden"),
+            (
+                "pl",
+                2.0,
+                unit(UnitIdentifier::Kilometer, Long),
+                "2 kilometry",
+            ),
+            (
+                "pl",
+                5.0,
+                unit(UnitIdentifier::Kilometer, Long),
+                "5 kilometrów",
+            ),
+            (
+                "en",
+                7.5,
+                unit(UnitIdentifier::Liter.per(UnitIdentifier::Kilometer), Short),
+                "7.5 L/km",
+            ),
+            ("en", 8.0, unit(UnitIdentifier::Byte, Short), "8 byte"),
+        ];
+
+        for (locale, n, format, expected) in test_data {
+            assert_eq!(
+                expected,
+                format_number(locale, n, format),
+                "{} {}",
+                locale,
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn test_unknown_locale() {
+        assert_eq!(
+            "1,234.5",
+            format_number("xx-invalid-locale!", 1234.5, NumberFormat::default())
+        );
+
+        let mut s = String::new();
+        format("en", &Value::from(1234.5), &mut s).unwrap();
+        assert_eq!("1234.5", s);
+    }
+
+    #[test]
+    fn test_list() {
+        use ListStyle::*;
+        use ListType::*;
+
+        let format_list = |locale: &str, items: &[&str], list_type, style| {
+            let mut s = String::new();
+            format(
+                locale,
+                &Value::formatted_list(items.iter().copied(), ListFormat::new(list_type, style)),
+                &mut s,
+            )
+            .expect("List to be formatted");
+            s
+        };
+
+        let cases = [
+            ("en", &["A"][..], Conjunction, Long, "A"),
+            ("en", &["A", "B"], Conjunction, Long, "A and B"),
+            ("en", &["A", "B", "C"], Conjunction, Long, "A, B, and C"),
+            (
+                "en",
+                &["A", "B", "C", "D"],
+                Conjunction,
+                Short,
+                "A, B, C, & D",
+            ),
+            ("en", &["A", "B", "C"], Disjunction, Long, "A, B, or C"),
+            ("en", &["A", "B", "C"], Unit, Narrow, "A B C"),
+            ("fr", &["A", "B", "C"], Conjunction, Long, "A, B et C"),
+            ("de", &["A", "B", "C"], Disjunction, Long, "A, B oder C"),
+        ];
+        for (locale, items, list_type, style, expected) in cases {
+            assert_eq!(
+                expected,
+                format_list(locale, items, list_type, style),
+                "{} {:?}",
+                locale,
+                items
+            );
+        }
+
+        let mut s = String::new();
+        format(
+            "en",
+            &Value::list([Value::from("A"), Value::from(1234.5)]),
+            &mut s,
+        )
+        .unwrap();
+        assert_eq!("A and 1234.5", s);
+    }
+
+    #[test]
+    fn test_relative_time() {
+        use RelativeTimeNumeric::*;
+        use RelativeTimeStyle::*;
+        use RelativeTimeUnit::*;
+
+        let format_relative_time = |locale: &str, value: i64, unit, numeric, style| {
+            let mut s = String::new();
+            format(
+                locale,
+                &Value::RelativeTime {
+                    value: Number::from(value),
+                    format: RelativeTimeFormat {
+                        unit,
+                        numeric,
+                        style,
+                    },
+                },
+                &mut s,
+            )
+            .expect("Relative time to be formatted");
+            s
+        };
+
+        let cases = [
+            ("en", 3, Day, Always, Long, "in 3 days"),
+            ("en", -3, Day, Always, Long, "3 days ago"),
+            ("en", 1, Day, Always, Long, "in 1 day"),
+            ("en", -1, Day, Auto, Long, "yesterday"),
+            ("en", 0, Year, Auto, Long, "this year"),
+            ("en", 5, Hour, Always, Short, "in 5 hr."),
+            ("en", 1234, Second, Always, Long, "in 1,234 seconds"),
+            ("de", -2, Week, Always, Long, "vor 2 Wochen"),
+            ("fr", 2, Month, Always, Long, "dans 2 mois"),
+        ];
+        for (locale, value, unit, numeric, style, expected) in cases {
+            assert_eq!(
+                expected,
+                format_relative_time(locale, value, unit, numeric, style),
+                "{} {} {:?}",
+                locale,
+                value,
+                unit
+            );
+        }
+    }
+}
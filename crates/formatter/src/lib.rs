@@ -1,30 +1,463 @@
+pub mod cache;
+pub mod cldr;
+#[cfg(feature = "icu4x")]
+pub mod icu4x;
+
+#[cfg(feature = "icu4c")]
 use std::fmt::Write;
 
-use fluent_static_value::Value;
+#[cfg(feature = "icu4c")]
+use fluent_static_value::{ListFormat, NumberFormat, Value};
+
+#[cfg(all(feature = "icu4x", not(feature = "icu4c")))]
+pub use icu4x::{format, prewarm};
+
+/// Creates and caches number formatters of the locale ahead of use
+#[cfg(feature = "icu4c")]
+pub fn prewarm(locale: &str, formats: &[NumberFormat]) -> std::fmt::Result {
+    formats
+        .iter()
+        .try_for_each(|format| number::formatter(locale, format).map(|_| ()))
+}
+
+/// Usage of the number formatter cache shared by all threads
+#[cfg(feature = "icu4c")]
+pub fn number_formatter_cache_metrics() -> cache::CacheMetrics {
+    number::cache().metrics()
+}
+
+/// Sets maximum number of cached number formatters, [`cache::DEFAULT_CAPACITY`] by default
+#[cfg(feature = "icu4c")]
+pub fn set_number_formatter_cache_capacity(capacity: usize) {
+    number::cache().set_capacity(capacity)
+}
+
+/// Usage of the list formatter cache shared by all threads
+#[cfg(feature = "icu4c")]
+pub fn list_formatter_cache_metrics() -> cache::CacheMetrics {
+    list::cache().metrics()
+}
 
+/// Sets maximum number of cached list formatters, [`cache::DEFAULT_CAPACITY`] by default
+#[cfg(feature = "icu4c")]
+pub fn set_list_formatter_cache_capacity(capacity: usize) {
+    list::cache().set_capacity(capacity)
+}
+
+#[cfg(feature = "icu4c")]
 pub fn format(locale: &str, value: &Value, out: &mut impl Write) -> std::fmt::Result {
     match value {
         Value::String(s) => out.write_str(s),
         Value::Number { value, format } => number::format_number(locale, value, format, out),
+        Value::Bool(b) => write!(out, "{}", b),
+        Value::List(items) => list::format_list(locale, items, &ListFormat::default(), out),
+        Value::FormattedList { items, format } => list::format_list(locale, items, format, out),
+        Value::RelativeTime { value, format } => {
+            relative_time::format_relative_time(locale, value, format, out)
+        }
+        Value::Custom(custom) => out.write_str(&custom.as_string(locale)),
+        Value::Markup(fragment) => out.write_str(fragment),
         Value::Empty => Ok(()),
         Value::Error => write!(out, "#error#"),
     }
 }
 
+#[cfg(feature = "icu4c")]
+mod list {
+    use std::{
+        fmt::Write,
+        sync::{Arc, OnceLock},
+    };
+
+    use fluent_static_value::{
+        list::{ListStyle, ListType},
+        ListFormat, Value,
+    };
+    use rust_icu_sys::{UListFormatterType, UListFormatterWidth};
+    use rust_icu_ulistformatter::UListFormatter;
+
+    use crate::cache::FormatterCache;
+
+    pub(super) struct SharedFormatter(UListFormatter);
+
+    // SAFETY: ICU list formatters are immutable once created and documented
+    // as safe to use from multiple threads
+    unsafe impl Send for SharedFormatter {}
+    unsafe impl Sync for SharedFormatter {}
+
+    pub(super) fn cache() -> &'static FormatterCache<(String, ListFormat), SharedFormatter> {
+        static CACHE: OnceLock<FormatterCache<(String, ListFormat), SharedFormatter>> =
+            OnceLock::new();
+        CACHE.get_or_init(FormatterCache::default)
+    }
+
+    fn formatter(
+        locale: &str,
+        format: &ListFormat,
+    ) -> Result<Arc<SharedFormatter>, std::fmt::Error> {
+        cache().get_or_try_insert_with(&(locale.to_string(), *format), || {
+            let list_type = match format.list_type {
+                ListType::Conjunction => UListFormatterType::ULISTFMT_TYPE_AND,
+                ListType::Disjunction => UListFormatterType::ULISTFMT_TYPE_OR,
+                ListType::Unit => UListFormatterType::ULISTFMT_TYPE_UNITS,
+            };
+            let width = match format.style {
+                ListStyle::Long => UListFormatterWidth::ULISTFMT_WIDTH_WIDE,
+                ListStyle::Short => UListFormatterWidth::ULISTFMT_WIDTH_SHORT,
+                ListStyle::Narrow => UListFormatterWidth::ULISTFMT_WIDTH_NARROW,
+            };
+            UListFormatter::try_new_styled(locale, list_type, width)
+                .map(SharedFormatter)
+                .map_err(|_| std::fmt::Error)
+        })
+    }
+
+    pub(super) fn format_list(
+        locale: &str,
+        items: &[Value],
+        format: &ListFormat,
+        out: &mut impl Write,
+    ) -> std::fmt::Result {
+        let items = items
+            .iter()
+            .map(|item| {
+                let mut s = String::new();
+                super::format(locale, item, &mut s).map(|_| s)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let items: Vec<&str> = items.iter().map(String::as_str).collect();
+
+        let s = formatter(locale, format)?
+            .0
+            .format(&items)
+            .map_err(|_| std::fmt::Error)?;
+        out.write_str(&s)
+    }
+
+    #[cfg(test)]
+    mod test {
+        use fluent_static_value::{ListFormat, Value};
+
+        use super::{cache, format_list};
+
+        #[test]
+        fn test_cached_list_formatter() {
+            let items = [Value::from("a"), Value::from("b"), Value::from("c")];
+            let format = ListFormat::default();
+            for _ in 0..2 {
+                let mut s = String::new();
+                format_list("en-US", &items, &format, &mut s).expect("List to be formatted");
+                assert_eq!("a, b, and c", s);
+            }
+            assert!(cache().metrics().hits >= 1);
+        }
+    }
+}
+
+#[cfg(feature = "icu4c")]
+mod relative_time {
+    //! rust_icu has no `ureldatefmt` bindings, relative time is formatted with CLDR patterns
+    //! of ICU locale data and plural rules, the same way `ureldatefmt` does
+
+    use std::{
+        ffi::{CStr, CString},
+        fmt::Write,
+        ptr::{self, NonNull},
+        sync::{Arc, OnceLock},
+    };
+
+    use fluent_static_value::{
+        number::format::NumberStyle,
+        relative_time::{RelativeTimeNumeric, RelativeTimeStyle},
+        Number, NumberFormat, RelativeTimeFormat,
+    };
+    use rust_icu_sys::{self as sys, versioned_function};
+
+    use crate::cache::FormatterCache;
+
+    pub(super) fn format_relative_time(
+        locale: &str,
+        value: &Number,
+        format: &RelativeTimeFormat,
+        out: &mut impl Write,
+    ) -> std::fmt::Result {
+        let field = match format.style {
+            RelativeTimeStyle::Long => format.unit.to_string(),
+            RelativeTimeStyle::Short => format!("{}-short", format.unit),
+            RelativeTimeStyle::Narrow => format!("{}-narrow", format.unit),
+        };
+
+        if format.numeric == RelativeTimeNumeric::Auto {
+            if let Some(name) = i8::try_from(value).ok().and_then(|offset| {
+                lookup(locale, &["fields", &field, "relative", &offset.to_string()])
+            }) {
+                return out.write_str(&name);
+            }
+        }
+
+        let mut number = String::new();
+        super::number::format_number(
+            locale,
+            &value.abs(),
+            &Some(NumberFormat::default()),
+            &mut number,
+        )?;
+        let direction = if value.as_f64().is_sign_negative() {
+            "past"
+        } else {
+            "future"
+        };
+        let pattern = plural_category(locale, displayed_value(value))
+            .and_then(|category| {
+                lookup(
+                    locale,
+                    &["fields", &field, "relativeTime", direction, &category],
+                )
+            })
+            .or_else(|| {
+                lookup(
+                    locale,
+                    &["fields", &field, "relativeTime", direction, "other"],
+                )
+            })
+            .ok_or(std::fmt::Error)?;
+        out.write_str(&pattern.replace("{0}", &number))
+    }
+
+    /// Absolute value as it is displayed by the default number format, plural form
+    /// is selected for the rounded number
+    fn displayed_value(value: &Number) -> f64 {
+        let (_, max_fraction) = NumberStyle::Decimal.default_fraction_digits();
+        value
+            .to_decimal()
+            .map(|decimal| decimal.round(max_fraction as u32).as_f64())
+            .unwrap_or_else(|| value.as_f64())
+            .abs()
+    }
+
+    fn is_success(status: sys::UErrorCode) -> bool {
+        // warnings are negative
+        (status as i32) <= (sys::UErrorCode::U_ZERO_ERROR as i32)
+    }
+
+    /// Looks up a string of ICU locale data, falling back to parent locales for missing keys,
+    /// ICU itself falls back only for top level resources
+    fn lookup(locale: &str, path: &[&str]) -> Option<String> {
+        let path = path
+            .iter()
+            .map(|key| CString::new(*key).ok())
+            .collect::<Option<Vec<_>>>()?;
+        let (key, tables) = path.split_last()?;
+        let locale = locale.replace('-', "_");
+        std::iter::successors(Some(locale.as_str()), |locale| {
+            locale.rfind('_').map(|index| &locale[..index])
+        })
+        .chain(std::iter::once("root"))
+        .find_map(|locale| {
+            let locale = CString::new(locale).ok()?;
+            let bundle = tables
+                .iter()
+                .try_fold(Bundle::open(&locale)?, |bundle, table| bundle.get(table))?;
+            bundle.get_string(key)
+        })
+    }
+
+    struct Bundle(NonNull<sys::UResourceBundle>);
+
+    impl Bundle {
+        /// `None` if there is no data for the locale and ICU falls back to the default locale
+        fn open(locale: &CStr) -> Option<Self> {
+            let mut status = sys::UErrorCode::U_ZERO_ERROR;
+            let bundle = NonNull::new(unsafe {
+                versioned_function!(ures_open)(ptr::null(), locale.as_ptr(), &mut status)
+            })
+            .map(Bundle)?;
+            (is_success(status) && !matches!(status, sys::UErrorCode::U_USING_DEFAULT_WARNING))
+                .then_some(bundle)
+        }
+
+        fn get(&self, key: &CStr) -> Option<Self> {
+            let mut status = sys::UErrorCode::U_ZERO_ERROR;
+            let bundle = NonNull::new(unsafe {
+                versioned_function!(ures_getByKey)(
+                    self.0.as_ptr(),
+                    key.as_ptr(),
+                    ptr::null_mut(),
+                    &mut status,
+                )
+            })
+            .map(Bundle)?;
+            is_success(status).then_some(bundle)
+        }
+
+        fn get_string(&self, key: &CStr) -> Option<String> {
+            let mut status = sys::UErrorCode::U_ZERO_ERROR;
+            let mut len = 0;
+            let chars = unsafe {
+                versioned_function!(ures_getStringByKey)(
+                    self.0.as_ptr(),
+                    key.as_ptr(),
+                    &mut len,
+                    &mut status,
+                )
+            };
+            if chars.is_null() || !is_success(status) {
+                return None;
+            }
+            // SAFETY: the string of `len` UTF-16 units is owned by ICU data and outlives the bundle
+            let chars = unsafe { std::slice::from_raw_parts(chars, len as usize) };
+            Some(String::from_utf16_lossy(chars))
+        }
+    }
+
+    impl Drop for Bundle {
+        fn drop(&mut self) {
+            unsafe { versioned_function!(ures_close)(self.0.as_ptr()) }
+        }
+    }
+
+    struct PluralRules(NonNull<sys::UPluralRules>);
+
+    // SAFETY: ICU plural rules are immutable once created
+    unsafe impl Send for PluralRules {}
+    unsafe impl Sync for PluralRules {}
+
+    impl Drop for PluralRules {
+        fn drop(&mut self) {
+            unsafe { versioned_function!(uplrules_close)(self.0.as_ptr()) }
+        }
+    }
+
+    fn cache() -> &'static FormatterCache<String, PluralRules> {
+        static CACHE: OnceLock<FormatterCache<String, PluralRules>> = OnceLock::new();
+        CACHE.get_or_init(FormatterCache::default)
+    }
+
+    fn plural_rules(locale: &str) -> Result<Arc<PluralRules>, std::fmt::Error> {
+        cache().get_or_try_insert_with(&locale.to_string(), || {
+            let locale = CString::new(locale).map_err(|_| std::fmt::Error)?;
+            let mut status = sys::UErrorCode::U_ZERO_ERROR;
+            let rules = NonNull::new(unsafe {
+                versioned_function!(uplrules_open)(locale.as_ptr(), &mut status)
+            })
+            .map(PluralRules)
+            .ok_or(std::fmt::Error)?;
+            if is_success(status) {
+                Ok(rules)
+            } else {
+                Err(std::fmt::Error)
+            }
+        })
+    }
+
+    /// Cardinal plural category keyword, i.e. `one`
+    fn plural_category(locale: &str, value: f64) -> Option<String> {
+        let rules = plural_rules(locale).ok()?;
+        let mut keyword = [0; 16];
+        let mut status = sys::UErrorCode::U_ZERO_ERROR;
+        let len = unsafe {
+            versioned_function!(uplrules_select)(
+                rules.0.as_ptr(),
+                value,
+                keyword.as_mut_ptr(),
+                keyword.len() as i32,
+                &mut status,
+            )
+        };
+        if !is_success(status) {
+            return None;
+        }
+        String::from_utf16(keyword.get(..len as usize)?).ok()
+    }
+
+    #[cfg(test)]
+    mod test {
+        use fluent_static_value::{
+            relative_time::{RelativeTimeNumeric::*, RelativeTimeStyle::*, RelativeTimeUnit::*},
+            Number, RelativeTimeFormat,
+        };
+
+        use super::format_relative_time;
+
+        #[test]
+        fn test_relative_time() {
+            let cases = [
+                ("en", 3.0, Day, Always, Long, "in 3 days"),
+                ("en", -3.0, Day, Always, Long, "3 days ago"),
+                ("en", 1.0, Day, Always, Long, "in 1 day"),
+                ("en", 1.5, Day, Always, Long, "in 1.5 days"),
+                ("en", -1.0, Day, Auto, Long, "yesterday"),
+                ("en", 0.0, Year, Auto, Long, "this year"),
+                ("en", 5.0, Hour, Always, Short, "in 5 hr."),
+                ("en", 1234.0, Second, Always, Long, "in 1,234 seconds"),
+                ("en-GB", -2.0, Week, Always, Long, "2 weeks ago"),
+                ("de", -2.0, Week, Always, Long, "vor 2 Wochen"),
+                ("fr", 2.0, Month, Always, Long, "dans 2 mois"),
+            ];
+            for (locale, value, unit, numeric, style, expected) in cases {
+                let mut s = String::new();
+                format_relative_time(
+                    locale,
+                    &Number::from(value),
+                    &RelativeTimeFormat {
+                        unit,
+                        numeric,
+                        style,
+                    },
+                    &mut s,
+                )
+                .expect("Relative time to be formatted");
+                assert_eq!(expected, s, "{} {} {:?}", locale, value, unit);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "icu4c")]
 mod number {
 
-    use std::{cell::RefCell, collections::HashMap, fmt::Write};
+    use std::{
+        fmt::Write,
+        sync::{Arc, OnceLock},
+    };
 
     use fluent_static_value::{
-        number::format::{
-            CurrencyDisplayStyle, CurrencySignMode, GroupingStyle, NumberStyle, UnitDisplayStyle,
+        number::{
+            format::{
+                CompactDisplay, CurrencyDisplayStyle, CurrencySignMode, GroupingStyle, Notation,
+                NumberStyle, SignDisplay, TrailingZeroDisplay, UnitDisplayStyle,
+            },
+            RoundingMode,
         },
         Number, NumberFormat,
     };
     use rust_icu_unumberformatter::{UFormattedNumber, UNumberFormatter};
 
-    thread_local! {
-        static FORMATTER_CACHE: RefCell<HashMap<(String, NumberFormat), Result<UNumberFormatter, std::fmt::Error>>> = RefCell::new(HashMap::new());
+    use crate::cache::FormatterCache;
+
+    pub(super) struct SharedFormatter(UNumberFormatter);
+
+    // SAFETY: ICU number formatters are immutable once created and documented
+    // as safe to use from multiple threads
+    unsafe impl Send for SharedFormatter {}
+    unsafe impl Sync for SharedFormatter {}
+
+    pub(super) fn cache() -> &'static FormatterCache<(String, NumberFormat), SharedFormatter> {
+        static CACHE: OnceLock<FormatterCache<(String, NumberFormat), SharedFormatter>> =
+            OnceLock::new();
+        CACHE.get_or_init(FormatterCache::default)
+    }
+
+    pub(super) fn formatter(
+        locale: &str,
+        format: &NumberFormat,
+    ) -> Result<Arc<SharedFormatter>, std::fmt::Error> {
+        cache().get_or_try_insert_with(&(locale.to_string(), format.clone()), || {
+            let skeleton = make_icu_skeleton(format)?;
+            UNumberFormatter::try_new(&skeleton, locale)
+                .map(SharedFormatter)
+                .map_err(|_| std::fmt::Error)
+        })
     }
 
     pub(super) fn format_number(
@@ -34,25 +467,17 @@ mod number {
         out: &mut impl Write,
     ) -> std::fmt::Result {
         if let Some(format) = format {
-            let key = (locale.to_string(), format.clone());
-            FORMATTER_CACHE.with(|cache| {
-                let mut cache = cache.borrow_mut();
-                if let Ok(formatter) = cache.entry(key).or_insert_with(|| {
-                    let skeleton = make_icu_skeleton(format)?;
-                    UNumberFormatter::try_new(&skeleton, locale).map_err(|_| std::fmt::Error)
-                }) {
-                    let formatted_number: UFormattedNumber = match value {
-                        Number::I64(n) => formatter.format_int(*n),
-                        n => formatter.format_double(n.as_f64()),
-                    }
-                    .map_err(|_| std::fmt::Error)?;
-                    let s: String = formatted_number.try_into().map_err(|_| std::fmt::Error)?;
-                    out.write_str(&s)?;
-                    Ok(())
-                } else {
-                    Err(std::fmt::Error)
-                }
-            })
+            let formatter = formatter(locale, format)?;
+            // other than `i64` and `f64` values are formatted from their exact decimal
+            // representation, so rounding applies to the exact value
+            let formatted_number: UFormattedNumber = match value {
+                Number::I64(n) => formatter.0.format_int(*n),
+                Number::F64(n) => formatter.0.format_double(*n),
+                n => formatter.0.format_decimal(&n.to_string()),
+            }
+            .map_err(|_| std::fmt::Error)?;
+            let s: String = formatted_number.try_into().map_err(|_| std::fmt::Error)?;
+            out.write_str(&s)
         } else {
             match value {
                 Number::I64(n) => write!(out, "{}", n),
@@ -60,10 +485,22 @@ mod number {
                 Number::I128(n) => write!(out, "{}", n),
                 Number::U128(n) => write!(out, "{}", n),
                 Number::F64(n) => write!(out, "{}", n),
+                Number::Decimal(n) => write!(out, "{}", n),
             }
         }
     }
 
+    /// Increment as a decimal with the given number of fraction digits, i.e. `0.05`
+    fn increment_stem(increment: usize, fraction_digits: usize) -> String {
+        let digits = format!("{:0>width$}", increment, width = fraction_digits + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - fraction_digits);
+        if fraction.is_empty() {
+            integer.to_string()
+        } else {
+            format!("{}.{}", integer, fraction)
+        }
+    }
+
     fn make_icu_skeleton(format: &NumberFormat) -> Result<String, std::fmt::Error> {
         let mut out = String::new();
 
@@ -83,9 +520,57 @@ mod number {
             }
         }
 
+        match (&format.notation, &format.compact_display) {
+            (Notation::Standard, _) => {}
+            (Notation::Scientific, _) => {
+                write!(out, "scientific ")?;
+            }
+            (Notation::Engineering, _) => {
+                write!(out, "engineering ")?;
+            }
+            (Notation::Compact, CompactDisplay::Short) => {
+                write!(out, "compact-short ")?;
+            }
+            (Notation::Compact, CompactDisplay::Long) => {
+                write!(out, "compact-long ")?;
+            }
+        }
+
+        // ICU default is half-even
+        match format.rounding_mode {
+            RoundingMode::HalfEven => {}
+            RoundingMode::Ceil => write!(out, "rounding-mode-ceiling ")?,
+            RoundingMode::Floor => write!(out, "rounding-mode-floor ")?,
+            RoundingMode::Expand => write!(out, "rounding-mode-up ")?,
+            RoundingMode::Trunc => write!(out, "rounding-mode-down ")?,
+            RoundingMode::HalfCeil => write!(out, "rounding-mode-half-ceiling ")?,
+            RoundingMode::HalfFloor => write!(out, "rounding-mode-half-floor ")?,
+            RoundingMode::HalfExpand => write!(out, "rounding-mode-half-up ")?,
+            RoundingMode::HalfTrunc => write!(out, "rounding-mode-half-down ")?,
+        }
+
+        let accounting = matches!(
+            format.style,
+            NumberStyle::Currency {
+                sign: CurrencySignMode::Accounting,
+                ..
+            }
+        );
+        match (format.sign_display, accounting) {
+            (SignDisplay::Auto, false) => {}
+            (SignDisplay::Auto, true) => write!(out, "sign-accounting ")?,
+            (SignDisplay::Always, false) => write!(out, "sign-always ")?,
+            (SignDisplay::Always, true) => write!(out, "sign-accounting-always ")?,
+            (SignDisplay::ExceptZero, false) => write!(out, "sign-except-zero ")?,
+            (SignDisplay::ExceptZero, true) => write!(out, "sign-accounting-except-zero ")?,
+            (SignDisplay::Negative, false) => write!(out, "sign-negative ")?,
+            (SignDisplay::Negative, true) => write!(out, "sign-accounting-negative ")?,
+            (SignDisplay::Never, _) => write!(out, "sign-never ")?,
+        }
+
         match &format.style {
             NumberStyle::Decimal => {}
-            NumberStyle::Currency { code, style, sign } => {
+            NumberStyle::Currency { code, style, .. } => {
                 write!(out, "currency/{} ", code)?;
                 match style {
                     CurrencyDisplayStyle::Code => {
@@ -101,12 +586,6 @@ mod number {
                         write!(out, "unit-width-full-name ")?;
                     }
                 }
-                match sign {
-                    CurrencySignMode::Standard => {}
-                    CurrencySignMode::Accounting => {
-                        write!(out, "sign-accounting ")?;
-                    }
-                }
             }
             NumberStyle::Percent => write!(out, "percent")?,
             NumberStyle::Unit { identifier, style } => {
@@ -136,24 +615,26 @@ mod number {
             }
         }
 
+        let mut precision = String::new();
+
         let min_frac = format.minimum_fraction_digits.clone();
         let max_frac = format.maximum_fraction_digits.clone();
 
         if let (Some(min_frac), Some(max_frac)) = (min_frac, max_frac) {
             if min_frac == max_frac {
-                write!(out, ".{}", "0".repeat(min_frac))?;
+                write!(precision, ".{}", "0".repeat(min_frac))?;
             } else {
                 write!(
-                    out,
+                    precision,
                     ".{}{}",
                     "0".repeat(min_frac),
                     "#".repeat(max_frac - min_frac)
                 )?;
             }
         } else if let Some(min_frac) = min_frac {
-            write!(out, ".{}*", "0".repeat(min_frac))?;
+            write!(precision, ".{}*", "0".repeat(min_frac))?;
         } else if let Some(max_frac) = max_frac {
-            write!(out, ".{}", "#".repeat(max_frac))?;
+            write!(precision, ".{}", "#".repeat(max_frac))?;
         }
 
         let min_sig = format.minimum_significant_digits.clone();
@@ -162,19 +643,45 @@ mod number {
         // Significant digits
         if let (Some(min_sig), Some(max_sig)) = (min_sig, max_sig) {
             if min_sig == max_sig {
-                write!(out, "{}", "@".repeat(min_sig))?;
+                write!(precision, "{}", "@".repeat(min_sig))?;
             } else {
                 write!(
-                    out,
+                    precision,
                     "@{}{}",
                     "@".repeat(min_sig - 1),
                     "#".repeat(max_sig - min_sig)
                 )?;
             }
         } else if let Some(min_sig) = min_sig {
-            write!(out, "@{}*", "@".repeat(min_sig - 1))?;
+            write!(precision, "@{}*", "@".repeat(min_sig - 1))?;
         } else if let Some(max_sig) = max_sig {
-            write!(out, "@{}", "#".repeat(max_sig - 1))?;
+            write!(precision, "@{}", "#".repeat(max_sig - 1))?;
+        }
+
+        if format.rounding_increment > 1 && min_sig.is_none() && max_sig.is_none() {
+            // increment applies to the last fraction digit
+            let fraction_digits = max_frac
+                .unwrap_or(format.style.default_fraction_digits().1)
+                .max(min_frac.unwrap_or(0));
+            precision = format!(
+                "precision-increment/{}",
+                increment_stem(format.rounding_increment, fraction_digits)
+            );
+        }
+
+        if format.trailing_zero_display == TrailingZeroDisplay::StripIfInteger {
+            if !precision.is_empty() {
+                precision.push_str("/w");
+            } else if format.style.is_currency() {
+                precision.push_str("precision-currency-standard/w");
+            }
+        }
+
+        if !precision.is_empty() {
+            if !out.is_empty() && !out.ends_with(' ') {
+                out.push(' ');
+            }
+            out.push_str(&precision);
         }
 
         Ok(out)
@@ -183,9 +690,13 @@ mod number {
     #[cfg(test)]
     mod test {
         use fluent_static_value::{
-            number::format::{
-                CurrencyCode, CurrencyDisplayStyle, CurrencySignMode, GroupingStyle, NumberStyle,
-                UnitDisplayStyle, UnitIdentifier,
+            number::{
+                format::{
+                    CompactDisplay, CurrencyCode, CurrencyDisplayStyle, CurrencySignMode,
+                    GroupingStyle, Notation, NumberStyle, SignDisplay, TrailingZeroDisplay,
+                    UnitDisplayStyle, UnitIdentifier,
+                },
+                Decimal, RoundingMode,
             },
             Number, NumberFormat,
         };
@@ -404,6 +915,112 @@ mod number {
             }
         }
 
+        #[test]
+        fn test_notation() {
+            let test_data: Vec<(f64, Notation, CompactDisplay, &str)> = vec![
+                (
+                    1234.0,
+                    Notation::Scientific,
+                    CompactDisplay::Short,
+                    "1.234E3",
+                ),
+                (
+                    12345.0,
+                    Notation::Engineering,
+                    CompactDisplay::Short,
+                    "12.345E3",
+                ),
+                (1234.0, Notation::Compact, CompactDisplay::Short, "1.2K"),
+                (
+                    1234.0,
+                    Notation::Compact,
+                    CompactDisplay::Long,
+                    "1.2 thousand",
+                ),
+                (3400000.0, Notation::Compact, CompactDisplay::Short, "3.4M"),
+            ];
+
+            for (n, notation, compact_display, expected) in test_data {
+                let mut s = String::new();
+                let format = NumberFormat {
+                    notation,
+                    compact_display,
+                    ..Default::default()
+                };
+
+                format_number("en-US", &Number::from(n), &Some(format), &mut s)
+                    .expect("Number to be formatted");
+
+                assert_eq!(expected, s);
+            }
+
+            let mut s = String::new();
+            let format = NumberFormat {
+                notation: Notation::Compact,
+                ..Default::default()
+            };
+            format_number("de", &Number::from(3400000), &Some(format), &mut s)
+                .expect("Number to be formatted");
+            assert_eq!("3,4\u{a0}Mio.", s);
+        }
+
+        #[test]
+        fn test_rounding_and_sign() {
+            let test_data: Vec<(f64, NumberFormat, &str)> = vec![
+                (
+                    1.5,
+                    NumberFormat {
+                        maximum_fraction_digits: Some(0),
+                        rounding_mode: RoundingMode::Floor,
+                        ..Default::default()
+                    },
+                    "1",
+                ),
+                (
+                    1.23,
+                    NumberFormat {
+                        minimum_fraction_digits: Some(2),
+                        maximum_fraction_digits: Some(2),
+                        rounding_increment: 5,
+                        ..Default::default()
+                    },
+                    "1.25",
+                ),
+                (
+                    42.0,
+                    NumberFormat {
+                        sign_display: SignDisplay::Always,
+                        ..Default::default()
+                    },
+                    "+42",
+                ),
+                (
+                    0.0,
+                    NumberFormat {
+                        sign_display: SignDisplay::ExceptZero,
+                        ..Default::default()
+                    },
+                    "0",
+                ),
+                (
+                    20.0,
+                    NumberFormat {
+                        trailing_zero_display: TrailingZeroDisplay::StripIfInteger,
+                        ..NumberFormat::currency(CurrencyCode::USD)
+                    },
+                    "$20",
+                ),
+            ];
+
+            for (n, format, expected) in test_data {
+                let mut s = String::new();
+                format_number("en-US", &Number::from(n), &Some(format.clone()), &mut s)
+                    .expect("Number to be formatted");
+
+                assert_eq!(expected, s, "{}", make_icu_skeleton(&format).unwrap());
+            }
+        }
+
         #[test]
         fn test_significant_digits() {
             let test_data: Vec<(f64, Option<usize>, Option<usize>, &str)> = vec![
@@ -459,5 +1076,50 @@ mod number {
                 i = i + 1;
             }
         }
+
+        #[test]
+        fn test_exact_decimal() {
+            let test_data: Vec<(Number, NumberFormat, &str)> = vec![
+                (
+                    Number::from(Decimal::new(12345678901234567891, 2)),
+                    NumberFormat::default(),
+                    "123,456,789,012,345,678.91",
+                ),
+                (
+                    Number::from(u128::MAX),
+                    NumberFormat::default(),
+                    "340,282,366,920,938,463,463,374,607,431,768,211,455",
+                ),
+                (
+                    // 2.67499… as a float
+                    Number::from(Decimal::new(2675, 3)),
+                    NumberFormat {
+                        maximum_fraction_digits: Some(2),
+                        rounding_mode: RoundingMode::HalfExpand,
+                        ..Default::default()
+                    },
+                    "2.68",
+                ),
+                (
+                    // halfway between increments, 1.02499… as a float
+                    Number::from(Decimal::new(1025, 3)),
+                    NumberFormat {
+                        minimum_fraction_digits: Some(2),
+                        maximum_fraction_digits: Some(2),
+                        rounding_increment: 5,
+                        rounding_mode: RoundingMode::HalfExpand,
+                        ..Default::default()
+                    },
+                    "1.05",
+                ),
+            ];
+
+            for (n, format, expected) in test_data {
+                let mut s = String::new();
+                format_number("en-US", &n, &Some(format), &mut s).expect("Number to be formatted");
+
+                assert_eq!(expected, s);
+            }
+        }
     }
 }
@@ -1,10 +1,111 @@
-use std::str::FromStr;
+use std::{ops::RangeInclusive, str::FromStr};
 
 use fluent_static_value::{
-    number::format::{CurrencyDisplayStyle, GroupingStyle, UnitDisplayStyle},
-    Number, NumberFormat, Value,
+    list::{ListStyle, ListType},
+    number::{
+        format::{
+            CompactDisplay, CurrencyCode, CurrencyDisplayStyle, CurrencySignMode, GroupingStyle,
+            Notation, NumberStyle, SignDisplay, TrailingZeroDisplay, UnitDisplayStyle,
+            UnitIdentifier,
+        },
+        RoundingMode,
+    },
+    relative_time::{RelativeTimeNumeric, RelativeTimeStyle, RelativeTimeUnit},
+    ListFormat, Number, NumberFormat, RelativeTimeFormat, Value,
 };
 
+use crate::{
+    schema::{parses, FunctionSchema, OptionKind, OptionSchema},
+    FunctionPack, PackFunction,
+};
+
+/// Builtin functions, registered by default
+pub const FUNCTIONS: FunctionPack = FunctionPack::new(
+    "::fluent_static::function::builtins",
+    &[
+        PackFunction::new("NUMBER", "number", NUMBER_SCHEMA),
+        PackFunction::new("LIST", "list", LIST_SCHEMA),
+        PackFunction::new("RELATIVE_TIME", "relative_time", RELATIVE_TIME_SCHEMA),
+    ],
+);
+
+pub const NUMBER_SCHEMA: FunctionSchema = FunctionSchema::new(1, Some(1)).with_options(&[
+    OptionSchema::new("style", OptionKind::String(is_number_style)),
+    OptionSchema::new("currency", OptionKind::String(parses::<CurrencyCode>)),
+    OptionSchema::new(
+        "currencyDisplay",
+        OptionKind::String(parses::<CurrencyDisplayStyle>),
+    ),
+    OptionSchema::new(
+        "currencySign",
+        OptionKind::String(parses::<CurrencySignMode>),
+    ),
+    OptionSchema::new("unit", OptionKind::String(parses::<UnitIdentifier>)),
+    OptionSchema::new(
+        "unitDisplay",
+        OptionKind::String(parses::<UnitDisplayStyle>),
+    ),
+    OptionSchema::new("useGrouping", OptionKind::String(parses::<GroupingStyle>)),
+    OptionSchema::new("notation", OptionKind::String(parses::<Notation>)),
+    OptionSchema::new(
+        "compactDisplay",
+        OptionKind::String(parses::<CompactDisplay>),
+    ),
+    OptionSchema::new("minimumIntegerDigits", OptionKind::Integer(INTEGER_DIGITS)),
+    OptionSchema::new(
+        "minimumFractionDigits",
+        OptionKind::Integer(FRACTION_DIGITS),
+    ),
+    OptionSchema::new(
+        "maximumFractionDigits",
+        OptionKind::Integer(FRACTION_DIGITS),
+    ),
+    OptionSchema::new(
+        "minimumSignificantDigits",
+        OptionKind::Integer(SIGNIFICANT_DIGITS),
+    ),
+    OptionSchema::new(
+        "maximumSignificantDigits",
+        OptionKind::Integer(SIGNIFICANT_DIGITS),
+    ),
+    OptionSchema::new("roundingMode", OptionKind::String(parses::<RoundingMode>)),
+    OptionSchema::new("roundingIncrement", OptionKind::OneOf(ROUNDING_INCREMENTS)),
+    OptionSchema::new("signDisplay", OptionKind::String(parses::<SignDisplay>)),
+    OptionSchema::new(
+        "trailingZeroDisplay",
+        OptionKind::String(parses::<TrailingZeroDisplay>),
+    ),
+]);
+
+/// Range of ECMA-402 `minimumIntegerDigits` option
+const INTEGER_DIGITS: RangeInclusive<usize> = 1..=21;
+
+/// Range of ECMA-402 `minimumFractionDigits` and `maximumFractionDigits` options
+const FRACTION_DIGITS: RangeInclusive<usize> = 0..=100;
+
+/// Range of ECMA-402 `minimumSignificantDigits` and `maximumSignificantDigits` options
+const SIGNIFICANT_DIGITS: RangeInclusive<usize> = 1..=21;
+
+/// Values allowed by ECMA-402 `roundingIncrement` option
+const ROUNDING_INCREMENTS: &[usize] = &[
+    1, 2, 5, 10, 20, 25, 50, 100, 200, 250, 500, 1000, 2000, 2500, 5000,
+];
+
+pub const LIST_SCHEMA: FunctionSchema = FunctionSchema::new(0, None).with_options(&[
+    OptionSchema::new("type", OptionKind::String(parses::<ListType>)),
+    OptionSchema::new("style", OptionKind::String(parses::<ListStyle>)),
+]);
+
+pub const RELATIVE_TIME_SCHEMA: FunctionSchema = FunctionSchema::new(1, Some(1)).with_options(&[
+    OptionSchema::new("unit", OptionKind::String(parses::<RelativeTimeUnit>)),
+    OptionSchema::new("numeric", OptionKind::String(parses::<RelativeTimeNumeric>)),
+    OptionSchema::new("style", OptionKind::String(parses::<RelativeTimeStyle>)),
+]);
+
+fn is_number_style(s: &str) -> bool {
+    matches!(s, "decimal" | "percent" | "currency" | "unit")
+}
+
 pub fn number<'a, 'b>(
     positional_args: &'a [Value<'a>],
     named_args: &'a [(&'a str, Value<'a>)],
@@ -12,70 +113,345 @@ pub fn number<'a, 'b>(
     if let Some(value) = positional_args.get(0) {
         match value {
             Value::String(s) => Number::from_str(s)
-                .map(|n| Value::Number {
-                    value: n,
-                    format: Some(parse_number_format(None, named_args)),
+                .ok()
+                .zip(parse_number_format(None, named_args).ok())
+                .map(|(value, format)| Value::Number {
+                    value,
+                    format: Some(format),
+                })
+                .unwrap_or(Value::Error),
+            Value::Number { value, format } => parse_number_format(format.clone(), named_args)
+                .map(|format| Value::Number {
+                    value: *value,
+                    format: Some(format),
                 })
                 .unwrap_or(Value::Error),
-            Value::Number { value, format } => Value::Number {
-                value: value.clone(),
-                format: Some(parse_number_format(format.clone(), named_args)),
-            },
             Value::Empty => Value::Empty,
-            Value::Error => Value::Error,
+            _ => Value::Error,
         }
     } else {
         Value::Error
     }
 }
 
-fn parse_number_format<'a>(
-    value_format: Option<NumberFormat>,
+/// Formats either a single list argument or all positional arguments as a list
+pub fn list<'a, 'b>(
+    positional_args: &'a [Value<'a>],
+    named_args: &'a [(&'a str, Value<'a>)],
+) -> Value<'b> {
+    let (items, format) = match positional_args {
+        [Value::List(items)] => (items.as_slice(), None),
+        [Value::FormattedList { items, format }] => (items.as_slice(), Some(*format)),
+        items => (items, None),
+    };
+    parse_list_format(format, named_args)
+        .map(|format| Value::FormattedList {
+            items: items.iter().cloned().map(Value::into_owned).collect(),
+            format,
+        })
+        .unwrap_or(Value::Error)
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ListOptionError {
+    #[error("Invalid value of LIST option '{0}'")]
+    InvalidValue(&'static str),
+}
+
+fn parse_list_format<'a>(
+    value_format: Option<ListFormat>,
     named_args: &'a [(&'a str, Value<'a>)],
-) -> NumberFormat {
+) -> Result<ListFormat, ListOptionError> {
     let mut result = value_format.unwrap_or_default();
     for (key, value) in named_args {
         match *key {
-            "currencyDisplay" if value.is_string() && result.style.is_currency() => {
-                if let Value::String(s) = value {
-                    result.style.set_currency_display_style(
-                        CurrencyDisplayStyle::from_str(s).unwrap_or_default(),
-                    );
-                }
+            "type" => {
+                result.list_type = read_list_option::<ListType>("type", value)?;
+            }
+            "style" => {
+                result.style = read_list_option::<ListStyle>("style", value)?;
             }
-            "unitDisplay" if value.is_string() && result.style.is_unit() => {
-                if let Value::String(s) = value {
-                    result
-                        .style
-                        .set_unit_display_style(UnitDisplayStyle::from_str(s).unwrap_or_default());
+            _ => {}
+        }
+    }
+    Ok(result)
+}
+
+fn read_list_option<T: FromStr>(option: &'static str, value: &Value) -> Result<T, ListOptionError> {
+    match value {
+        Value::String(s) => T::from_str(s).map_err(|_| ListOptionError::InvalidValue(option)),
+        _ => Err(ListOptionError::InvalidValue(option)),
+    }
+}
+
+/// Formats a number of `unit`s relative to now, e.g. "in 3 days" or "2 hours ago".
+///
+/// If `unit` option is omitted the value is an offset in seconds, formatted in the largest fitting unit
+pub fn relative_time<'a, 'b>(
+    positional_args: &'a [Value<'a>],
+    named_args: &'a [(&'a str, Value<'a>)],
+) -> Value<'b> {
+    let (value, format) = match positional_args.first() {
+        Some(Value::String(s)) => match Number::from_str(s) {
+            Ok(value) => (value, None),
+            Err(_) => return Value::Error,
+        },
+        Some(Value::Number { value, .. }) => (*value, None),
+        Some(Value::RelativeTime { value, format }) => (*value, Some(*format)),
+        Some(Value::Empty) => return Value::Empty,
+        _ => return Value::Error,
+    };
+    let options = match parse_relative_time_options(named_args) {
+        Ok(options) => options,
+        Err(_) => return Value::Error,
+    };
+    let (value, mut format) = match (format, options.unit) {
+        (format, Some(unit)) => (
+            value,
+            RelativeTimeFormat {
+                unit,
+                ..format.unwrap_or_default()
+            },
+        ),
+        (Some(format), None) => (value, format),
+        (None, None) => {
+            let (value, unit) = RelativeTimeUnit::best_fit(value.as_f64());
+            (Number::from(value), RelativeTimeFormat::new(unit))
+        }
+    };
+    if let Some(numeric) = options.numeric {
+        format.numeric = numeric;
+    }
+    if let Some(style) = options.style {
+        format.style = style;
+    }
+    Value::RelativeTime { value, format }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RelativeTimeOptionError {
+    #[error("Invalid value of RELATIVE_TIME option '{0}'")]
+    InvalidValue(&'static str),
+}
+
+#[derive(Default)]
+struct RelativeTimeOptions {
+    unit: Option<RelativeTimeUnit>,
+    numeric: Option<RelativeTimeNumeric>,
+    style: Option<RelativeTimeStyle>,
+}
+
+fn parse_relative_time_options<'a>(
+    named_args: &'a [(&'a str, Value<'a>)],
+) -> Result<RelativeTimeOptions, RelativeTimeOptionError> {
+    let mut result = RelativeTimeOptions::default();
+    for (key, value) in named_args {
+        match *key {
+            "unit" => result.unit = Some(read_relative_time_option("unit", value)?),
+            "numeric" => result.numeric = Some(read_relative_time_option("numeric", value)?),
+            "style" => result.style = Some(read_relative_time_option("style", value)?),
+            _ => {}
+        }
+    }
+    Ok(result)
+}
+
+fn read_relative_time_option<T: FromStr>(
+    option: &'static str,
+    value: &Value,
+) -> Result<T, RelativeTimeOptionError> {
+    match value {
+        Value::String(s) => {
+            T::from_str(s).map_err(|_| RelativeTimeOptionError::InvalidValue(option))
+        }
+        _ => Err(RelativeTimeOptionError::InvalidValue(option)),
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum NumberOptionError {
+    #[error("Invalid value of NUMBER option '{0}'")]
+    InvalidValue(&'static str),
+    #[error("NUMBER option '{0}' is required for style '{1}'")]
+    MissingOption(&'static str, &'static str),
+}
+
+#[derive(Default)]
+struct NumberStyleOptions<'a> {
+    style: Option<&'a str>,
+    currency: Option<CurrencyCode>,
+    currency_display: Option<CurrencyDisplayStyle>,
+    currency_sign: Option<CurrencySignMode>,
+    unit: Option<UnitIdentifier>,
+    unit_display: Option<UnitDisplayStyle>,
+}
+
+impl<'a> NumberStyleOptions<'a> {
+    fn apply(self, current: NumberStyle) -> Result<NumberStyle, NumberOptionError> {
+        let mut result = match self.style {
+            None => current,
+            Some("decimal") => NumberStyle::Decimal,
+            Some("percent") => NumberStyle::Percent,
+            Some("currency") => match current {
+                style @ NumberStyle::Currency { .. } => style,
+                _ => NumberStyle::Currency {
+                    code: self
+                        .currency
+                        .ok_or(NumberOptionError::MissingOption("currency", "currency"))?,
+                    style: CurrencyDisplayStyle::default(),
+                    sign: CurrencySignMode::default(),
+                },
+            },
+            Some("unit") => match current {
+                style @ NumberStyle::Unit { .. } => style,
+                _ => NumberStyle::Unit {
+                    identifier: self
+                        .unit
+                        .clone()
+                        .ok_or(NumberOptionError::MissingOption("unit", "unit"))?,
+                    style: UnitDisplayStyle::default(),
+                },
+            },
+            Some(_) => return Err(NumberOptionError::InvalidValue("style")),
+        };
+
+        match &mut result {
+            NumberStyle::Currency { code, style, sign } => {
+                if let Some(currency) = self.currency {
+                    *code = currency;
+                }
+                if let Some(currency_display) = self.currency_display {
+                    *style = currency_display;
+                }
+                if let Some(currency_sign) = self.currency_sign {
+                    *sign = currency_sign;
                 }
             }
-            "useGrouping" if value.is_string() => {
-                if let Value::String(s) = value {
-                    result.use_grouping = GroupingStyle::from_str(s).unwrap_or_default();
+            NumberStyle::Unit { identifier, style } => {
+                if let Some(unit) = self.unit {
+                    *identifier = unit;
                 }
+                if let Some(unit_display) = self.unit_display {
+                    *style = unit_display;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(result)
+    }
+}
+
+/// Applies `NUMBER` function options to the format of a value, used to create
+/// formats of literal options ahead of formatting
+pub fn parse_number_format<'a>(
+    value_format: Option<NumberFormat>,
+    named_args: &'a [(&'a str, Value<'a>)],
+) -> Result<NumberFormat, NumberOptionError> {
+    let mut result = value_format.unwrap_or_default();
+    let mut style_options = NumberStyleOptions::default();
+    for (key, value) in named_args {
+        match *key {
+            "style" => style_options.style = Some(read_str("style", value)?),
+            "currency" => style_options.currency = Some(read_option("currency", value)?),
+            "currencyDisplay" => {
+                style_options.currency_display = Some(read_option("currencyDisplay", value)?)
+            }
+            "currencySign" => {
+                style_options.currency_sign = Some(read_option("currencySign", value)?)
+            }
+            "unit" => style_options.unit = Some(read_option("unit", value)?),
+            "unitDisplay" => style_options.unit_display = Some(read_option("unitDisplay", value)?),
+            "useGrouping" => result.use_grouping = read_option("useGrouping", value)?,
+            "notation" => result.notation = read_option("notation", value)?,
+            "compactDisplay" => result.compact_display = read_option("compactDisplay", value)?,
+            "minimumIntegerDigits" => {
+                result.minimum_integer_digits =
+                    Some(read_digits("minimumIntegerDigits", value, INTEGER_DIGITS)?)
+            }
+            "minimumFractionDigits" => {
+                result.minimum_fraction_digits = Some(read_digits(
+                    "minimumFractionDigits",
+                    value,
+                    FRACTION_DIGITS,
+                )?)
+            }
+            "maximumFractionDigits" => {
+                result.maximum_fraction_digits = Some(read_digits(
+                    "maximumFractionDigits",
+                    value,
+                    FRACTION_DIGITS,
+                )?)
             }
-            "minimumIntegerDigits" => result.minimum_integer_digits = read_digits(value, 1, 21),
-            "minimumFractionDigits" => result.minimum_fraction_digits = read_digits(value, 0, 100),
-            "maximumFractionDigits" => result.maximum_fraction_digits = read_digits(value, 0, 100),
             "minimumSignificantDigits" => {
-                result.minimum_significant_digits = read_digits(value, 1, 21)
+                result.minimum_significant_digits = Some(read_digits(
+                    "minimumSignificantDigits",
+                    value,
+                    SIGNIFICANT_DIGITS,
+                )?)
             }
             "maximumSignificantDigits" => {
-                result.maximum_significant_digits = read_digits(value, 1, 21)
+                result.maximum_significant_digits = Some(read_digits(
+                    "maximumSignificantDigits",
+                    value,
+                    SIGNIFICANT_DIGITS,
+                )?)
+            }
+            "roundingMode" => result.rounding_mode = read_option("roundingMode", value)?,
+            "roundingIncrement" => {
+                let increment = read_integer("roundingIncrement", value)?;
+                if !ROUNDING_INCREMENTS.contains(&increment) {
+                    return Err(NumberOptionError::InvalidValue("roundingIncrement"));
+                }
+                result.rounding_increment = increment;
+            }
+            "signDisplay" => result.sign_display = read_option("signDisplay", value)?,
+            "trailingZeroDisplay" => {
+                result.trailing_zero_display = read_option("trailingZeroDisplay", value)?
             }
             _ => {}
         }
     }
-    result
+    result.style = style_options.apply(result.style)?;
+    Ok(result)
 }
 
-fn read_digits<'a>(value: &Value<'a>, min: usize, max: usize) -> Option<usize> {
+fn read_str<'a>(option: &'static str, value: &'a Value<'a>) -> Result<&'a str, NumberOptionError> {
     match value {
-        Value::String(s) => Number::from_str(s).ok().map(|n| clamp(&n, min, max)),
-        Value::Number { value, .. } => Some(clamp(value, min, max)),
+        Value::String(s) => Ok(s),
+        _ => Err(NumberOptionError::InvalidValue(option)),
+    }
+}
+
+fn read_option<T: FromStr>(option: &'static str, value: &Value) -> Result<T, NumberOptionError> {
+    read_str(option, value)
+        .and_then(|s| T::from_str(s).map_err(|_| NumberOptionError::InvalidValue(option)))
+}
+
+fn read_digits(
+    option: &'static str,
+    value: &Value,
+    digits: RangeInclusive<usize>,
+) -> Result<usize, NumberOptionError> {
+    let (min, max) = digits.into_inner();
+    match value {
+        Value::String(s) => Number::from_str(s)
+            .map(|n| clamp(&n, min, max))
+            .map_err(|_| NumberOptionError::InvalidValue(option)),
+        Value::Number { value, .. } => Ok(clamp(value, min, max)),
+        _ => Err(NumberOptionError::InvalidValue(option)),
+    }
+}
+
+fn read_integer(option: &'static str, value: &Value) -> Result<usize, NumberOptionError> {
+    match value {
+        Value::String(s) => Number::from_str(s)
+            .ok()
+            .and_then(|n| usize::try_from(n).ok()),
+        Value::Number { value, .. } => usize::try_from(value).ok(),
         _ => None,
     }
+    .ok_or(NumberOptionError::InvalidValue(option))
 }
 
 fn clamp(value: &Number, min: usize, max: usize) -> usize {
@@ -88,5 +464,285 @@ fn clamp(value: &Number, min: usize, max: usize) -> usize {
             let clamped = val.clamp(min as f64, max as f64);
             clamped.round() as usize
         }
+        Number::Decimal(val) => val.trunc().clamp(min as i128, max as i128) as usize,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use fluent_static_value::{
+        list::{ListStyle, ListType},
+        number::{
+            format::{
+                CompactDisplay, CurrencyCode, CurrencyDisplayStyle, CurrencySignMode,
+                GroupingStyle, Notation, NumberStyle, SignDisplay, TrailingZeroDisplay,
+                UnitDisplayStyle, UnitIdentifier,
+            },
+            RoundingMode,
+        },
+        relative_time::{RelativeTimeNumeric, RelativeTimeStyle, RelativeTimeUnit},
+        ListFormat, Number, NumberFormat, RelativeTimeFormat, Value,
+    };
+
+    use super::{list, number, relative_time};
+
+    fn number_format(value: Value, named_args: &[(&str, Value)]) -> Option<NumberFormat> {
+        match number(&[value], named_args) {
+            Value::Number { format, .. } => format,
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_currency_style() {
+        let format = number_format(
+            Value::from(42),
+            &[
+                ("style", Value::from("currency")),
+                ("currency", Value::from("EUR")),
+                ("currencyDisplay", Value::from("name")),
+                ("currencySign", Value::from("accounting")),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            NumberStyle::Currency {
+                code: CurrencyCode::EUR,
+                style: CurrencyDisplayStyle::Name,
+                sign: CurrencySignMode::Accounting,
+            },
+            format.style
+        );
+    }
+
+    #[test]
+    fn test_unit_style() {
+        let format = number_format(
+            Value::from("12.5"),
+            &[
+                ("unitDisplay", Value::from("long")),
+                ("unit", Value::from("kilometer-per-hour")),
+                ("style", Value::from("unit")),
+                ("useGrouping", Value::from("false")),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            NumberStyle::Unit {
+                identifier: UnitIdentifier::Kilometer.per(UnitIdentifier::Hour),
+                style: UnitDisplayStyle::Long,
+            },
+            format.style
+        );
+        assert_eq!(GroupingStyle::Off, format.use_grouping);
+    }
+
+    #[test]
+    fn test_override_value_format() {
+        let value = Value::formatted_number(42, NumberFormat::currency(CurrencyCode::USD));
+
+        let format = number_format(value.clone(), &[("currency", Value::from("JPY"))]).unwrap();
+        assert_eq!(
+            NumberFormat::currency(CurrencyCode::JPY).style,
+            format.style
+        );
+
+        let format = number_format(value, &[("style", Value::from("percent"))]).unwrap();
+        assert_eq!(NumberStyle::Percent, format.style);
+    }
+
+    #[test]
+    fn test_notation() {
+        let format = number_format(
+            Value::from(1234),
+            &[
+                ("notation", Value::from("compact")),
+                ("compactDisplay", Value::from("long")),
+            ],
+        )
+        .unwrap();
+        assert_eq!(Notation::Compact, format.notation);
+        assert_eq!(CompactDisplay::Long, format.compact_display);
+
+        let format = number_format(
+            Value::from(1234),
+            &[("notation", Value::from("engineering"))],
+        )
+        .unwrap();
+        assert_eq!(Notation::Engineering, format.notation);
+        assert_eq!(CompactDisplay::Short, format.compact_display);
+    }
+
+    #[test]
+    fn test_rounding_and_sign() {
+        let format = number_format(
+            Value::from(1.23),
+            &[
+                ("roundingMode", Value::from("halfExpand")),
+                ("roundingIncrement", Value::from(5)),
+                ("signDisplay", Value::from("exceptZero")),
+                ("trailingZeroDisplay", Value::from("stripIfInteger")),
+            ],
+        )
+        .unwrap();
+        assert_eq!(RoundingMode::HalfExpand, format.rounding_mode);
+        assert_eq!(5, format.rounding_increment);
+        assert_eq!(SignDisplay::ExceptZero, format.sign_display);
+        assert_eq!(
+            TrailingZeroDisplay::StripIfInteger,
+            format.trailing_zero_display
+        );
+
+        let format = number_format(Value::from(1.23), &[]).unwrap();
+        assert_eq!(RoundingMode::HalfEven, format.rounding_mode);
+        assert_eq!(1, format.rounding_increment);
+    }
+
+    #[test]
+    fn test_invalid_options() {
+        let invalid_options = [
+            vec![("style", Value::from("scientific"))],
+            vec![("style", Value::from("currency"))],
+            vec![("style", Value::from("unit"))],
+            vec![("currency", Value::from("EURO"))],
+            vec![("unit", Value::from("parsec"))],
+            vec![("currencySign", Value::from("negative"))],
+            vec![("currencyDisplay", Value::from(42))],
+            vec![("useGrouping", Value::from("sometimes"))],
+            vec![("notation", Value::from("exponential"))],
+            vec![("compactDisplay", Value::from("narrow"))],
+            vec![("roundingMode", Value::from("halfUp"))],
+            vec![("roundingIncrement", Value::from(3))],
+            vec![("roundingIncrement", Value::from("five"))],
+            vec![("signDisplay", Value::from("positive"))],
+            vec![("trailingZeroDisplay", Value::from("strip"))],
+            vec![("minimumFractionDigits", Value::from("two"))],
+        ];
+        for named_args in invalid_options {
+            assert!(
+                matches!(number(&[Value::from(42)], &named_args), Value::Error),
+                "{:?}",
+                named_args
+            );
+        }
+    }
+
+    #[test]
+    fn test_list_format() {
+        let value = list(
+            &[Value::from("a"), Value::from(1)],
+            &[
+                ("type", Value::from("disjunction")),
+                ("style", Value::from("narrow")),
+            ],
+        );
+        assert_eq!(Value::list([Value::from("a"), Value::from(1)]), value);
+        assert!(matches!(
+            value,
+            Value::FormattedList {
+                format: ListFormat {
+                    list_type: ListType::Disjunction,
+                    style: ListStyle::Narrow
+                },
+                ..
+            }
+        ));
+
+        let value = Value::formatted_list(
+            ["a", "b"],
+            ListFormat::new(ListType::Unit, ListStyle::Short),
+        );
+        let result = list(&[value], &[("style", Value::from("long"))]);
+        assert_eq!(Value::list(["a", "b"]), result);
+        assert!(matches!(
+            result,
+            Value::FormattedList {
+                format: ListFormat {
+                    list_type: ListType::Unit,
+                    style: ListStyle::Long
+                },
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_invalid_list_options() {
+        let invalid_options = [
+            vec![("type", Value::from("and"))],
+            vec![("style", Value::from("wide"))],
+            vec![("style", Value::from(1))],
+        ];
+        for named_args in invalid_options {
+            assert!(
+                matches!(list(&[Value::from("a")], &named_args), Value::Error),
+                "{:?}",
+                named_args
+            );
+        }
+    }
+
+    #[test]
+    fn test_relative_time() {
+        let value = relative_time(
+            &[Value::from(-3)],
+            &[
+                ("unit", Value::from("days")),
+                ("numeric", Value::from("auto")),
+                ("style", Value::from("short")),
+            ],
+        );
+        assert!(matches!(
+            value,
+            Value::RelativeTime {
+                value: Number::I64(-3),
+                format: RelativeTimeFormat {
+                    unit: RelativeTimeUnit::Day,
+                    numeric: RelativeTimeNumeric::Auto,
+                    style: RelativeTimeStyle::Short,
+                },
+            }
+        ));
+
+        // offset in seconds without unit
+        let value = relative_time(&[Value::from("7200")], &[]);
+        assert_eq!(Value::relative_time(2, RelativeTimeUnit::Hour), value);
+
+        let value = relative_time(
+            &[Value::relative_time(5, RelativeTimeUnit::Minute)],
+            &[("style", Value::from("narrow"))],
+        );
+        assert!(matches!(
+            value,
+            Value::RelativeTime {
+                format: RelativeTimeFormat {
+                    unit: RelativeTimeUnit::Minute,
+                    style: RelativeTimeStyle::Narrow,
+                    ..
+                },
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_invalid_relative_time_options() {
+        let invalid_options = [
+            vec![("unit", Value::from("decade"))],
+            vec![("numeric", Value::from("never"))],
+            vec![("style", Value::from("wide"))],
+            vec![("unit", Value::from(1))],
+        ];
+        for named_args in invalid_options {
+            assert!(
+                matches!(relative_time(&[Value::from(1)], &named_args), Value::Error),
+                "{:?}",
+                named_args
+            );
+        }
+        assert!(matches!(
+            relative_time(&[Value::from("soon")], &[]),
+            Value::Error
+        ));
     }
 }
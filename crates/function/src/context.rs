@@ -0,0 +1,168 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt::{Debug, Write},
+    sync::Arc,
+};
+
+use fluent_static_value::Value;
+use intl_pluralrules::PluralRules;
+
+use crate::ValueFormatter;
+
+/// Value formatter function, same as `fluent_static::formatter::FormatterFn`
+pub type FormatFn = fn(&str, &Value, &mut dyn Write) -> std::fmt::Result;
+
+/// Bundle state available to functions registered with context,
+/// see [`ContextualFunction`](crate::ContextualFunction)
+pub struct FunctionContext<'a> {
+    language_id: &'a str,
+    plural_rules: &'a PluralRules,
+    formatter: &'a dyn ValueFormatter,
+    extensions: &'a Extensions,
+}
+
+impl<'a> FunctionContext<'a> {
+    pub fn new(
+        language_id: &'a str,
+        plural_rules: &'a PluralRules,
+        formatter: &'a dyn ValueFormatter,
+        extensions: &'a Extensions,
+    ) -> Self {
+        Self {
+            language_id,
+            plural_rules,
+            formatter,
+            extensions,
+        }
+    }
+
+    pub fn language_id(&self) -> &'a str {
+        self.language_id
+    }
+
+    /// Cardinal plural rules of the bundle language
+    pub fn plural_rules(&self) -> &'a PluralRules {
+        self.plural_rules
+    }
+
+    /// Formats value with the bundle formatter, without bidi isolation
+    pub fn write(&self, value: &Value, out: &mut dyn Write) -> std::fmt::Result {
+        self.formatter.format(self.language_id, value, out)
+    }
+
+    pub fn formatter(&self) -> &'a dyn ValueFormatter {
+        self.formatter
+    }
+
+    pub fn format(&self, value: &Value) -> Result<String, std::fmt::Error> {
+        let mut result = String::new();
+        self.write(value, &mut result)?;
+        Ok(result)
+    }
+
+    pub fn extensions(&self) -> &'a Extensions {
+        self.extensions
+    }
+
+    /// Shortcut for `self.extensions().get::<T>()`
+    pub fn extension<T: Any>(&self) -> Option<&'a T> {
+        self.extensions.get::<T>()
+    }
+}
+
+/// Typed map of application values (services, settings) stored on a bundle,
+/// at most one value per type
+#[derive(Clone, Default)]
+pub struct Extensions {
+    map: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+impl Extensions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts value replacing previously inserted value of the same type
+    pub fn insert<T: Any + Send + Sync>(&mut self, value: T) {
+        self.map.insert(TypeId::of::<T>(), Arc::new(value));
+    }
+
+    pub fn get<T: Any>(&self) -> Option<&T> {
+        self.map
+            .get(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_ref::<T>())
+    }
+
+    pub fn remove<T: Any>(&mut self) -> bool {
+        self.map.remove(&TypeId::of::<T>()).is_some()
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+impl Debug for Extensions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Extensions")
+            .field("len", &self.map.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fmt::Write;
+
+    use fluent_static_value::Value;
+    use intl_pluralrules::{PluralRuleType, PluralRules};
+    use unic_langid::LanguageIdentifier;
+
+    use super::{Extensions, FormatFn, FunctionContext};
+
+    struct Rates(f64);
+
+    fn format_fn(language_id: &str, value: &Value, out: &mut dyn Write) -> std::fmt::Result {
+        match value {
+            Value::String(s) => write!(out, "{}:{}", language_id, s),
+            _ => Err(std::fmt::Error),
+        }
+    }
+
+    #[test]
+    fn test_extensions() {
+        let mut extensions = Extensions::new();
+        assert!(extensions.get::<Rates>().is_none());
+
+        extensions.insert(Rates(1.5));
+        extensions.insert(Rates(2.0));
+        extensions.insert("base");
+        assert_eq!(2, extensions.len());
+        assert_eq!(2.0, extensions.get::<Rates>().unwrap().0);
+        assert_eq!(Some(&"base"), extensions.get::<&str>());
+
+        let cloned = extensions.clone();
+        assert!(extensions.remove::<Rates>());
+        assert!(!extensions.remove::<Rates>());
+        assert!(cloned.get::<Rates>().is_some());
+    }
+
+    #[test]
+    fn test_function_context() {
+        let language_id: LanguageIdentifier = "en".parse().unwrap();
+        let rules = PluralRules::create(language_id, PluralRuleType::CARDINAL).unwrap();
+        let mut extensions = Extensions::new();
+        extensions.insert(Rates(1.5));
+
+        let context = FunctionContext::new("en", &rules, &(format_fn as FormatFn), &extensions);
+        assert_eq!("en", context.language_id());
+        assert_eq!(1.5, context.extension::<Rates>().unwrap().0);
+        assert_eq!("en:foo", context.format(&Value::from("foo")).unwrap());
+        assert!(context.format(&Value::Empty).is_err());
+    }
+}
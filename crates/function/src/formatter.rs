@@ -0,0 +1,135 @@
+use std::{
+    fmt::{Debug, Write},
+    sync::Arc,
+};
+
+use fluent_static_value::Value;
+
+use crate::context::FormatFn;
+
+/// Formatter of placeable values, could keep state like caches, configuration or exchange rates.
+///
+/// Plain [`FormatFn`] functions are formatters as well
+pub trait ValueFormatter: Send + Sync {
+    /// Called once the formatter is set on a bundle of the language,
+    /// e.g. to prepare language specific resources
+    fn init(&self, _language_id: &str) {}
+
+    fn format(&self, language_id: &str, value: &Value, out: &mut dyn Write) -> std::fmt::Result;
+}
+
+impl ValueFormatter for FormatFn {
+    fn format(&self, language_id: &str, value: &Value, out: &mut dyn Write) -> std::fmt::Result {
+        self(language_id, value, out)
+    }
+}
+
+impl<T: ValueFormatter + ?Sized> ValueFormatter for Arc<T> {
+    fn init(&self, language_id: &str) {
+        (**self).init(language_id)
+    }
+
+    fn format(&self, language_id: &str, value: &Value, out: &mut dyn Write) -> std::fmt::Result {
+        (**self).format(language_id, value, out)
+    }
+}
+
+impl Debug for dyn ValueFormatter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ValueFormatter")
+    }
+}
+
+/// Formatter wrapping another one, see [`wrap`]
+pub struct WrappedFormatter<F> {
+    inner: Arc<dyn ValueFormatter>,
+    format: F,
+}
+
+/// Composes formatters: `format` receives the wrapped formatter to delegate values
+/// it doesn't handle or to format parts of the value, e.g. a number followed by a custom unit
+pub fn wrap<F>(inner: Arc<dyn ValueFormatter>, format: F) -> WrappedFormatter<F>
+where
+    F: Fn(&str, &Value, &mut dyn Write, &dyn ValueFormatter) -> std::fmt::Result + Send + Sync,
+{
+    WrappedFormatter { inner, format }
+}
+
+impl<F> ValueFormatter for WrappedFormatter<F>
+where
+    F: Fn(&str, &Value, &mut dyn Write, &dyn ValueFormatter) -> std::fmt::Result + Send + Sync,
+{
+    fn init(&self, language_id: &str) {
+        self.inner.init(language_id)
+    }
+
+    fn format(&self, language_id: &str, value: &Value, out: &mut dyn Write) -> std::fmt::Result {
+        (self.format)(language_id, value, out, self.inner.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        fmt::Write,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
+
+    use fluent_static_value::Value;
+
+    use super::{wrap, ValueFormatter};
+    use crate::context::FormatFn;
+
+    fn plain(_: &str, value: &Value, out: &mut dyn Write) -> std::fmt::Result {
+        match value {
+            Value::String(s) => out.write_str(s),
+            Value::Number { value, .. } => write!(out, "{}", value.as_string()),
+            _ => Ok(()),
+        }
+    }
+
+    #[derive(Default)]
+    struct Counting(AtomicUsize);
+
+    impl ValueFormatter for Counting {
+        fn init(&self, _: &str) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fn format(
+            &self,
+            language_id: &str,
+            value: &Value,
+            out: &mut dyn Write,
+        ) -> std::fmt::Result {
+            (plain as FormatFn).format(language_id, value, out)
+        }
+    }
+
+    #[test]
+    fn test_wrap() {
+        let inner = Arc::new(Counting::default());
+        let formatter = wrap(
+            inner.clone(),
+            |language_id, value, out, inner| match value {
+                Value::Number { .. } => {
+                    inner.format(language_id, value, out)?;
+                    out.write_str(" km")
+                }
+                _ => inner.format(language_id, value, out),
+            },
+        );
+        formatter.init("en");
+        assert_eq!(1, inner.0.load(Ordering::Relaxed));
+
+        let mut out = String::new();
+        formatter.format("en", &Value::from(42), &mut out).unwrap();
+        formatter
+            .format("en", &Value::from(", foo"), &mut out)
+            .unwrap();
+        assert_eq!("42 km, foo", out);
+    }
+}
@@ -3,11 +3,43 @@ use std::any::type_name;
 use fluent_static_value::Value;
 
 pub mod builtins;
+pub mod context;
+pub mod formatter;
+pub mod pack;
+pub mod schema;
+
+pub use context::{Extensions, FunctionContext};
+pub use formatter::ValueFormatter;
+pub use pack::{call, find_function, FunctionPack, PackFunction};
+pub use schema::FunctionSchema;
 
 pub type FluentFunction<'a, 'b> = fn(&'a [Value<'a>], &'a [(&'a str, Value<'a>)]) -> Value<'b>;
 
+pub type ContextualFluentFunction<'a, 'b> =
+    fn(&'a FunctionContext<'a>, &'a [Value<'a>], &'a [(&'a str, Value<'a>)]) -> Value<'b>;
+
 pub trait FluentFunctionDescriptor {
     fn type_name(&self) -> &'static str;
+
+    /// Arguments accepted by the function, any arguments are accepted by default
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::ANY
+    }
+
+    /// Whether function expects [`FunctionContext`] as the first argument
+    fn requires_context(&self) -> bool {
+        false
+    }
+
+    fn with_schema(self, schema: FunctionSchema) -> DescribedFunction<Self>
+    where
+        Self: Sized,
+    {
+        DescribedFunction {
+            function: self,
+            schema,
+        }
+    }
 }
 
 impl<'b, F> FluentFunctionDescriptor for F
@@ -18,3 +50,44 @@ where
         type_name::<Self>()
     }
 }
+
+/// Function descriptor with an explicit [`FunctionSchema`]
+pub struct DescribedFunction<F> {
+    function: F,
+    schema: FunctionSchema,
+}
+
+impl<F: FluentFunctionDescriptor> FluentFunctionDescriptor for DescribedFunction<F> {
+    fn type_name(&self) -> &'static str {
+        self.function.type_name()
+    }
+
+    fn schema(&self) -> FunctionSchema {
+        self.schema
+    }
+
+    fn requires_context(&self) -> bool {
+        self.function.requires_context()
+    }
+}
+
+/// Descriptor of a function receiving [`FunctionContext`] of the calling bundle
+pub struct ContextualFunction<F>(pub F);
+
+impl<'b, F> FluentFunctionDescriptor for ContextualFunction<F>
+where
+    F: for<'a> Fn(
+            &'a FunctionContext<'a>,
+            &'a [Value<'a>],
+            &'a [(&'a str, Value<'a>)],
+        ) -> Value<'b>
+        + 'static,
+{
+    fn type_name(&self) -> &'static str {
+        type_name::<F>()
+    }
+
+    fn requires_context(&self) -> bool {
+        true
+    }
+}
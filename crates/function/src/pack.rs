@@ -0,0 +1,176 @@
+use fluent_static_value::Value;
+
+use crate::{schema::const_str_eq, FunctionContext, FunctionSchema};
+
+/// Set of Fluent functions published by a crate.
+///
+/// Functions are referenced in generated code as `{path}::{name}`, so `path` must be
+/// the absolute path of the module as seen from the crate using the pack,
+/// e.g. `::my_l10n_fns`
+#[derive(Debug, Clone, Copy)]
+pub struct FunctionPack {
+    pub path: &'static str,
+    pub functions: &'static [PackFunction],
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PackFunction {
+    /// Fluent function ID, e.g. `NUMBER`
+    pub id: &'static str,
+    /// Rust function name within the pack module
+    pub name: &'static str,
+    pub schema: FunctionSchema,
+}
+
+impl FunctionPack {
+    pub const fn new(path: &'static str, functions: &'static [PackFunction]) -> Self {
+        Self { path, functions }
+    }
+}
+
+impl PackFunction {
+    pub const fn new(id: &'static str, name: &'static str, schema: FunctionSchema) -> Self {
+        Self { id, name, schema }
+    }
+
+    pub const fn has_name(&self, name: &str) -> bool {
+        const_str_eq(self.name, name)
+    }
+}
+
+/// Finds function by Fluent ID in the first pack defining it, usable in const context
+pub const fn find_function(packs: &[&FunctionPack], id: &str) -> Option<&'static PackFunction> {
+    let mut i = 0;
+    while i < packs.len() {
+        let functions = packs[i].functions;
+        let mut j = 0;
+        while j < functions.len() {
+            if const_str_eq(functions[j].id, id) {
+                return Some(&functions[j]);
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Marker of functions with [`FluentFunction`](crate::FluentFunction) signature
+pub struct WithoutContext;
+
+/// Marker of functions with [`ContextualFluentFunction`](crate::ContextualFluentFunction) signature
+pub struct WithContext;
+
+/// Fluent function with or without [`FunctionContext`] argument, allows generated code
+/// to call functions resolved by path without knowing their signature
+pub trait FluentFunctionImpl<'b, M> {
+    fn call_with<'a>(
+        &self,
+        context: &'a FunctionContext<'a>,
+        positional_args: &'a [Value<'a>],
+        named_args: &'a [(&'a str, Value<'a>)],
+    ) -> Value<'b>;
+}
+
+impl<'b, F> FluentFunctionImpl<'b, WithoutContext> for F
+where
+    F: for<'a> Fn(&'a [Value<'a>], &'a [(&'a str, Value<'a>)]) -> Value<'b>,
+{
+    fn call_with<'a>(
+        &self,
+        _context: &'a FunctionContext<'a>,
+        positional_args: &'a [Value<'a>],
+        named_args: &'a [(&'a str, Value<'a>)],
+    ) -> Value<'b> {
+        self(positional_args, named_args)
+    }
+}
+
+impl<'b, F> FluentFunctionImpl<'b, WithContext> for F
+where
+    F: for<'a> Fn(
+        &'a FunctionContext<'a>,
+        &'a [Value<'a>],
+        &'a [(&'a str, Value<'a>)],
+    ) -> Value<'b>,
+{
+    fn call_with<'a>(
+        &self,
+        context: &'a FunctionContext<'a>,
+        positional_args: &'a [Value<'a>],
+        named_args: &'a [(&'a str, Value<'a>)],
+    ) -> Value<'b> {
+        self(context, positional_args, named_args)
+    }
+}
+
+pub fn call<'a, 'b, M>(
+    function: impl FluentFunctionImpl<'b, M>,
+    context: &'a FunctionContext<'a>,
+    positional_args: &'a [Value<'a>],
+    named_args: &'a [(&'a str, Value<'a>)],
+) -> Value<'b> {
+    function.call_with(context, positional_args, named_args)
+}
+
+#[cfg(test)]
+mod test {
+    use fluent_static_value::Value;
+    use intl_pluralrules::{PluralRuleType, PluralRules};
+    use unic_langid::LanguageIdentifier;
+
+    use super::{call, find_function, FunctionPack, PackFunction};
+    use crate::{context::FormatFn, Extensions, FunctionContext, FunctionSchema};
+
+    fn first<'a, 'b>(positional_args: &'a [Value<'a>], _: &'a [(&'a str, Value<'a>)]) -> Value<'b> {
+        positional_args
+            .first()
+            .cloned()
+            .map(Value::into_owned)
+            .unwrap_or(Value::Empty)
+    }
+
+    fn language<'a, 'b>(
+        context: &'a FunctionContext<'a>,
+        _: &'a [Value<'a>],
+        _: &'a [(&'a str, Value<'a>)],
+    ) -> Value<'b> {
+        Value::from(context.language_id().to_string())
+    }
+
+    fn format_fn(_: &str, _: &Value, _: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        Ok(())
+    }
+
+    #[test]
+    fn test_call() {
+        let language_id: LanguageIdentifier = "fr".parse().unwrap();
+        let rules = PluralRules::create(language_id, PluralRuleType::CARDINAL).unwrap();
+        let extensions = Extensions::new();
+        let context = FunctionContext::new("fr", &rules, &(format_fn as FormatFn), &extensions);
+
+        let args = [Value::from("foo")];
+        assert_eq!(Value::from("foo"), call(first, &context, &args, &[]));
+        assert_eq!(Value::from("fr"), call(language, &context, &args, &[]));
+    }
+
+    #[test]
+    fn test_find_function() {
+        const FIRST: FunctionPack = FunctionPack::new(
+            "::first",
+            &[PackFunction::new("FIRST", "first", FunctionSchema::ANY)],
+        );
+        const SECOND: FunctionPack = FunctionPack::new(
+            "::second",
+            &[
+                PackFunction::new("FIRST", "other", FunctionSchema::ANY),
+                PackFunction::new("LANGUAGE", "language", FunctionSchema::new(0, Some(0))),
+            ],
+        );
+        const LANGUAGE: Option<&PackFunction> = find_function(&[&FIRST, &SECOND], "LANGUAGE");
+
+        assert!(LANGUAGE.is_some_and(|function| function.has_name("language")));
+        assert!(find_function(&[&FIRST, &SECOND], "FIRST").is_some_and(|f| f.has_name("first")));
+        assert!(find_function(&[&FIRST, &SECOND], "LAST").is_none());
+    }
+}
@@ -0,0 +1,377 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use fluent_static_value::{Number, Value};
+
+/// Positional arity and named options accepted by a Fluent function,
+/// used to validate function calls at code generation time
+#[derive(Debug, Clone, Copy)]
+pub struct FunctionSchema {
+    pub min_args: usize,
+    pub max_args: Option<usize>,
+    /// Allowed named options, `None` accepts any option
+    pub options: Option<&'static [OptionSchema]>,
+}
+
+#[derive(Debug, Clone)]
+pub struct OptionSchema {
+    pub name: &'static str,
+    pub kind: OptionKind,
+}
+
+/// Kind of a named option value, checked only if the value is a literal
+#[derive(Debug, Clone)]
+pub enum OptionKind {
+    Any,
+    Number,
+    /// Integer number within the range, e.g. number of digits
+    Integer(RangeInclusive<usize>),
+    /// Integer number from the set of allowed values
+    OneOf(&'static [usize]),
+    /// String value accepted by the predicate, e.g. [`parses`] for option enums
+    String(fn(&str) -> bool),
+}
+
+/// Option value of a function call as seen in const context, see [`FunctionSchema::accepts_option_value`]
+#[derive(Debug, Clone, Copy)]
+pub enum OptionLiteral {
+    /// Value is not a literal
+    Unknown,
+    /// Number literal or string literal parsed as a number,
+    /// `integer` is set for non-negative integers
+    Number {
+        integer: Option<usize>,
+        string: bool,
+    },
+    /// String literal which is not a number
+    String,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum FunctionSchemaError {
+    #[error("expected {expected} positional argument(s), found {actual}")]
+    InvalidArity { expected: String, actual: usize },
+    #[error("unknown option '{0}'")]
+    UnknownOption(String),
+    #[error("invalid value {value} of option '{name}'")]
+    InvalidOptionValue { name: String, value: String },
+}
+
+impl FunctionSchema {
+    /// Accepts any positional arguments and named options
+    pub const ANY: Self = Self {
+        min_args: 0,
+        max_args: None,
+        options: None,
+    };
+
+    /// Accepts `min_args..=max_args` positional arguments and no named options
+    pub const fn new(min_args: usize, max_args: Option<usize>) -> Self {
+        Self {
+            min_args,
+            max_args,
+            options: Some(&[]),
+        }
+    }
+
+    pub const fn with_options(self, options: &'static [OptionSchema]) -> Self {
+        Self {
+            options: Some(options),
+            ..self
+        }
+    }
+
+    pub fn check_arity(&self, count: usize) -> Result<(), FunctionSchemaError> {
+        let valid = count >= self.min_args && self.max_args.is_none_or(|max| count <= max);
+        if valid {
+            Ok(())
+        } else {
+            let expected = match self.max_args {
+                Some(max) if max == self.min_args => max.to_string(),
+                Some(max) => format!("{}..{}", self.min_args, max),
+                None => format!("at least {}", self.min_args),
+            };
+            Err(FunctionSchemaError::InvalidArity {
+                expected,
+                actual: count,
+            })
+        }
+    }
+
+    /// Checks named option, `value` is expected to be `Some` only for literal values
+    pub fn check_option(
+        &self,
+        name: &str,
+        value: Option<&Value>,
+    ) -> Result<(), FunctionSchemaError> {
+        let Some(options) = self.options else {
+            return Ok(());
+        };
+        let option = options
+            .iter()
+            .find(|option| option.name == name)
+            .ok_or_else(|| FunctionSchemaError::UnknownOption(name.to_string()))?;
+
+        let valid = match (&option.kind, value) {
+            (OptionKind::Any, _) | (_, None) => true,
+            (OptionKind::Number, Some(Value::Number { .. })) => true,
+            (OptionKind::Number, Some(Value::String(s))) => Number::from_str(s).is_ok(),
+            (OptionKind::Integer(range), Some(value)) => {
+                integer_value(value).is_some_and(|n| range.contains(&n))
+            }
+            (OptionKind::OneOf(values), Some(value)) => {
+                integer_value(value).is_some_and(|n| values.contains(&n))
+            }
+            (OptionKind::String(accepts), Some(Value::String(s))) => accepts(s),
+            _ => false,
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(FunctionSchemaError::InvalidOptionValue {
+                name: name.to_string(),
+                value: match value {
+                    Some(Value::String(s)) => format!("'{}'", s),
+                    Some(Value::Number { value, .. }) => value.to_string(),
+                    value => format!("{:?}", value),
+                },
+            })
+        }
+    }
+}
+
+/// Const counterparts of the checks, used by `message_bundle` macro to validate
+/// calls of function packs, which are only known to the compiler
+impl FunctionSchema {
+    pub const fn accepts_arity(&self, count: usize) -> bool {
+        count >= self.min_args
+            && match self.max_args {
+                Some(max) => count <= max,
+                None => true,
+            }
+    }
+
+    pub const fn accepts_option(&self, name: &str) -> bool {
+        match self.options {
+            Some(options) => find_option(options, name).is_some(),
+            None => true,
+        }
+    }
+
+    /// Predicates of [`OptionKind::String`] can't be evaluated in const context,
+    /// so any string literal is accepted
+    pub const fn accepts_option_value(&self, name: &str, value: OptionLiteral) -> bool {
+        let Some(options) = self.options else {
+            return true;
+        };
+        let Some(option) = find_option(options, name) else {
+            return false;
+        };
+        match (&option.kind, value) {
+            (OptionKind::Any, _) | (_, OptionLiteral::Unknown) => true,
+            (OptionKind::Number, value) => matches!(value, OptionLiteral::Number { .. }),
+            (
+                OptionKind::Integer(range),
+                OptionLiteral::Number {
+                    integer: Some(n), ..
+                },
+            ) => *range.start() <= n && n <= *range.end(),
+            (
+                OptionKind::OneOf(values),
+                OptionLiteral::Number {
+                    integer: Some(n), ..
+                },
+            ) => {
+                let mut i = 0;
+                while i < values.len() {
+                    if values[i] == n {
+                        return true;
+                    }
+                    i += 1;
+                }
+                false
+            }
+            (OptionKind::String(_), OptionLiteral::String)
+            | (OptionKind::String(_), OptionLiteral::Number { string: true, .. }) => true,
+            _ => false,
+        }
+    }
+}
+
+const fn find_option<'a>(options: &'a [OptionSchema], name: &str) -> Option<&'a OptionSchema> {
+    let mut i = 0;
+    while i < options.len() {
+        if const_str_eq(options[i].name, name) {
+            return Some(&options[i]);
+        }
+        i += 1;
+    }
+    None
+}
+
+pub(crate) const fn const_str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+impl OptionSchema {
+    pub const fn new(name: &'static str, kind: OptionKind) -> Self {
+        Self { name, kind }
+    }
+}
+
+fn integer_value(value: &Value) -> Option<usize> {
+    match value {
+        Value::Number { value, .. } => usize::try_from(value).ok(),
+        Value::String(s) => Number::from_str(s)
+            .ok()
+            .and_then(|n| usize::try_from(n).ok()),
+        _ => None,
+    }
+}
+
+/// Accepts strings parsed by `T::from_str`
+pub fn parses<T: FromStr>(s: &str) -> bool {
+    T::from_str(s).is_ok()
+}
+
+#[cfg(test)]
+mod test {
+    use fluent_static_value::{list::ListType, Value};
+
+    use super::{
+        parses, FunctionSchema, FunctionSchemaError, OptionKind, OptionLiteral, OptionSchema,
+    };
+
+    const SCHEMA: FunctionSchema = FunctionSchema::new(1, Some(2)).with_options(&[
+        OptionSchema::new("type", OptionKind::String(parses::<ListType>)),
+        OptionSchema::new("digits", OptionKind::Number),
+        OptionSchema::new("precision", OptionKind::Integer(1..=21)),
+        OptionSchema::new("increment", OptionKind::OneOf(&[1, 5, 10])),
+    ]);
+
+    #[test]
+    fn test_check_arity() {
+        assert!(SCHEMA.check_arity(1).is_ok());
+        assert!(SCHEMA.check_arity(2).is_ok());
+        assert!(matches!(
+            SCHEMA.check_arity(3),
+            Err(FunctionSchemaError::InvalidArity { actual: 3, .. })
+        ));
+        assert!(SCHEMA.check_arity(0).is_err());
+        assert!(FunctionSchema::ANY.check_arity(42).is_ok());
+    }
+
+    #[test]
+    fn test_check_option() {
+        assert!(SCHEMA
+            .check_option("type", Some(&Value::from("disjunction")))
+            .is_ok());
+        assert!(SCHEMA.check_option("type", None).is_ok());
+        assert!(SCHEMA.check_option("digits", Some(&Value::from(2))).is_ok());
+        assert!(SCHEMA
+            .check_option("digits", Some(&Value::from("2")))
+            .is_ok());
+        assert!(matches!(
+            SCHEMA.check_option("type", Some(&Value::from("both"))),
+            Err(FunctionSchemaError::InvalidOptionValue { .. })
+        ));
+        assert!(matches!(
+            SCHEMA.check_option("digits", Some(&Value::from("two"))),
+            Err(FunctionSchemaError::InvalidOptionValue { .. })
+        ));
+        assert!(matches!(
+            SCHEMA.check_option("style", None),
+            Err(FunctionSchemaError::UnknownOption(name)) if name == "style"
+        ));
+        assert!(FunctionSchema::ANY.check_option("style", None).is_ok());
+    }
+
+    #[test]
+    fn test_check_integer_option() {
+        assert!(SCHEMA
+            .check_option("precision", Some(&Value::from(21)))
+            .is_ok());
+        assert!(SCHEMA
+            .check_option("precision", Some(&Value::from("1")))
+            .is_ok());
+        assert!(SCHEMA.check_option("precision", None).is_ok());
+        for invalid in [
+            Value::from(0),
+            Value::from(22),
+            Value::from(2.5),
+            Value::from("-1"),
+        ] {
+            assert!(matches!(
+                SCHEMA.check_option("precision", Some(&invalid)),
+                Err(FunctionSchemaError::InvalidOptionValue { .. })
+            ));
+        }
+        assert!(SCHEMA
+            .check_option("increment", Some(&Value::from(5)))
+            .is_ok());
+        assert!(matches!(
+            SCHEMA.check_option("increment", Some(&Value::from(3))),
+            Err(FunctionSchemaError::InvalidOptionValue { .. })
+        ));
+    }
+
+    #[test]
+    fn test_const_checks() {
+        const _: () = assert!(
+            SCHEMA.accepts_arity(2)
+                && SCHEMA.accepts_option("digits")
+                && SCHEMA.accepts_option_value(
+                    "precision",
+                    OptionLiteral::Number {
+                        integer: Some(21),
+                        string: false,
+                    },
+                )
+        );
+
+        assert!(!SCHEMA.accepts_arity(3));
+        assert!(!SCHEMA.accepts_option("style"));
+        assert!(FunctionSchema::ANY.accepts_option("style"));
+        assert!(SCHEMA.accepts_option_value("type", OptionLiteral::String));
+        assert!(SCHEMA.accepts_option_value("type", OptionLiteral::Unknown));
+        assert!(!SCHEMA.accepts_option_value(
+            "type",
+            OptionLiteral::Number {
+                integer: Some(1),
+                string: false
+            }
+        ));
+        assert!(!SCHEMA.accepts_option_value("digits", OptionLiteral::String));
+        assert!(!SCHEMA.accepts_option_value(
+            "precision",
+            OptionLiteral::Number {
+                integer: None,
+                string: false
+            }
+        ));
+        assert!(SCHEMA.accepts_option_value(
+            "increment",
+            OptionLiteral::Number {
+                integer: Some(10),
+                string: true
+            }
+        ));
+        assert!(!SCHEMA.accepts_option_value(
+            "increment",
+            OptionLiteral::Number {
+                integer: Some(3),
+                string: false
+            }
+        ));
+    }
+}
@@ -1,20 +1,62 @@
-#[cfg(not(feature = "icu"))]
-use crate::value::Number;
-#[cfg(not(feature = "icu"))]
-use crate::value::Value;
+use std::fmt::Write;
+
+use crate::value::{Number, Value};
+
+#[cfg(any(feature = "icu", feature = "icu4x"))]
+pub use fluent_static_formatter::{format, prewarm};
 
 #[cfg(feature = "icu")]
-pub use fluent_static_formatter::format;
+pub use fluent_static_formatter::{
+    cache::CacheMetrics, list_formatter_cache_metrics, number_formatter_cache_metrics,
+    set_list_formatter_cache_capacity, set_number_formatter_cache_capacity,
+};
+
+#[cfg(feature = "cldr")]
+pub use fluent_static_formatter::cldr;
+
+pub use fluent_static_function::formatter::{wrap, ValueFormatter, WrappedFormatter};
+
+pub type FormatterFn = fluent_static_function::context::FormatFn;
+
+#[cfg(not(any(feature = "icu", feature = "icu4x")))]
+pub use self::format_plain as format;
+
+/// Formatter of the enabled backend, [`IcuFormatter`] with `icu` or `icu4x` feature
+#[cfg(any(feature = "icu", feature = "icu4x"))]
+pub type DefaultFormatter = IcuFormatter;
+#[cfg(not(any(feature = "icu", feature = "icu4x")))]
+pub type DefaultFormatter = PlainFormatter;
+
+/// [`format_plain`] as [`ValueFormatter`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PlainFormatter;
 
-pub type FormatterFn =
-    fn(&str, &fluent_static_value::Value, &mut dyn std::fmt::Write) -> std::fmt::Result;
+impl ValueFormatter for PlainFormatter {
+    fn format(&self, language_id: &str, value: &Value, out: &mut dyn Write) -> std::fmt::Result {
+        format_plain(language_id, value, &mut { out })
+    }
+}
+
+/// Locale specific formatting by ICU4C (`icu` feature) or ICU4X (`icu4x` feature)
+#[cfg(any(feature = "icu", feature = "icu4x"))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IcuFormatter;
+
+#[cfg(any(feature = "icu", feature = "icu4x"))]
+impl ValueFormatter for IcuFormatter {
+    fn format(&self, language_id: &str, value: &Value, out: &mut dyn Write) -> std::fmt::Result {
+        fluent_static_formatter::format(language_id, value, &mut { out })
+    }
+}
+
+/// Nothing to prepare for the locale independent formatter
+#[cfg(not(any(feature = "icu", feature = "icu4x")))]
+pub fn prewarm(_locale: &str, _formats: &[crate::value::NumberFormat]) -> std::fmt::Result {
+    Ok(())
+}
 
-#[cfg(not(feature = "icu"))]
-pub fn format(
-    _locale: &str,
-    value: &fluent_static_value::Value,
-    out: &mut impl std::fmt::Write,
-) -> std::fmt::Result {
+/// Locale independent formatter, used by default if neither `icu` nor `icu4x` feature is enabled
+pub fn format_plain(locale: &str, value: &Value, out: &mut impl Write) -> std::fmt::Result {
     match value {
         Value::String(s) => out.write_str(s),
         Value::Number { value, .. } => match value {
@@ -23,7 +65,35 @@ pub fn format(
             Number::I128(n) => write!(out, "{}", n),
             Number::U128(n) => write!(out, "{}", n),
             Number::F64(n) => write!(out, "{}", n),
+            Number::Decimal(n) => write!(out, "{}", n),
         },
+        Value::Bool(b) => write!(out, "{}", b),
+        // locale independent list pattern, enable `icu` or `icu4x` feature for locale specific lists
+        Value::List(items) | Value::FormattedList { items, .. } => {
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.write_str(", ")?;
+                }
+                format_plain(locale, item, out)?;
+            }
+            Ok(())
+        }
+        // CLDR root pattern, i.e. `+3 d`, enable `icu` or `icu4x` feature for locale specific patterns
+        Value::RelativeTime { value, format } => {
+            out.write_char(if value.as_f64().is_sign_negative() {
+                '-'
+            } else {
+                '+'
+            })?;
+            write!(
+                out,
+                "{} {}",
+                value.abs().as_string(),
+                format.unit.root_symbol()
+            )
+        }
+        Value::Custom(custom) => out.write_str(&custom.as_string(locale)),
+        Value::Markup(fragment) => out.write_str(fragment),
         Value::Empty => Ok(()),
         Value::Error => write!(out, "#error#"),
     }
@@ -0,0 +1,53 @@
+//! Escaping of interpolated values of bundles generated with HTML output,
+//! see `html` option of [`message_bundle`](crate::message_bundle)
+
+use std::fmt::Write;
+
+/// Writes `s` replacing HTML special characters with character references
+pub fn escape<W: Write + ?Sized>(s: &str, out: &mut W) -> std::fmt::Result {
+    let mut start = 0;
+    for (idx, c) in s.char_indices() {
+        let entity = match c {
+            '&' => "&amp;",
+            '<' => "&lt;",
+            '>' => "&gt;",
+            '"' => "&quot;",
+            '\'' => "&#39;",
+            _ => continue,
+        };
+        out.write_str(&s[start..idx])?;
+        out.write_str(entity)?;
+        start = idx + 1;
+    }
+    out.write_str(&s[start..])
+}
+
+/// Writer escaping everything written through it
+pub struct Escape<'a, W: ?Sized>(pub &'a mut W);
+
+impl<W: Write + ?Sized> Write for Escape<'_, W> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        escape(s, self.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fmt::Write;
+
+    use super::{escape, Escape};
+
+    #[test]
+    fn test_escape() {
+        let mut out = String::new();
+        escape("<a href=\"/\">Fish & 'chips'</a>", &mut out).unwrap();
+        assert_eq!(
+            "&lt;a href=&quot;/&quot;&gt;Fish &amp; &#39;chips&#39;&lt;/a&gt;",
+            out
+        );
+
+        let mut out = String::new();
+        write!(Escape(&mut out), "1 > ü").unwrap();
+        assert_eq!("1 &gt; ü", out);
+    }
+}
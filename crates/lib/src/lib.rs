@@ -3,13 +3,21 @@ pub use once_cell;
 pub use unic_langid;
 
 pub use fluent_static_function as function;
-pub use fluent_static_macros::message_bundle;
+pub use fluent_static_macros::{message_bundle, Localize};
 pub use fluent_static_value as value;
 
 mod message;
 
-pub use message::Message;
+pub use message::{Message, MessageBuffer, MessageWrite};
 pub mod formatter;
+pub mod html;
+pub mod rich;
+
+pub mod registry;
+pub use registry::BundleRegistry;
+
+mod metadata;
+pub use metadata::{AttributeInfo, MessageInfo, MessageSource, VariableInfo, VariableKind};
 
 pub mod support;
 
@@ -22,6 +30,11 @@ macro_rules! include_source {
 
 pub trait LanguageAware {
     fn language_id(&self) -> &str;
+
+    /// Base direction of the language, detected from the language ID by default
+    fn text_direction(&self) -> value::bidi::TextDirection {
+        value::bidi::language_direction(self.language_id())
+    }
 }
 
 pub trait MessageBundle: LanguageAware + Default {
@@ -31,3 +44,30 @@ pub trait MessageBundle: LanguageAware + Default {
     fn default_language_id() -> &'static str;
     fn supported_language_ids() -> &'static [&'static str];
 }
+
+/// Conversion of a value (e.g. error enum) into a message of bundle `B`,
+/// usually implemented with `#[derive(Localize)]`
+pub trait Localize<B> {
+    fn localize(&self, bundle: &B) -> Message;
+}
+
+/// Object-safe counterpart of [`MessageBundle`] that allows to look up messages by ID at runtime
+/// and to keep bundles of different types behind `dyn DynMessageBundle`
+pub trait DynMessageBundle: LanguageAware {
+    fn supported_languages(&self) -> &'static [&'static str];
+
+    /// Formats message or message attribute (`message-id.attribute-id`) with given named arguments,
+    /// returns `None` if bundle has no such message
+    fn format_message(
+        &self,
+        message_id: &str,
+        args: &[(&str, value::Value<'_>)],
+    ) -> Option<Message>;
+
+    /// Returns attribute IDs of the message or `None` if bundle has no such message
+    fn message_attributes(&self, message_id: &str) -> Option<&'static [&'static str]>;
+
+    fn messages(&self) -> &'static [MessageInfo] {
+        &[]
+    }
+}
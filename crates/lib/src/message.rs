@@ -1,11 +1,51 @@
 use std::{borrow::Cow, fmt::Display, ops::Deref};
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Message(pub(crate) Cow<'static, str>);
+pub struct Message {
+    pub(crate) value: Cow<'static, str>,
+    /// Byte ranges of pre-rendered markup fragments, see [`Value::Markup`](crate::value::Value::Markup)
+    pub(crate) fragments: Vec<(usize, usize)>,
+}
 
 impl Message {
     pub const fn new(value: Cow<'static, str>) -> Self {
-        Self(value)
+        Self {
+            value,
+            fragments: Vec::new(),
+        }
+    }
+
+    /// Markup fragments of the message, in order
+    pub fn fragments(&self) -> impl Iterator<Item = &str> {
+        self.fragments
+            .iter()
+            .map(|&(start, end)| &self.value[start..end])
+    }
+
+    pub fn has_fragments(&self) -> bool {
+        !self.fragments.is_empty()
+    }
+
+    /// Whether the whole message is markup, e.g. message of a bundle with HTML output
+    pub fn is_markup(&self) -> bool {
+        self.fragments == [(0, self.value.len())]
+    }
+
+    /// Splits message into text and markup fragment (`true`) segments
+    pub fn segments(&self) -> impl Iterator<Item = (&str, bool)> {
+        let mut segments = Vec::with_capacity(self.fragments.len() * 2 + 1);
+        let mut start = 0;
+        for &(fragment_start, fragment_end) in self.fragments.iter() {
+            if fragment_start > start {
+                segments.push((&self.value[start..fragment_start], false));
+            }
+            segments.push((&self.value[fragment_start..fragment_end], true));
+            start = fragment_end;
+        }
+        if start < self.value.len() {
+            segments.push((&self.value[start..], false));
+        }
+        segments.into_iter()
     }
 }
 
@@ -17,7 +57,7 @@ impl From<String> for Message {
 
 impl Display for Message {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(&self.0)
+        f.write_str(&self.value)
     }
 }
 
@@ -25,30 +65,145 @@ impl Deref for Message {
     type Target = str;
 
     fn deref(&self) -> &Self::Target {
-        &*self.0
+        &self.value
     }
 }
 
 impl PartialEq<str> for Message {
     fn eq(&self, other: &str) -> bool {
-        self.0 == other
+        self.value == other
     }
 }
 
 impl PartialEq<Message> for str {
     fn eq(&self, other: &Message) -> bool {
-        self == other.0
+        self == other.value
     }
 }
 
 impl PartialEq<&str> for Message {
     fn eq(&self, other: &&str) -> bool {
-        self.0 == *other
+        self.value == *other
     }
 }
 
 impl PartialEq<Message> for &str {
     fn eq(&self, other: &Message) -> bool {
-        *self == other.0
+        *self == other.value
+    }
+}
+
+/// Output of generated message functions
+pub trait MessageWrite: std::fmt::Write {
+    /// Writes pre-rendered markup fragment
+    fn write_fragment(&mut self, fragment: &str) -> std::fmt::Result {
+        self.write_str(fragment)
+    }
+
+    /// Starts rich-text element, e.g. `link` of `<link>here</link>`, writes the tag by default
+    fn start_element(&mut self, name: &str) -> std::fmt::Result {
+        write!(self, "<{}>", name)
+    }
+
+    fn end_element(&mut self, name: &str) -> std::fmt::Result {
+        write!(self, "</{}>", name)
+    }
+}
+
+impl MessageWrite for String {}
+
+/// [`MessageWrite`] keeping track of markup fragments
+#[derive(Debug, Default)]
+pub struct MessageBuffer {
+    value: String,
+    fragments: Vec<(usize, usize)>,
+    html: bool,
+}
+
+impl MessageBuffer {
+    /// Buffer of HTML output, the whole message is a single markup fragment
+    pub fn html() -> Self {
+        Self {
+            html: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn into_message(self) -> Message {
+        let fragments = if self.html && !self.value.is_empty() {
+            vec![(0, self.value.len())]
+        } else {
+            self.fragments
+        };
+        Message {
+            value: Cow::Owned(self.value),
+            fragments,
+        }
+    }
+}
+
+impl std::fmt::Write for MessageBuffer {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.value.write_str(s)
+    }
+}
+
+impl MessageWrite for MessageBuffer {
+    fn write_fragment(&mut self, fragment: &str) -> std::fmt::Result {
+        let start = self.value.len();
+        self.value.push_str(fragment);
+        if !self.html && !fragment.is_empty() {
+            self.fragments.push((start, self.value.len()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fmt::Write;
+
+    use super::{MessageBuffer, MessageWrite};
+
+    #[test]
+    fn test_segments() {
+        let mut buffer = MessageBuffer::default();
+        buffer.write_str("See ").unwrap();
+        buffer.write_fragment("<a href=\"/\">home</a>").unwrap();
+        buffer.write_fragment("").unwrap();
+        buffer.write_str(" & ").unwrap();
+        buffer.write_fragment("<br>").unwrap();
+        let message = buffer.into_message();
+
+        assert_eq!("See <a href=\"/\">home</a> & <br>", message);
+        assert!(message.has_fragments());
+        assert_eq!(
+            vec!["<a href=\"/\">home</a>", "<br>"],
+            message.fragments().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![
+                ("See ", false),
+                ("<a href=\"/\">home</a>", true),
+                (" & ", false),
+                ("<br>", true)
+            ],
+            message.segments().collect::<Vec<_>>()
+        );
+        assert!(!message.is_markup());
+    }
+
+    #[test]
+    fn test_html_buffer() {
+        let mut buffer = MessageBuffer::html();
+        buffer.write_str("<b>Hello</b>, ").unwrap();
+        buffer.write_fragment("<i>World</i>").unwrap();
+        let message = buffer.into_message();
+
+        assert!(message.is_markup());
+        assert_eq!(
+            vec![("<b>Hello</b>, <i>World</i>", true)],
+            message.segments().collect::<Vec<_>>()
+        );
     }
 }
@@ -0,0 +1,60 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VariableKind {
+    /// Variable is only used as a placeable, any value is accepted
+    Any,
+    /// Variable is used as a selector with string keys
+    String,
+    /// Variable is used as a selector with numeric or plural category keys, or as `NUMBER` argument
+    Number,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VariableInfo {
+    pub name: &'static str,
+    pub kind: VariableKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttributeInfo {
+    pub id: &'static str,
+    pub variables: &'static [VariableInfo],
+}
+
+/// Location of the message definition in a Fluent resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageSource {
+    pub language_id: &'static str,
+    pub path: &'static str,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageInfo {
+    pub id: &'static str,
+    pub variables: &'static [VariableInfo],
+    pub attributes: &'static [AttributeInfo],
+    /// Message definitions, one for each language the message is available in
+    pub sources: &'static [MessageSource],
+}
+
+impl MessageInfo {
+    pub fn attribute(&self, attribute_id: &str) -> Option<&'static AttributeInfo> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.id == attribute_id)
+    }
+
+    pub fn source(&self, language_id: &str) -> Option<&'static MessageSource> {
+        self.sources
+            .iter()
+            .find(|source| source.language_id == language_id)
+    }
+
+    pub fn is_available(&self, language_id: &str) -> bool {
+        self.source(language_id).is_some()
+    }
+
+    pub fn languages(&self) -> impl Iterator<Item = &'static str> {
+        self.sources.iter().map(|source| source.language_id)
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::BTreeMap;
+
+use crate::{value::Value, DynMessageBundle, Message};
+
+pub const NAMESPACE_SEPARATOR: char = '/';
+
+/// Collection of message bundles of different types, each registered under its own namespace.
+///
+/// Messages are addressed as `namespace/message-id` or `namespace/message-id.attribute-id`
+#[derive(Default)]
+pub struct BundleRegistry {
+    bundles: BTreeMap<String, Box<dyn DynMessageBundle + Send + Sync>>,
+}
+
+impl BundleRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(
+        &mut self,
+        namespace: &str,
+        bundle: impl DynMessageBundle + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.bundles.insert(namespace.to_string(), Box::new(bundle));
+        self
+    }
+
+    pub fn bundle(&self, namespace: &str) -> Option<&(dyn DynMessageBundle + Send + Sync)> {
+        self.bundles.get(namespace).map(|bundle| bundle.as_ref())
+    }
+
+    pub fn namespaces(&self) -> impl Iterator<Item = &str> {
+        self.bundles.keys().map(String::as_str)
+    }
+
+    pub fn format_message(&self, message_id: &str, args: &[(&str, Value<'_>)]) -> Option<Message> {
+        self.resolve(message_id)
+            .and_then(|(bundle, message_id)| bundle.format_message(message_id, args))
+    }
+
+    pub fn message_attributes(&self, message_id: &str) -> Option<&'static [&'static str]> {
+        self.resolve(message_id)
+            .and_then(|(bundle, message_id)| bundle.message_attributes(message_id))
+    }
+
+    fn resolve<'a, 'b>(
+        &'a self,
+        message_id: &'b str,
+    ) -> Option<(&'a (dyn DynMessageBundle + Send + Sync), &'b str)> {
+        let (namespace, message_id) = message_id.split_once(NAMESPACE_SEPARATOR)?;
+        self.bundle(namespace).map(|bundle| (bundle, message_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{value::Value, DynMessageBundle, LanguageAware, Message};
+
+    use super::BundleRegistry;
+
+    struct Greetings;
+
+    impl LanguageAware for Greetings {
+        fn language_id(&self) -> &str {
+            "en"
+        }
+    }
+
+    impl DynMessageBundle for Greetings {
+        fn supported_languages(&self) -> &'static [&'static str] {
+            &["en"]
+        }
+
+        fn format_message(&self, message_id: &str, args: &[(&str, Value<'_>)]) -> Option<Message> {
+            match message_id {
+                "hello" => Some(Message::from("hello".to_string())),
+                "hello-name" => {
+                    let name = args
+                        .iter()
+                        .find(|(name, _)| *name == "name")
+                        .and_then(|(_, value)| match value {
+                            Value::String(s) => Some(s.to_string()),
+                            _ => None,
+                        })
+                        .unwrap_or_default();
+                    Some(Message::from(format!("hello {}", name)))
+                }
+                _ => None,
+            }
+        }
+
+        fn message_attributes(&self, message_id: &str) -> Option<&'static [&'static str]> {
+            match message_id {
+                "hello" | "hello-name" => Some(&[]),
+                _ => None,
+            }
+        }
+    }
+
+    struct Errors;
+
+    impl LanguageAware for Errors {
+        fn language_id(&self) -> &str {
+            "fr"
+        }
+    }
+
+    impl DynMessageBundle for Errors {
+        fn supported_languages(&self) -> &'static [&'static str] {
+            &["en", "fr"]
+        }
+
+        fn format_message(&self, message_id: &str, _: &[(&str, Value<'_>)]) -> Option<Message> {
+            match message_id {
+                "not-found" => Some(Message::from("introuvable".to_string())),
+                "not-found.title" => Some(Message::from("erreur".to_string())),
+                _ => None,
+            }
+        }
+
+        fn message_attributes(&self, message_id: &str) -> Option<&'static [&'static str]> {
+            match message_id {
+                "not-found" => Some(&["title"]),
+                _ => None,
+            }
+        }
+    }
+
+    fn registry() -> BundleRegistry {
+        let mut registry = BundleRegistry::new();
+        registry
+            .register("greetings", Greetings)
+            .register("errors", Errors);
+        registry
+    }
+
+    #[test]
+    fn test_format_message() {
+        let registry = registry();
+
+        assert_eq!(
+            Some(Message::from("hello".to_string())),
+            registry.format_message("greetings/hello", &[])
+        );
+        assert_eq!(
+            Some(Message::from("hello foo".to_string())),
+            registry.format_message("greetings/hello-name", &[("name", Value::from("foo"))])
+        );
+        assert_eq!(
+            Some(Message::from("erreur".to_string())),
+            registry.format_message("errors/not-found.title", &[])
+        );
+    }
+
+    #[test]
+    fn test_unknown_message() {
+        let registry = registry();
+
+        assert_eq!(None, registry.format_message("hello", &[]));
+        assert_eq!(None, registry.format_message("errors/hello", &[]));
+        assert_eq!(None, registry.format_message("warnings/hello", &[]));
+    }
+
+    #[test]
+    fn test_message_attributes() {
+        let registry = registry();
+
+        assert_eq!(
+            Some(&["title"][..]),
+            registry.message_attributes("errors/not-found")
+        );
+        assert_eq!(
+            Some(&[][..]),
+            registry.message_attributes("greetings/hello")
+        );
+        assert_eq!(None, registry.message_attributes("greetings/not-found"));
+    }
+
+    #[test]
+    fn test_bundle_lookup() {
+        let registry = registry();
+
+        let bundle = registry.bundle("errors").unwrap();
+        assert_eq!("fr", bundle.language_id());
+        assert_eq!(&["en", "fr"], bundle.supported_languages());
+        assert_eq!(
+            vec!["errors", "greetings"],
+            registry.namespaces().collect::<Vec<_>>()
+        );
+    }
+}
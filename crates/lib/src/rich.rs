@@ -0,0 +1,165 @@
+//! Rich-text messages: text elements like `Click <link>here</link>` are returned as
+//! a tree of [`Segment`]s by generated `*_segments` methods, so elements can be
+//! rendered by UI frameworks instead of being written as tags
+
+use std::fmt::Write;
+
+use crate::MessageWrite;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Segment {
+    Text(String),
+    /// Pre-rendered markup fragment, see [`Value::Markup`](crate::value::Value::Markup)
+    Markup(String),
+    Element {
+        name: String,
+        children: Vec<Segment>,
+    },
+}
+
+impl Segment {
+    /// Text of the segment without element tags
+    pub fn text(&self) -> String {
+        let mut result = String::new();
+        self.write_text(&mut result);
+        result
+    }
+
+    fn write_text(&self, out: &mut String) {
+        match self {
+            Segment::Text(text) | Segment::Markup(text) => out.push_str(text),
+            Segment::Element { children, .. } => {
+                children.iter().for_each(|child| child.write_text(out))
+            }
+        }
+    }
+}
+
+/// [`MessageWrite`] building message [`Segment`]s
+#[derive(Debug, Default)]
+pub struct SegmentBuffer {
+    segments: Vec<Segment>,
+    open: Vec<(String, Vec<Segment>)>,
+}
+
+impl SegmentBuffer {
+    pub fn into_segments(mut self) -> Vec<Segment> {
+        while self.pop_element() {}
+        self.segments
+    }
+
+    fn pop_element(&mut self) -> bool {
+        if let Some((name, parent)) = self.open.pop() {
+            let children = std::mem::replace(&mut self.segments, parent);
+            self.segments.push(Segment::Element { name, children });
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl Write for SegmentBuffer {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        if !s.is_empty() {
+            match self.segments.last_mut() {
+                Some(Segment::Text(text)) => text.push_str(s),
+                _ => self.segments.push(Segment::Text(s.to_string())),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for SegmentBuffer {
+    fn write_fragment(&mut self, fragment: &str) -> std::fmt::Result {
+        self.segments.push(Segment::Markup(fragment.to_string()));
+        Ok(())
+    }
+
+    fn start_element(&mut self, name: &str) -> std::fmt::Result {
+        let parent = std::mem::take(&mut self.segments);
+        self.open.push((name.to_string(), parent));
+        Ok(())
+    }
+
+    fn end_element(&mut self, _name: &str) -> std::fmt::Result {
+        self.pop_element();
+        Ok(())
+    }
+}
+
+/// Renders segments to `out`, elements are rendered by the `element` callback
+/// which receives element name and rendered content of its children.
+/// Text is written as is, escaping is up to the caller
+pub fn render(
+    segments: &[Segment],
+    out: &mut dyn Write,
+    element: &mut dyn FnMut(&str, &str, &mut dyn Write) -> std::fmt::Result,
+) -> std::fmt::Result {
+    for segment in segments {
+        match segment {
+            Segment::Text(text) | Segment::Markup(text) => out.write_str(text)?,
+            Segment::Element { name, children } => {
+                let mut content = String::new();
+                render(children, &mut content, element)?;
+                element(name, &content, out)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::fmt::Write;
+
+    use super::{render, Segment, SegmentBuffer};
+    use crate::MessageWrite;
+
+    fn text(s: &str) -> Segment {
+        Segment::Text(s.to_string())
+    }
+
+    #[test]
+    fn test_segment_buffer() {
+        let mut buffer = SegmentBuffer::default();
+        buffer.write_str("Click ").unwrap();
+        buffer.start_element("link").unwrap();
+        buffer.write_str("here ").unwrap();
+        buffer.start_element("b").unwrap();
+        buffer.write_fragment("<i>now</i>").unwrap();
+        buffer.end_element("b").unwrap();
+        buffer.end_element("link").unwrap();
+        buffer.write_str(" to ").unwrap();
+        buffer.write_str("continue").unwrap();
+        let segments = buffer.into_segments();
+
+        assert_eq!(
+            vec![
+                text("Click "),
+                Segment::Element {
+                    name: "link".to_string(),
+                    children: vec![
+                        text("here "),
+                        Segment::Element {
+                            name: "b".to_string(),
+                            children: vec![Segment::Markup("<i>now</i>".to_string())]
+                        }
+                    ]
+                },
+                text(" to continue")
+            ],
+            segments
+        );
+        assert_eq!("here <i>now</i>", segments[1].text());
+
+        let mut out = String::new();
+        render(&segments, &mut out, &mut |name, content, out| match name {
+            "link" => write!(out, "[{}](/next)", content),
+            _ => write!(out, "**{}**", content),
+        })
+        .unwrap();
+        assert_eq!("Click [here **<i>now</i>**](/next) to continue", out);
+    }
+}
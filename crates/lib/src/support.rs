@@ -3,8 +3,56 @@ pub mod maud {
     use crate::Message;
 
     impl maud::Render for Message {
+        /// Escapes message text, markup fragments are rendered as is
         fn render_to(&self, buffer: &mut String) {
-            str::render_to(&self.0, buffer);
+            for (segment, is_fragment) in self.segments() {
+                if is_fragment {
+                    buffer.push_str(segment);
+                } else {
+                    str::render_to(segment, buffer);
+                }
+            }
+        }
+    }
+
+    impl From<Message> for maud::Markup {
+        fn from(message: Message) -> Self {
+            maud::Render::render(&message)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use std::fmt::Write;
+
+        use maud::html;
+
+        use crate::{MessageBuffer, MessageWrite};
+
+        #[test]
+        fn test_render_fragments() {
+            let mut buffer = MessageBuffer::default();
+            buffer.write_str("Fish & ").unwrap();
+            buffer.write_fragment("<em>chips</em>").unwrap();
+            buffer.write_str(" <3").unwrap();
+            let message = buffer.into_message();
+
+            assert_eq!(
+                "<p>Fish &amp; <em>chips</em> &lt;3</p>",
+                html! { p { (message) } }.into_string()
+            );
+        }
+
+        #[test]
+        fn test_render_html_message() {
+            let mut buffer = MessageBuffer::html();
+            buffer.write_str("<b>Fish</b> &amp; chips").unwrap();
+            let markup: maud::Markup = buffer.into_message().into();
+
+            assert_eq!(
+                "<p><b>Fish</b> &amp; chips</p>",
+                html! { p { (markup) } }.into_string()
+            );
         }
     }
 }
@@ -294,8 +342,15 @@ pub mod topcoat {
     pub use super::http::{RequestLanguageConfig, RequestLanguageConfigBuilder};
 
     impl NodeViewParts for Message {
+        /// Escapes message text, markup fragments are rendered as is
         fn into_view_parts(self, _: &Cx, parts: &mut PartsWriter<'_>) {
-            parts.push_str(self.0);
+            for (segment, is_fragment) in self.segments() {
+                if is_fragment {
+                    parts.push_str_unescaped(segment.to_owned());
+                } else {
+                    parts.push_str(segment.to_owned());
+                }
+            }
         }
     }
 
@@ -346,8 +401,12 @@ pub mod topcoat {
         use topcoat::{
             context::{ContextMap, CxBuilder},
             cookie::CookieJarCell,
+            view::{HtmlContext, View, ViewParts},
         };
 
+        use crate::{MessageBuffer, MessageWrite};
+        use std::fmt::Write;
+
         #[derive(Debug, Clone, PartialEq, Eq)]
         struct LanguageSpec(String);
 
@@ -470,5 +529,35 @@ pub mod topcoat {
                 LanguageSpec::default()
             );
         }
+
+        fn render(message: crate::Message) -> String {
+            let mut parts = ViewParts::new();
+            message.into_view_parts(
+                &Cx::default(),
+                &mut PartsWriter::new(&mut parts, HtmlContext::Text),
+            );
+            View::new(parts).render(&Cx::default())
+        }
+
+        #[test]
+        fn test_render_fragments() {
+            let mut buffer = MessageBuffer::default();
+            buffer.write_str("Fish & ").unwrap();
+            buffer.write_fragment("<em>chips</em>").unwrap();
+            buffer.write_str(" <3").unwrap();
+
+            assert_eq!(
+                "Fish &amp; <em>chips</em> &lt;3",
+                render(buffer.into_message())
+            );
+        }
+
+        #[test]
+        fn test_render_html_message() {
+            let mut buffer = MessageBuffer::html();
+            buffer.write_str("<b>Fish</b> &amp; chips").unwrap();
+
+            assert_eq!("<b>Fish</b> &amp; chips", render(buffer.into_message()));
+        }
     }
 }
@@ -1,7 +1,10 @@
 use std::{env, fs, path::PathBuf};
 
-use fluent_static_codegen::{function::FunctionRegistry, MessageBundleBuilder};
-use fluent_static_function::FluentFunctionDescriptor;
+use fluent_static_codegen::{
+    function::{FunctionPack, FunctionRegistry, FunctionSchema},
+    MessageBundleBuilder,
+};
+use fluent_static_function::{FluentFunctionDescriptor, PackFunction};
 
 fn resources_base_dir() -> PathBuf {
     PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").expect("'CARGO_MANIFEST_DIR' not set"))
@@ -106,10 +109,24 @@ fn test_selector_messages() {
         .write_to_file(output_dir().join("selectors_pluralrules.rs"))
         .expect("Error writing generated source");
 
+    let other_only = MessageBundleBuilder::new("OtherOnly")
+        .set_default_language("ja")
+        .unwrap()
+        .set_resources_dir(resources_base_dir())
+        .add_resource("ja", "selectors/pluralrules-ja.ftl")
+        .unwrap()
+        .build()
+        .unwrap();
+
+    other_only
+        .write_to_file(output_dir().join("selectors_other_only.rs"))
+        .expect("Error writing generated source");
+
     let test_cases = trybuild::TestCases::new();
     test_cases.pass("tests/sources/selectors/strings.rs");
     test_cases.pass("tests/sources/selectors/numbers.rs");
     test_cases.pass("tests/sources/selectors/pluralrules.rs");
+    test_cases.pass("tests/sources/selectors/other_only.rs");
 }
 
 #[test]
@@ -141,6 +158,33 @@ impl FluentFunctionDescriptor for CustomFn {
     }
 }
 
+const TEXT_FUNCTIONS: FunctionPack = FunctionPack::new(
+    "self::text",
+    &[PackFunction::new(
+        "SHOUT",
+        "shout",
+        FunctionSchema::new(1, Some(1)),
+    )],
+);
+
+// shadowed by previously registered `TEXT_FUNCTIONS`
+const LOUD_FUNCTIONS: FunctionPack = FunctionPack::new(
+    "self::loud",
+    &[PackFunction::new("SHOUT", "shout", FunctionSchema::ANY)],
+);
+
+struct CustomContextFn(&'static str);
+
+impl FluentFunctionDescriptor for CustomContextFn {
+    fn type_name(&self) -> &'static str {
+        self.0
+    }
+
+    fn requires_context(&self) -> bool {
+        true
+    }
+}
+
 #[test]
 fn test_functions() {
     let bundle = MessageBundleBuilder::new("BuiltinFns")
@@ -159,7 +203,13 @@ fn test_functions() {
         .expect("Error writing generated source");
 
     let mut registry = FunctionRegistry::default();
-    registry.register("UPPERCASE", CustomFn("fluent_uppercase"));
+    registry.register(
+        "UPPERCASE",
+        CustomFn("fluent_uppercase").with_schema(FunctionSchema::new(1, Some(1))),
+    );
+    registry.register("LANGUAGE_TAG", CustomContextFn("fluent_language_tag"));
+    registry.register_pack(&TEXT_FUNCTIONS);
+    registry.register_pack(&LOUD_FUNCTIONS);
 
     let bundle = MessageBundleBuilder::new("CustomFns")
         .set_default_language("en")
@@ -181,3 +231,165 @@ fn test_functions() {
     test_cases.pass("tests/sources/functions/builtins.rs");
     test_cases.pass("tests/sources/functions/custom.rs");
 }
+
+#[test]
+#[should_panic(expected = "can't be registered by type name")]
+fn test_register_builtin_function() {
+    FunctionRegistry::default().register("NUM", fluent_static::function::builtins::number);
+}
+
+#[test]
+fn test_invalid_function_calls() {
+    let resources = [
+        ("functions/invalid-option-en.ftl", "price", "NUMBER"),
+        ("functions/invalid-option-value-en.ftl", "names", "LIST"),
+        ("functions/invalid-arity-en.ftl", "total", "NUMBER"),
+        ("functions/invalid-digits-en.ftl", "price", "NUMBER"),
+        ("functions/invalid-increment-en.ftl", "price", "NUMBER"),
+    ];
+    for (resource, message_id, function) in resources {
+        let result = MessageBundleBuilder::new("InvalidFns")
+            .set_resources_dir(resources_base_dir())
+            .add_resource("en", resource)
+            .map(|_| ());
+        match result {
+            Err(fluent_static_codegen::Error::InvalidFunctionCall {
+                entry_id,
+                function_id,
+                ..
+            }) => {
+                assert_eq!(message_id, entry_id);
+                assert_eq!(function, function_id);
+            }
+            other => panic!("Expected invalid function call error, got {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn test_rich_text_messages() {
+    let rich = MessageBundleBuilder::new("Rich")
+        .set_default_language("en")
+        .unwrap()
+        .set_resources_dir(resources_base_dir())
+        .add_resource("en", "rich/rich-en.ftl")
+        .unwrap()
+        .add_resource("it", "rich/rich-it.ftl")
+        .unwrap()
+        .build()
+        .unwrap();
+
+    rich.write_to_file(output_dir().join("rich.rs"))
+        .expect("Error writing generated source");
+
+    let test_cases = trybuild::TestCases::new();
+    test_cases.pass("tests/sources/rich/rich.rs");
+
+    let result = MessageBundleBuilder::new("Mismatched")
+        .set_default_language("en")
+        .unwrap()
+        .set_resources_dir(resources_base_dir())
+        .add_resource("en", "rich/mismatched-en.ftl")
+        .unwrap()
+        .add_resource("it", "rich/mismatched-it.ftl")
+        .unwrap()
+        .build();
+    match result {
+        Err(fluent_static_codegen::Error::MismatchedElements {
+            message_id,
+            language,
+            expected,
+            actual,
+        }) => {
+            assert_eq!("proceed", message_id);
+            assert_eq!("it", language);
+            assert_eq!("link", expected);
+            assert_eq!("a", actual);
+        }
+        other => panic!(
+            "Expected mismatched elements error, got {:?}",
+            other.map(|_| ())
+        ),
+    }
+}
+
+#[test]
+fn test_method_name_collision() {
+    let result = MessageBundleBuilder::new("Reserved")
+        .set_resources_dir(resources_base_dir())
+        .add_resource("en", "reserved-en.ftl")
+        .unwrap()
+        .build();
+    match result {
+        Err(fluent_static_codegen::Error::MethodNameCollision {
+            message_id, method, ..
+        }) => {
+            assert_eq!("format", message_id);
+            assert_eq!("format", method);
+        }
+        other => panic!(
+            "Expected method name collision error, got {:?}",
+            other.map(|_| ())
+        ),
+    }
+}
+
+#[test]
+fn test_unisolated_messages() {
+    let result = MessageBundleBuilder::new("Isolation")
+        .set_resources_dir(resources_base_dir())
+        .set_unisolated_messages(["log-line", "missing"])
+        .unwrap()
+        .add_resource("en", "isolation-en.ftl")
+        .unwrap()
+        .build();
+    match result {
+        Err(fluent_static_codegen::Error::UndefinedUnisolatedMessage(message_id)) => {
+            assert_eq!("missing", message_id);
+        }
+        other => panic!(
+            "Expected undefined unisolated message error, got {:?}",
+            other.map(|_| ())
+        ),
+    }
+
+    let result = MessageBundleBuilder::new("Isolation")
+        .set_resources_dir(resources_base_dir())
+        .add_resource("en", "isolation-en.ftl")
+        .unwrap()
+        .set_unisolated_messages(["log-line"])
+        .map(|_| ());
+    assert!(matches!(
+        result,
+        Err(fluent_static_codegen::Error::UnisolatedMessagesAfterResources)
+    ));
+}
+
+#[cfg(feature = "cldr")]
+#[test]
+fn test_embedded_locale_data() {
+    let bundle = MessageBundleBuilder::new("Numbers")
+        .set_default_language("en")
+        .unwrap()
+        .set_embed_locale_data(true)
+        .set_resources_dir(resources_base_dir())
+        .add_resource("en", "numbers/numbers-en.ftl")
+        .unwrap()
+        .add_resource("de", "numbers/numbers-de.ftl")
+        .unwrap()
+        .build()
+        .unwrap();
+
+    // only currencies used in the resources are embedded
+    let code = bundle.tokens().to_string();
+    assert!(code.contains("\"EUR\""));
+    assert!(code.contains("\"JPY\""));
+    assert!(!code.contains("\"USD\""));
+
+    bundle
+        .write_to_file(output_dir().join("numbers.rs"))
+        .expect("Error writing generated source");
+
+    let test_cases = trybuild::TestCases::new();
+    test_cases.pass("tests/sources/numbers.rs");
+}
@@ -8,5 +8,15 @@ fn test_derive_fluent_bundle() {
     let test_cases = trybuild::TestCases::new();
     test_cases.pass("tests/sources/messages.rs");
     test_cases.pass("tests/sources/formatter.rs");
+    test_cases.pass("tests/sources/context.rs");
+    test_cases.pass("tests/sources/packs.rs");
+    test_cases.compile_fail("tests/sources/packs-invalid-call.rs");
+    test_cases.pass("tests/sources/isolation.rs");
+    #[cfg(feature = "maud")]
+    test_cases.pass("tests/sources/markup.rs");
+    #[cfg(feature = "maud")]
+    test_cases.pass("tests/sources/html.rs");
     test_cases.compile_fail("tests/sources/messages-missing-resource.rs");
+    test_cases.pass("tests/sources/localize.rs");
+    test_cases.compile_fail("tests/sources/localize-unknown-variable.rs");
 }
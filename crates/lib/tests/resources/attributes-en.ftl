@@ -3,3 +3,6 @@ hello = hello
 hello-name = hello { $name }
  .no-args = en without args
  .with-args = { $arg2 } { $arg1 }
+login-input =
+ .placeholder = email@example.com
+ .aria-label = Login input for { $user }
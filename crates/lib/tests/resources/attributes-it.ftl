@@ -3,3 +3,6 @@ hello = ciao
 hello-name = ciao { $name }
  .no-args = it without args
  .with-args = { $arg2 } { $arg1 }
+login-input =
+ .placeholder = email@esempio.it
+ .aria-label = Campo di accesso per { $user }
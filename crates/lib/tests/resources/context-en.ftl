@@ -0,0 +1,2 @@
+greeting = Hello, { SALUTE($name) }!
+items = { ITEMS($count) }
@@ -0,0 +1,2 @@
+greeting = Bonjour, { SALUTE($name) } !
+items = { ITEMS($count) }
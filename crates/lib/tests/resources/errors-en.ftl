@@ -0,0 +1,4 @@
+not-found = File { $path } not found
+permission-denied = Access denied
+io-error = I/O error: { $reason }
+    .hint = Check { $userName } permissions
@@ -0,0 +1,4 @@
+not-found = Fichier { $path } introuvable
+permission-denied = Accès refusé
+io-error = Erreur d'E/S : { $reason }
+    .hint = Vérifiez les droits de { $userName }
@@ -17,3 +17,14 @@ number-number = en inception { NUMBER(NUMBER($mynum)) }
 
 -term-arg = en { $arg }
 term-arg-msg = en msg { -term-arg(arg:NUMBER($mynum)) }
+
+plural-points = { NUMBER($points, minimumFractionDigits: 2) ->
+  [one] en one point
+  *[other] en other points
+}
+
+list-names = en { LIST($names) }
+list-options = en { LIST($first, $second, $third, type: "disjunction", style: "short") }
+
+relative-days = en { RELATIVE_TIME($days, unit: "day") }
+relative-auto = en { RELATIVE_TIME($days, unit: "day", numeric: "auto") }
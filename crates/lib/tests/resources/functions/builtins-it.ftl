@@ -17,3 +17,14 @@ number-number = it inception { NUMBER(NUMBER($mynum)) }
 
 -term-arg = it { $arg }
 term-arg-msg = it msg { -term-arg(arg:NUMBER($mynum)) }
+
+plural-points = { NUMBER($points, minimumFractionDigits: 2) ->
+  [one] it one point
+  *[other] it other points
+}
+
+list-names = it { LIST($names) }
+list-options = it { LIST($first, $second, $third, type: "disjunction", style: "short") }
+
+relative-days = it { RELATIVE_TIME($days, unit: "day") }
+relative-auto = it { RELATIVE_TIME($days, unit: "day", numeric: "auto") }
@@ -1 +1,3 @@
 uppercase-arg = en { UPPERCASE($param)} { NUMBER($num) }
+language-tag = en { LANGUAGE_TAG() }
+shout = en { SHOUT($text) }
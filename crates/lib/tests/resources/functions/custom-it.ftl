@@ -1 +1,3 @@
 uppercase-arg = it { NUMBER($num) } { UPPERCASE($param)}
+language-tag = it { LANGUAGE_TAG() }
+shout = it { SHOUT($text) }
@@ -0,0 +1 @@
+total = { NUMBER($amount, $total) }
@@ -0,0 +1 @@
+price = { NUMBER($amount, maximumFractionDigits: 101) }
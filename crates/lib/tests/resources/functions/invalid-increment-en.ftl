@@ -0,0 +1 @@
+price = { NUMBER($amount, roundingIncrement: 3) }
@@ -0,0 +1 @@
+price = { NUMBER($amount, style: "currency", curency: "EUR") }
@@ -0,0 +1 @@
+names = { LIST($names, type: "both") }
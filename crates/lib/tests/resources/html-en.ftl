@@ -0,0 +1,2 @@
+welcome = Welcome, <b>{ $name }</b>!
+terms = Read the { LINK("/terms", "Terms & Conditions") }.
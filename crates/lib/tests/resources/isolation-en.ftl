@@ -0,0 +1,3 @@
+greeting = Hello, { $name }!
+log-line = User { $name } signed in
+quote = ציטוט: { $text }
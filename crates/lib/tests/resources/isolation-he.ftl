@@ -0,0 +1,3 @@
+greeting = שלום, { $name }!
+log-line = המשתמש { $name } התחבר
+quote = ציטוט: { $text }
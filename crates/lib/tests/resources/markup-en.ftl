@@ -0,0 +1,2 @@
+terms = Read the { LINK("/terms", "Terms & Conditions") } before <signing>.
+greeting = Hello, { $name }!
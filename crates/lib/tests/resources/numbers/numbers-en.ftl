@@ -0,0 +1,9 @@
+total = Total: { NUMBER($total) }
+price = Price: { NUMBER($amount, style: "currency", currency: "EUR") }
+price-name = { NUMBER($amount, style: "currency", currency: "JPY", currencyDisplay: "name") }
+speed = { NUMBER($speed, style: "unit", unit: "kilometer-per-hour", unitDisplay: "long") }
+share = { NUMBER($share, style: "percent") }
+names = { LIST($names) }
+posted = Posted { RELATIVE_TIME($days, unit: "day", numeric: "auto") }
+followers = { NUMBER($count, notation: "compact") } followers
+change = { NUMBER($change, signDisplay: "exceptZero", maximumFractionDigits: 1, roundingMode: "halfExpand") }
@@ -0,0 +1,6 @@
+slug = { SLUG($title) }
+snake-slug = { SLUG($title, separator: "_") }
+language-slug = { LANGUAGE_SLUG($title) }
+title = { TITLE($text) }
+price = { NUMBER($amount) }
+shout = { SHOUT($text) }
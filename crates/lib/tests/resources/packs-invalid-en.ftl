@@ -0,0 +1 @@
+slug = { SLUG($title, $separator) }
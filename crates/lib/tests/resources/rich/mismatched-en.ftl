@@ -0,0 +1 @@
+proceed = Click <link>here</link> to continue.
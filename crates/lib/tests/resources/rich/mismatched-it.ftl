@@ -0,0 +1 @@
+proceed = Fai clic <a>qui</a> per continuare.
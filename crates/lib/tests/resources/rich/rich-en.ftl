@@ -0,0 +1,6 @@
+proceed = Click <link>here</link> to continue.
+inbox = Welcome, <b>{ $name }</b>! See <link>your <em>{ $count ->
+        [one] message
+       *[other] messages
+    }</em></link>.
+plain = 1 <2 and 3 > 2, <br> <i>unclosed
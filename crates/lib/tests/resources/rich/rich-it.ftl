@@ -0,0 +1,6 @@
+proceed = Fai clic <link>qui</link> per continuare.
+inbox = Benvenuto, <b>{ $name }</b>! Vedi <link><em>{ $count ->
+        [one] il tuo messaggio
+       *[other] i tuoi messaggi
+    }</em></link>.
+plain = 1 <2 e 3 > 2, <br> <i>non chiuso
@@ -4,5 +4,6 @@ test-numbers =
    [0] zero
    [1.0] one.o
    [42] the answer
+   [19.99] price
    *[idk] other
  }
@@ -0,0 +1,3 @@
+new-photos = { $photoCount ->
+   *[other] 新しい写真が{ $photoCount }枚あります
+}
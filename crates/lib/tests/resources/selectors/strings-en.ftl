@@ -4,3 +4,12 @@ sel-foo =
    [bar]  EN Bar { $bar }
   *[baz]  EN Baz { $baz } Baz { $bar } 
  }
+sel-bool = { $flag ->
+   [true] EN Yes
+  *[false] EN No
+ }
+sel-plan = { $plan ->
+   [premium] EN Premium { $plan }
+  *[other] EN Other { $plan }
+ }
+names = EN { $names }
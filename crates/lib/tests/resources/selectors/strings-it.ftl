@@ -3,3 +3,12 @@ sel-foo = { $sl ->
   *[bar] IT Bar { $bar }
   [baz] IT Baz { $baz } Baz { $bar } 
  }
+sel-bool = { $flag ->
+   [true] IT Si
+  *[false] IT No
+ }
+sel-plan = { $plan ->
+   [premium] IT Premium { $plan }
+  *[other] IT Altro { $plan }
+ }
+names = IT { $names }
@@ -2,7 +2,7 @@ mod fluent {
     fluent_static::include_source!("attributes.rs");
 }
 
-use fluent_static::MessageBundle;
+use fluent_static::{value::Value, BundleRegistry, DynMessageBundle, MessageBundle};
 
 fn main() {
     let mut bundle = fluent::Attributes::get("it").unwrap();
@@ -16,4 +16,68 @@ fn main() {
         "second first",
         bundle.hello_name_with_args("second", "first")
     );
+
+    assert_eq!(
+        Some(bundle.hello_name("foo")),
+        bundle.format_message("hello-name", &[("name", Value::from("foo"))])
+    );
+    assert_eq!(
+        "first second",
+        bundle
+            .format_message(
+                "hello-name.with-args",
+                &[("arg1", Value::from("second")), ("arg2", Value::from("first"))]
+            )
+            .unwrap()
+    );
+    assert_eq!(None, bundle.format_message("hello.no-args", &[]));
+    assert_eq!(Some(&["attr"][..]), bundle.message_attributes("hello"));
+    assert_eq!(
+        Some(&["no-args", "with-args"][..]),
+        bundle.message_attributes("hello-name")
+    );
+    assert_eq!(&["en", "it"], bundle.supported_languages());
+
+    let ids: Vec<&str> = fluent::Attributes::MESSAGES
+        .iter()
+        .map(|info| info.id)
+        .collect();
+    assert_eq!(vec!["hello", "hello-name", "login-input"], ids);
+    let info = bundle.messages()[1];
+    assert_eq!("name", info.variables[0].name);
+    assert_eq!(Some(3), info.source("it").map(|source| source.line));
+    assert!(info.attribute("no-args").unwrap().variables.is_empty());
+    assert_eq!(
+        vec!["arg2", "arg1"],
+        info.attribute("with-args")
+            .unwrap()
+            .variables
+            .iter()
+            .map(|var| var.name)
+            .collect::<Vec<_>>()
+    );
+
+    assert_eq!(
+        fluent::AttributesHelloEntry {
+            value: bundle.hello(),
+            attr: bundle.hello_attr(),
+        },
+        bundle.hello_entry()
+    );
+
+    let entry = bundle.hello_name_entry("foo", "first", "second");
+    assert_eq!("ciao foo", entry.value);
+    assert_eq!("it without args", entry.no_args);
+    assert_eq!("first second", entry.with_args);
+
+    let entry = bundle.login_input_entry("foo");
+    assert_eq!("email@esempio.it", entry.placeholder);
+    assert_eq!("Campo di accesso per foo", entry.aria_label);
+
+    let mut registry = BundleRegistry::new();
+    registry.register("attrs", bundle);
+    assert_eq!(
+        "ciao with attributes",
+        registry.format_message("attrs/hello.attr", &[]).unwrap()
+    );
 }
@@ -2,11 +2,25 @@ mod fluent {
     fluent_static::include_source!("basic.rs");
 }
 
-use fluent_static::MessageBundle;
+use fluent_static::{value::Value, MessageBundle};
+
+fn greeting(name: Option<&str>) -> fluent::BasicMessageId<'_> {
+    match name {
+        Some(name) => fluent::BasicMessageId::HelloName {
+            name: Value::from(name),
+        },
+        None => fluent::BasicMessageId::Hello,
+    }
+}
 
 fn main() {
     let bundle = fluent::Basic::get("en").unwrap();
 
     assert_eq!("hello", bundle.hello());
     assert_eq!("hello \u{2068}foo\u{2069}", bundle.hello_name("foo"));
+
+    assert_eq!("hello", greeting(None).id());
+    assert_eq!("hello-name", greeting(Some("foo")).id());
+    assert_eq!("hello", bundle.format(greeting(None)));
+    assert_eq!(bundle.hello_name("foo"), bundle.format(greeting(Some("foo"))));
 }
@@ -0,0 +1,83 @@
+use fluent_static::function::FunctionContext;
+use fluent_static::intl_pluralrules::PluralCategory;
+use fluent_static::value::Value;
+use fluent_static::{message_bundle, MessageBundle};
+
+#[message_bundle(
+    resources = [
+        ("tests/resources/context-en.ftl", "en"),
+        ("tests/resources/context-fr.ftl", "fr")
+    ],
+    context_functions = (
+        "SALUTE" = salute,
+        "ITEMS" = count_items,
+    ),
+    default_language = "en",
+)
+]
+struct Messages;
+
+struct Salutations {
+    en: &'static str,
+    fr: &'static str,
+}
+
+impl Messages {
+    fn salute<'a, 'b>(
+        context: &'a FunctionContext<'a>,
+        positional_args: &'a [Value<'a>],
+        _: &'a [(&'a str, Value<'a>)],
+    ) -> Value<'b> {
+        let salutation = context
+            .extension::<Salutations>()
+            .map(|s| if context.language_id() == "fr" { s.fr } else { s.en })
+            .unwrap_or_default();
+        match positional_args.first().map(|name| context.format(name)) {
+            Some(Ok(name)) => Value::from(format!("{}{}", salutation, name)),
+            _ => Value::Error,
+        }
+    }
+
+    fn count_items<'a, 'b>(
+        context: &'a FunctionContext<'a>,
+        positional_args: &'a [Value<'a>],
+        _: &'a [(&'a str, Value<'a>)],
+    ) -> Value<'b> {
+        match positional_args.first() {
+            Some(Value::Number { value, .. }) => {
+                let category = context
+                    .plural_rules()
+                    .select(value.plural_operands())
+                    .unwrap_or(PluralCategory::OTHER);
+                let suffix = if category == PluralCategory::ONE { "" } else { "s" };
+                Value::from(format!("{} item{}", value, suffix))
+            }
+            _ => Value::Error,
+        }
+    }
+}
+
+fn main() {
+    let mut messages = Messages::default();
+    messages.set_use_isolating(false);
+
+    assert_eq!("Hello, Alice!", messages.greeting("Alice"));
+    assert_eq!("1 item", messages.items(1));
+    assert_eq!("2 items", messages.items(2));
+
+    messages.extensions_mut().insert(Salutations {
+        en: "dear ",
+        fr: "chère ",
+    });
+    assert_eq!("Hello, dear Alice!", messages.greeting("Alice"));
+
+    let mut messages = Messages::get("fr").unwrap();
+    messages.set_use_isolating(false);
+    messages.extensions_mut().insert(Salutations {
+        en: "dear ",
+        fr: "chère ",
+    });
+    assert_eq!("Bonjour, chère Alice !", messages.greeting("Alice"));
+    // 1 and 0 are singular in French
+    assert_eq!("0 item", messages.items(0));
+}
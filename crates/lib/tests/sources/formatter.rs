@@ -1,5 +1,8 @@
+use std::sync::{Arc, Mutex};
+
+use fluent_static::formatter::{wrap, PlainFormatter, ValueFormatter};
 use fluent_static::value::Value;
-use fluent_static::message_bundle;
+use fluent_static::{message_bundle, MessageBundle};
 
 #[message_bundle(
     resources = [
@@ -40,6 +43,22 @@ impl Messages {
     }
 }
 
+/// Stateful formatter recording languages it was initialized for
+#[derive(Default)]
+struct Recording {
+    languages: Mutex<Vec<String>>,
+}
+
+impl ValueFormatter for Recording {
+    fn init(&self, language_id: &str) {
+        self.languages.lock().unwrap().push(language_id.to_string());
+    }
+
+    fn format(&self, language_id: &str, value: &Value, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        PlainFormatter.format(language_id, value, out)
+    }
+}
+
 fn main() {
     let mut messages = Messages::default();
     messages.set_use_isolating(false);
@@ -47,4 +66,25 @@ fn main() {
     assert_eq!("en |foo|", messages.test_param("foo"));
     assert_eq!("en |oof|", messages.test_fn("foo"));
     assert_eq!("en |I64(42)|", messages.number_fn(42));
+
+    let recording = Arc::new(Recording::default());
+    let distance = wrap(recording.clone(), |language_id, value, out, inner| match value {
+        Value::Number { .. } => {
+            inner.format(language_id, value, out)?;
+            out.write_str(" km")
+        }
+        _ => inner.format(language_id, value, out),
+    });
+
+    let mut messages = Messages::get("fr").unwrap();
+    messages.set_use_isolating(false);
+    messages.set_value_formatter(Some(Arc::new(distance)));
+    assert_eq!(vec!["fr"], *recording.languages.lock().unwrap());
+
+    assert_eq!("fr foo", messages.test_param("foo"));
+    assert_eq!("fr oof", messages.test_fn("foo"));
+    assert_eq!("fr 42 km", messages.number_fn(42));
+
+    messages.set_value_formatter(None);
+    assert_eq!("fr |I64(42)|", messages.number_fn(42));
 }
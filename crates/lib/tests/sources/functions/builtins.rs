@@ -10,7 +10,12 @@ fn main() {
 
     assert_eq!("en 10", bundle.simple_number(10));
     assert_eq!("en 11", bundle.simple_number("11"));
-    assert_eq!("en 4242", bundle.number_const());
+    // locale aware formatters group digits
+    if cfg!(any(feature = "icu", feature = "icu4x")) {
+        assert_eq!("en 4,242", bundle.number_const());
+    } else {
+        assert_eq!("en 4242", bundle.number_const());
+    }
     assert_eq!("en 42", bundle.number_with_named_arg(42));
     assert_eq!("en 100", bundle.number_msg_ref());
     assert_eq!("en term 111", bundle.number_term_ref());
@@ -18,4 +23,40 @@ fn main() {
     assert_eq!("en other", bundle.selector_number(0.01));
     assert_eq!("en inception 0.01", bundle.number_number(0.01));
     assert_eq!("en msg en 0.01", bundle.term_arg_msg(0.01));
+    // plural category is selected for the formatted number, i.e. "1.00"
+    assert_eq!("en other points", bundle.plural_points(1));
+    assert_eq!("en other points", bundle.plural_points(1.0));
+    assert_eq!("en other points", bundle.plural_points(u128::MAX));
+
+    if cfg!(any(feature = "icu", feature = "icu4x")) {
+        assert_eq!("en A, B, and C", bundle.list_names(vec!["A", "B", "C"]));
+        assert_eq!("en A, B, or 1", bundle.list_options("A", "B", 1));
+    } else {
+        assert_eq!("en A, B, C", bundle.list_names(vec!["A", "B", "C"]));
+        assert_eq!("en A, B, 1", bundle.list_options("A", "B", 1));
+    }
+    assert_eq!("en A", bundle.list_names(vec!["A"]));
+
+    if cfg!(any(feature = "icu", feature = "icu4x")) {
+        assert_eq!("en in 3 days", bundle.relative_days(3));
+        assert_eq!("en 1 day ago", bundle.relative_days(-1));
+        assert_eq!("en yesterday", bundle.relative_auto(-1));
+        assert_eq!("en in 5 days", bundle.relative_auto(5));
+    } else {
+        // CLDR root patterns
+        assert_eq!("en +3 d", bundle.relative_days(3));
+        assert_eq!("en -1 d", bundle.relative_auto(-1));
+    }
+
+    let mut bundle = fluent::BuiltinFns::get("it").unwrap();
+    bundle.set_use_isolating(false);
+
+    if cfg!(any(feature = "icu", feature = "icu4x")) {
+        assert_eq!("it A, B e C", bundle.list_names(vec!["A", "B", "C"]));
+        assert_eq!("it A, B o 1", bundle.list_options("A", "B", 1));
+    }
+    if cfg!(any(feature = "icu", feature = "icu4x")) {
+        assert_eq!("it tra 3 giorni", bundle.relative_days(3));
+        assert_eq!("it ieri", bundle.relative_auto(-1));
+    }
 }
@@ -1,4 +1,5 @@
 mod fluent {
+    use fluent_static::function::FunctionContext;
     use fluent_static::value::Value;
 
     pub fn fluent_uppercase<'a, 'b>(
@@ -15,6 +16,39 @@ mod fluent {
         }
     }
 
+    pub fn fluent_language_tag<'a, 'b>(
+        context: &'a FunctionContext<'a>,
+        _positional_args: &'a [Value<'a>],
+        _named_args: &'a [(&'a str, Value<'a>)],
+    ) -> Value<'b> {
+        Value::from(context.language_id().to_uppercase())
+    }
+
+    pub mod text {
+        use fluent_static::value::Value;
+
+        pub fn shout<'a, 'b>(
+            positional_args: &'a [Value<'a>],
+            _named_args: &'a [(&'a str, Value<'a>)],
+        ) -> Value<'b> {
+            match positional_args.first() {
+                Some(Value::String(s)) => Value::from(format!("{}!", s.to_uppercase())),
+                _ => Value::Error,
+            }
+        }
+    }
+
+    pub mod loud {
+        use fluent_static::value::Value;
+
+        pub fn shout<'a, 'b>(
+            _positional_args: &'a [Value<'a>],
+            _named_args: &'a [(&'a str, Value<'a>)],
+        ) -> Value<'b> {
+            Value::Error
+        }
+    }
+
     fluent_static::include_source!("custom_fns.rs");
 }
 
@@ -25,6 +59,8 @@ fn main() {
     bundle.set_use_isolating(false);
 
     assert_eq!("en FOO 10", bundle.uppercase_arg("foo", 10));
+    assert_eq!("en EN", bundle.language_tag());
+    assert_eq!("en HEY!", bundle.shout("hey"));
 
     let bundle = fluent::CustomFns::get("it").unwrap();
     assert_eq!(
@@ -0,0 +1,44 @@
+use fluent_static::message_bundle;
+use fluent_static::value::Value;
+use maud::html;
+
+fn link<'a, 'b>(positional_args: &'a [Value<'a>], _: &'a [(&'a str, Value<'a>)]) -> Value<'b> {
+    match positional_args {
+        [Value::String(href), Value::String(text)] => Value::markup(format!(
+            "<a href=\"{}\">{}</a>",
+            html! { (href) }.into_string(),
+            html! { (text) }.into_string()
+        )),
+        _ => Value::Error,
+    }
+}
+
+#[message_bundle(
+    resources = [("tests/resources/html-en.ftl", "en")],
+    functions = ("LINK" = crate::link),
+    default_language = "en",
+    html = true,
+)]
+struct Messages;
+
+fn main() {
+    let mut messages = Messages::default();
+    messages.set_use_isolating(false);
+
+    let welcome = messages.welcome("<script>'Bob' & co</script>");
+    assert!(welcome.is_markup());
+    assert_eq!(
+        "Welcome, <b>&lt;script&gt;&#39;Bob&#39; &amp; co&lt;/script&gt;</b>!",
+        welcome
+    );
+    assert_eq!(
+        "<p>Welcome, <b>&lt;script&gt;&#39;Bob&#39; &amp; co&lt;/script&gt;</b>!</p>",
+        html! { p { (welcome) } }.into_string()
+    );
+
+    let terms: maud::Markup = messages.terms().into();
+    assert_eq!(
+        "Read the <a href=\"/terms\">Terms &amp; Conditions</a>.",
+        terms.into_string()
+    );
+}
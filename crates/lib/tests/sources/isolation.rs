@@ -0,0 +1,41 @@
+use fluent_static::{
+    message_bundle,
+    value::bidi::{Isolation, TextDirection},
+    LanguageAware, MessageBundle,
+};
+
+#[message_bundle(
+    resources = [
+        ("tests/resources/isolation-en.ftl", "en"),
+        ("tests/resources/isolation-he.ftl", "he"),
+    ],
+    default_language = "en",
+    isolation = "auto",
+    unisolated_messages = ["log-line"],
+)]
+struct Messages;
+
+fn main() {
+    let mut messages = Messages::default();
+    assert_eq!(TextDirection::Ltr, messages.text_direction());
+
+    // same direction values are not isolated
+    assert_eq!("Hello, Bob!", messages.greeting("Bob"));
+    assert_eq!("Hello, 42!", messages.greeting(42));
+    assert_eq!("Hello, \u{2068}דוד\u{2069}!", messages.greeting("דוד"));
+    assert_eq!("User דוד signed in", messages.log_line("דוד"));
+
+    // direction of the surrounding text takes precedence over the language direction
+    assert_eq!("ציטוט: דוד", messages.quote("דוד"));
+    assert_eq!("ציטוט: \u{2068}Bob\u{2069}", messages.quote("Bob"));
+
+    messages.set_isolation(Isolation::Always);
+    assert_eq!("Hello, \u{2068}Bob\u{2069}!", messages.greeting("Bob"));
+    assert_eq!("User Bob signed in", messages.log_line("Bob"));
+
+    let messages = Messages::get("he").unwrap();
+    assert_eq!(TextDirection::Rtl, messages.text_direction());
+    assert_eq!("שלום, דוד!", messages.greeting("דוד"));
+    assert_eq!("שלום, \u{2068}Bob\u{2069}!", messages.greeting("Bob"));
+    assert_eq!("המשתמש Bob התחבר", messages.log_line("Bob"));
+}
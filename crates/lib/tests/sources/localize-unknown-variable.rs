@@ -0,0 +1,18 @@
+use fluent_static::{message_bundle, Localize};
+
+#[message_bundle(
+    resources = [("tests/resources/errors-en.ftl", "en")],
+    default_language = "en",
+)]
+struct Errors;
+
+#[derive(Localize)]
+#[fluent(bundle = Errors)]
+enum AppError {
+    #[fluent(message = "not-found")]
+    NotFound { file: String },
+    #[fluent(message = "no-such-message")]
+    Unknown,
+}
+
+fn main() {}
@@ -0,0 +1,68 @@
+use fluent_static::{message_bundle, Localize, MessageBundle};
+
+#[message_bundle(
+    resources = [
+        ("tests/resources/errors-en.ftl", "en"),
+        ("tests/resources/errors-fr.ftl", "fr"),
+    ],
+    default_language = "en",
+)]
+struct Errors;
+
+#[derive(Debug, Localize)]
+#[fluent(bundle = Errors)]
+enum AppError {
+    NotFound {
+        path: String,
+    },
+    PermissionDenied,
+    #[fluent(message = "io-error")]
+    Io(#[fluent(arg = "reason")] &'static str, #[fluent(skip)] i32),
+    #[fluent(message = "io-error.hint")]
+    Access {
+        #[fluent(arg = "userName")]
+        user: String,
+        #[fluent(skip)]
+        uid: u32,
+    },
+}
+
+#[derive(Localize)]
+enum GenericError<T: Clone>
+where
+    fluent_static::value::Value<'static>: From<T>,
+{
+    #[fluent(bundle = Errors, message = "not-found")]
+    NotFound { path: T },
+}
+
+fn main() {
+    let mut errors = Errors::default();
+    errors.set_use_isolating(false);
+
+    assert_eq!(
+        "File foo.txt not found",
+        AppError::NotFound {
+            path: "foo.txt".to_string()
+        }
+        .localize(&errors)
+    );
+    assert_eq!("Access denied", AppError::PermissionDenied.localize(&errors));
+    assert_eq!("I/O error: broken pipe", AppError::Io("broken pipe", 32).localize(&errors));
+    assert_eq!(
+        "Check root permissions",
+        AppError::Access {
+            user: "root".to_string(),
+            uid: 0
+        }
+        .localize(&errors)
+    );
+    assert_eq!(
+        "File bar.txt not found",
+        GenericError::NotFound { path: "bar.txt" }.localize(&errors)
+    );
+
+    let mut errors = Errors::get("fr").unwrap();
+    errors.set_use_isolating(false);
+    assert_eq!("Accès refusé", AppError::PermissionDenied.localize(&errors));
+}
@@ -0,0 +1,42 @@
+use fluent_static::message_bundle;
+use fluent_static::value::Value;
+use maud::html;
+
+fn link<'a, 'b>(positional_args: &'a [Value<'a>], _: &'a [(&'a str, Value<'a>)]) -> Value<'b> {
+    match positional_args {
+        [Value::String(href), Value::String(text)] => Value::markup(format!(
+            "<a href=\"{}\">{}</a>",
+            html! { (href) }.into_string(),
+            html! { (text) }.into_string()
+        )),
+        _ => Value::Error,
+    }
+}
+
+#[message_bundle(
+    resources = [("tests/resources/markup-en.ftl", "en")],
+    functions = ("LINK" = crate::link),
+    default_language = "en",
+)]
+struct Messages;
+
+fn main() {
+    let messages = Messages::default();
+
+    let terms = messages.terms();
+    assert_eq!(
+        "Read the <a href=\"/terms\">Terms &amp; Conditions</a> before <signing>.",
+        terms
+    );
+    assert_eq!(
+        "<p>Read the <a href=\"/terms\">Terms &amp; Conditions</a> before &lt;signing&gt;.</p>",
+        html! { p { (terms) } }.into_string()
+    );
+
+    let greeting = messages.greeting("<b>Bob</b>");
+    assert!(!greeting.has_fragments());
+    assert_eq!(
+        "<p>Hello, \u{2068}&lt;b&gt;Bob&lt;/b&gt;\u{2069}!</p>",
+        html! { p { (greeting) } }.into_string()
+    );
+}
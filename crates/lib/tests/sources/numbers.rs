@@ -0,0 +1,36 @@
+mod fluent {
+    fluent_static::include_source!("numbers.rs");
+}
+
+use fluent_static::MessageBundle;
+
+fn main() {
+    fluent::Numbers::prewarm_formatters().unwrap();
+
+    let mut en = fluent::Numbers::get("en").unwrap();
+    en.set_use_isolating(false);
+
+    assert_eq!("Total: 1,234.5", en.total(1234.5));
+    assert_eq!("Price: €1,234.50", en.price(1234.5));
+    assert_eq!("1,235 Japanese yen", en.price_name(1234.6));
+    assert_eq!("1 kilometer/hour", en.speed(1));
+    assert_eq!("25%", en.share(0.25));
+    assert_eq!("A, B, and C", en.names(vec!["A", "B", "C"]));
+    assert_eq!("Posted yesterday", en.posted(-1));
+    assert_eq!("Posted in 2 days", en.posted(2));
+    assert_eq!("1.2K followers", en.followers(1234));
+    assert_eq!("+1.3", en.change(1.25));
+    assert_eq!("0", en.change(0.01));
+
+    let mut de = fluent::Numbers::get("de").unwrap();
+    de.set_use_isolating(false);
+
+    assert_eq!("Gesamt: 1.234,5", de.total(1234.5));
+    assert_eq!("Preis: 1.234,50\u{a0}€", de.price(1234.5));
+    assert_eq!("25\u{a0}%", de.share(0.25));
+    assert_eq!("A, B und C", de.names(vec!["A", "B", "C"]));
+    assert_eq!("Gepostet gestern", de.posted(-1));
+    assert_eq!("Gepostet vor 3 Tagen", de.posted(-3));
+    assert_eq!("3,4\u{a0}Mio. Follower", de.followers(3_400_000));
+    assert_eq!("-1,3", de.change(-1.25));
+}
@@ -0,0 +1,24 @@
+use fluent_static::message_bundle;
+
+mod l10n_fns {
+    use fluent_static::function::{FunctionPack, FunctionSchema, PackFunction};
+    use fluent_static::value::Value;
+
+    pub const FUNCTIONS: FunctionPack = FunctionPack::new(
+        "crate::l10n_fns",
+        &[PackFunction::new("SLUG", "slug", FunctionSchema::new(1, Some(1)))],
+    );
+
+    pub fn slug<'a, 'b>(_: &'a [Value<'a>], _: &'a [(&'a str, Value<'a>)]) -> Value<'b> {
+        Value::Empty
+    }
+}
+
+#[message_bundle(
+    resources = [("tests/resources/packs-invalid-en.ftl", "en")],
+    functions = [l10n_fns::*],
+    default_language = "en",
+)]
+struct Messages;
+
+fn main() {}
@@ -0,0 +1,125 @@
+use fluent_static::message_bundle;
+
+mod l10n_fns {
+    use fluent_static::function::{
+        schema::{OptionKind, OptionSchema},
+        FunctionContext, FunctionPack, FunctionSchema, PackFunction,
+    };
+    use fluent_static::value::Value;
+
+    pub const FUNCTIONS: FunctionPack = FunctionPack::new(
+        "crate::l10n_fns",
+        &[
+            PackFunction::new(
+                "SLUG",
+                "slug",
+                FunctionSchema::new(1, Some(1)).with_options(&[OptionSchema::new(
+                    "separator",
+                    OptionKind::String(|s| s.chars().count() == 1),
+                )]),
+            ),
+            PackFunction::new("LANGUAGE_SLUG", "language_slug", FunctionSchema::new(1, Some(1))),
+        ],
+    );
+
+    pub fn slug<'a, 'b>(
+        positional_args: &'a [Value<'a>],
+        named_args: &'a [(&'a str, Value<'a>)],
+    ) -> Value<'b> {
+        let separator = match named_args.iter().find(|(name, _)| *name == "separator") {
+            Some((_, Value::String(separator))) => separator.as_ref(),
+            _ => "-",
+        };
+        match positional_args.first() {
+            Some(Value::String(s)) => Value::from(s.to_lowercase().replace(' ', separator)),
+            _ => Value::Error,
+        }
+    }
+
+    pub fn language_slug<'a, 'b>(
+        context: &'a FunctionContext<'a>,
+        positional_args: &'a [Value<'a>],
+        named_args: &'a [(&'a str, Value<'a>)],
+    ) -> Value<'b> {
+        match slug(positional_args, named_args) {
+            Value::String(s) => Value::from(format!("{}/{}", context.language_id(), s)),
+            value => value,
+        }
+    }
+
+    pub mod text {
+        use fluent_static::value::Value;
+
+        pub fn title<'a, 'b>(
+            positional_args: &'a [Value<'a>],
+            _: &'a [(&'a str, Value<'a>)],
+        ) -> Value<'b> {
+            match positional_args.first() {
+                Some(Value::String(s)) => {
+                    let mut chars = s.chars();
+                    Value::from(
+                        chars
+                            .next()
+                            .map(|c| c.to_uppercase().chain(chars).collect::<String>())
+                            .unwrap_or_default(),
+                    )
+                }
+                _ => Value::Error,
+            }
+        }
+
+        pub fn uppercase<'a, 'b>(
+            positional_args: &'a [Value<'a>],
+            _: &'a [(&'a str, Value<'a>)],
+        ) -> Value<'b> {
+            match positional_args.first() {
+                Some(Value::String(s)) => Value::from(s.to_uppercase()),
+                _ => Value::Error,
+            }
+        }
+    }
+}
+
+mod extra_fns {
+    use fluent_static::function::{FunctionPack, FunctionSchema, PackFunction};
+    use fluent_static::value::Value;
+
+    // functions of other modules are exported by snake case names of Fluent functions
+    pub use crate::l10n_fns::text::uppercase as shout;
+
+    pub const FUNCTIONS: FunctionPack = FunctionPack::new(
+        "crate::extra_fns",
+        &[
+            PackFunction::new("SLUG", "slug", FunctionSchema::ANY),
+            PackFunction::new("SHOUT", "shout", FunctionSchema::new(1, Some(1))),
+        ],
+    );
+
+    // shadowed by `l10n_fns` pack
+    pub fn slug<'a, 'b>(_: &'a [Value<'a>], _: &'a [(&'a str, Value<'a>)]) -> Value<'b> {
+        Value::Error
+    }
+}
+
+#[message_bundle(
+    resources = [("tests/resources/packs-en.ftl", "en")],
+    functions = [
+        l10n_fns::*,
+        extra_fns::*,
+        "TITLE" = crate::l10n_fns::text::title,
+    ],
+    default_language = "en",
+)]
+struct Messages;
+
+fn main() {
+    let mut messages = Messages::default();
+    messages.set_use_isolating(false);
+
+    assert_eq!("hello-world", messages.slug("Hello World"));
+    assert_eq!("hello_world", messages.snake_slug("Hello World"));
+    assert_eq!("en/hello-world", messages.language_slug("Hello World"));
+    assert_eq!("Hello", messages.title("hello"));
+    assert_eq!("HELLO", messages.shout("hello"));
+    assert_eq!("42", messages.price(42));
+}
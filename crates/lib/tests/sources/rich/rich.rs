@@ -0,0 +1,77 @@
+mod fluent {
+    fluent_static::include_source!("rich.rs");
+}
+
+use fluent_static::{
+    rich::{render, Segment},
+    MessageBundle,
+};
+
+fn text(s: &str) -> Segment {
+    Segment::Text(s.to_string())
+}
+
+fn element(name: &str, children: Vec<Segment>) -> Segment {
+    Segment::Element {
+        name: name.to_string(),
+        children,
+    }
+}
+
+fn main() {
+    let mut bundle = fluent::Rich::get("en").unwrap();
+    bundle.set_use_isolating(false);
+
+    // elements are written as tags to messages
+    assert_eq!("Click <link>here</link> to continue.", bundle.proceed());
+    assert_eq!(
+        vec![
+            text("Click "),
+            element("link", vec![text("here")]),
+            text(" to continue.")
+        ],
+        bundle.proceed_segments()
+    );
+
+    let segments = bundle.inbox_segments("Bob", 2);
+    assert_eq!(
+        vec![
+            text("Welcome, "),
+            element("b", vec![text("Bob")]),
+            text("! See "),
+            element(
+                "link",
+                vec![text("your "), element("em", vec![text("messages")])]
+            ),
+            text(".")
+        ],
+        segments
+    );
+
+    let mut out = String::new();
+    render(&segments, &mut out, &mut |name, content, out| match name {
+        "link" => write!(out, "[{}](/inbox)", content),
+        _ => write!(out, "*{}*", content),
+    })
+    .unwrap();
+    assert_eq!("Welcome, *Bob*! See [your *messages*](/inbox).", out);
+
+    // unmatched tags are plain text
+    assert_eq!("1 <2 and 3 > 2, <br> <i>unclosed", bundle.plain());
+
+    let mut bundle = fluent::Rich::get("it").unwrap();
+    bundle.set_use_isolating(false);
+    assert_eq!(
+        vec![
+            text("Benvenuto, "),
+            element("b", vec![text("Bob")]),
+            text("! Vedi "),
+            element(
+                "link",
+                vec![element("em", vec![text("il tuo messaggio")])]
+            ),
+            text(".")
+        ],
+        bundle.inbox_segments("Bob", 1)
+    );
+}
@@ -16,4 +16,10 @@ fn main() {
     assert_eq!("other", bundle.test_numbers(1.000001));
     assert_eq!("the answer", bundle.test_numbers(42.0));
     assert_eq!("other", bundle.test_numbers(-9999));
+    assert_eq!("price", bundle.test_numbers(19.99));
+    assert_eq!(
+        "price",
+        bundle.test_numbers(fluent_static::value::number::Decimal::new(1999, 2))
+    );
+    assert_eq!("other", bundle.test_numbers(19.99000001));
 }
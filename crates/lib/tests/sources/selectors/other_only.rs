@@ -0,0 +1,39 @@
+mod fluent {
+    fluent_static::include_source!("selectors_other_only.rs");
+}
+
+use fluent_static::{value::Value, Localize, MessageBundle, VariableInfo, VariableKind};
+
+#[derive(Localize)]
+#[fluent(bundle = fluent::OtherOnly)]
+enum Notification {
+    NewPhotos { photo_count: u32 },
+}
+
+fn main() {
+    let mut bundle = fluent::OtherOnly::get("ja").unwrap();
+    bundle.set_use_isolating(false);
+
+    assert_eq!("新しい写真が3枚あります", bundle.new_photos(3));
+
+    // `other`-only selector does not make the variable a string
+    let message_id = fluent::OtherOnlyMessageId::NewPhotos {
+        photo_count: Value::from(3),
+    };
+    assert_eq!("新しい写真が3枚あります", bundle.format(message_id));
+    assert_eq!(
+        "新しい写真が3枚あります",
+        Notification::NewPhotos { photo_count: 3 }.localize(&bundle)
+    );
+
+    let [info] = fluent::OtherOnly::MESSAGES else {
+        panic!("Expected exactly one message");
+    };
+    assert_eq!(
+        &[VariableInfo {
+            name: "photoCount",
+            kind: VariableKind::Any
+        }],
+        info.variables
+    );
+}
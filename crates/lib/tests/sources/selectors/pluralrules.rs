@@ -2,7 +2,12 @@ mod fluent {
     fluent_static::include_source!("selectors_pluralrules.rs");
 }
 
-use fluent_static::MessageBundle;
+use std::borrow::Cow;
+
+use fluent_static::{
+    value::{Number, Value},
+    MessageBundle, MessageSource, VariableInfo, VariableKind,
+};
 
 fn main() {
     let mut bundle = fluent::Prs::get("en-US").unwrap();
@@ -103,4 +108,47 @@ fn main() {
         "Baz dodał(a) 27 nowych zdjęć do swojego strumienia.",
         bundle.shared_photos("Baz", 27, "other")
     );
+
+    // message ID fields are typed by the variable kinds
+    let message_id = fluent::PrsMessageId::SharedPhotos {
+        user_name: Value::from("Baz"),
+        photo_count: Number::from(2),
+        user_gender: Cow::from("female"),
+    };
+    assert_eq!(
+        bundle.shared_photos("Baz", 2, "female"),
+        bundle.format(message_id)
+    );
+
+    let [info] = fluent::Prs::MESSAGES else {
+        panic!("Expected exactly one message");
+    };
+    assert_eq!("shared-photos", info.id);
+    assert_eq!(
+        &[
+            VariableInfo {
+                name: "userName",
+                kind: VariableKind::Any
+            },
+            VariableInfo {
+                name: "photoCount",
+                kind: VariableKind::Number
+            },
+            VariableInfo {
+                name: "userGender",
+                kind: VariableKind::String
+            },
+        ],
+        info.variables
+    );
+    assert!(info.attributes.is_empty());
+    assert_eq!(
+        Some(&MessageSource {
+            language_id: "pl-PL",
+            path: "selectors/pluralrules-pl.ftl",
+            line: 1
+        }),
+        info.source("pl-PL")
+    );
+    assert_eq!(vec!["en-US", "pl-PL"], info.languages().collect::<Vec<_>>());
 }
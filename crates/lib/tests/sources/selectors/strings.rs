@@ -2,7 +2,25 @@ mod fluent {
     fluent_static::include_source!("selectors_strings.rs");
 }
 
-use fluent_static::MessageBundle;
+use std::borrow::Cow;
+
+use fluent_static::{
+    value::{FluentType, Value},
+    MessageBundle,
+};
+
+#[derive(Debug)]
+struct Plan(&'static str);
+
+impl FluentType for Plan {
+    fn as_string(&self, locale: &str) -> Cow<'_, str> {
+        Cow::Owned(format!("{}:{}", locale, self.0))
+    }
+
+    fn selector_key(&self) -> Option<Cow<'_, str>> {
+        Some(Cow::Borrowed(self.0))
+    }
+}
 
 fn main() {
     let mut bundle = fluent::Strings::get("it").unwrap();
@@ -12,6 +30,17 @@ fn main() {
     assert_eq!("IT Bar foobar", bundle.sel_foo("bar", "foobar", "baz"));
     assert_eq!("IT Bar 123", bundle.sel_foo("42", "123", "bazbazbaz"));
     assert_eq!("IT Baz 2 Baz 1", bundle.sel_foo("baz", "1", "2"));
+    assert_eq!("IT Foo foo", bundle.sel_foo("foo".to_string(), "bar", "baz"));
+
+    assert_eq!("IT Si", bundle.sel_bool(true));
+    assert_eq!("IT No", bundle.sel_bool(false));
+    assert_eq!("IT Premium it:premium", bundle.sel_plan(Value::custom(Plan("premium"))));
+    assert_eq!("IT Altro it:basic", bundle.sel_plan(Value::custom(Plan("basic"))));
+    if cfg!(any(feature = "icu", feature = "icu4x")) {
+        assert_eq!("IT Alice e Bob", bundle.names(vec!["Alice", "Bob"]));
+    } else {
+        assert_eq!("IT Alice, Bob", bundle.names(vec!["Alice", "Bob"]));
+    }
 
     let mut bundle = fluent::Strings::get("fr").unwrap_or_default();
     bundle.set_use_isolating(false);
@@ -20,4 +49,12 @@ fn main() {
     assert_eq!("EN Bar foobar", bundle.sel_foo("bar", "foobar", "baz"));
     assert_eq!("EN Baz baz Baz 123", bundle.sel_foo("q", "123", "baz"));
     assert_eq!("EN Baz 2 Baz 1", bundle.sel_foo("baz", "1", "2"));
+
+    assert_eq!("EN Yes", bundle.sel_bool(true));
+    assert_eq!("EN Premium en:premium", bundle.sel_plan(Value::custom(Plan("premium"))));
+    if cfg!(any(feature = "icu", feature = "icu4x")) {
+        assert_eq!("EN Alice and Bob", bundle.names(vec!["Alice", "Bob"]));
+    } else {
+        assert_eq!("EN Alice, Bob", bundle.names(vec!["Alice", "Bob"]));
+    }
 }
@@ -1,15 +1,17 @@
 use std::{collections::HashMap, env, ffi::OsString};
 
+use convert_case::{Case, Casing};
 use fluent_static_codegen::{
-    function::{FunctionCallGenerator, FunctionRegistry},
+    function::{FunctionCallArgs, FunctionCallGenerator, FunctionRegistry, FunctionSchema},
     MessageBundleBuilder,
 };
 use proc_macro::TokenStream;
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::{format_ident, quote};
 use syn::{
-    parse::Parse, parse_macro_input, punctuated::Punctuated, spanned::Spanned, token::Comma, Ident,
-    ItemStruct, LitStr, Result as SyntaxResult, Token,
+    ext::IdentExt, parse::Parse, parse_macro_input, punctuated::Punctuated, spanned::Spanned,
+    token::Comma, DeriveInput, Ident, ItemStruct, LitBool, LitStr, Path, PathSegment,
+    Result as SyntaxResult, Token,
 };
 
 macro_rules! syntax_err {
@@ -18,6 +20,8 @@ macro_rules! syntax_err {
     }
 }
 
+mod localize;
+
 #[proc_macro_attribute]
 pub fn message_bundle(args: TokenStream, input: TokenStream) -> TokenStream {
     let item_struct = parse_macro_input!(input as ItemStruct);
@@ -56,6 +60,14 @@ pub fn message_bundle(args: TokenStream, input: TokenStream) -> TokenStream {
     }
 }
 
+#[proc_macro_derive(Localize, attributes(fluent))]
+pub fn localize(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    localize::derive_localize(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
 fn get_project_dir() -> Option<OsString> {
     env::var_os("CARGO_MANIFEST_DIR_OVERRIDE") // used for tests
         .or_else(|| env::var_os("CARGO_MANIFEST_DIR"))
@@ -88,25 +100,68 @@ impl Parse for FluentResource {
     }
 }
 
-struct FunctionMapping {
-    fluent_id: LitStr,
-    fn_ident: Option<Ident>,
+enum FunctionMapping {
+    /// `"ID"`, `"ID" = method` or `"ID" = path::to::function`
+    Function {
+        fluent_id: LitStr,
+        fn_path: Option<Path>,
+        requires_context: bool,
+    },
+    /// `path::to::pack::*`, module of function pack. Fluent function `FOO_BAR` is resolved
+    /// as `path::to::pack::foo_bar` and its calls are checked against `path::to::pack::FUNCTIONS`
+    Pack(Path),
 }
 
 impl Parse for FunctionMapping {
     fn parse(input: syn::parse::ParseStream) -> SyntaxResult<Self> {
-        let fluent_id = input.parse::<LitStr>()?;
-        let fn_ident = if input.peek(Token![=]) {
-            input.parse::<Token![=]>()?;
-            Some(input.parse::<Ident>()?)
+        if input.peek(LitStr) {
+            let fluent_id = input.parse::<LitStr>()?;
+            let fn_path = if input.peek(Token![=]) {
+                input.parse::<Token![=]>()?;
+                Some(input.parse::<Path>()?)
+            } else {
+                None
+            };
+            Ok(FunctionMapping::Function {
+                fluent_id,
+                fn_path,
+                requires_context: false,
+            })
         } else {
-            None
-        };
-        Ok(FunctionMapping {
-            fluent_id,
-            fn_ident,
-        })
+            let mut path = Path {
+                leading_colon: input.parse()?,
+                segments: Punctuated::new(),
+            };
+            loop {
+                path.segments
+                    .push_value(PathSegment::from(input.call(Ident::parse_any)?));
+                if !input.peek(Token![::]) {
+                    return Err(syntax_err!(
+                        path.span(),
+                        "Function pack must be referenced by module, e.g. 'path::to::pack::*'"
+                    ));
+                }
+                let separator = input.parse::<Token![::]>()?;
+                if input.peek(Token![*]) {
+                    input.parse::<Token![*]>()?;
+                    return Ok(FunctionMapping::Pack(path));
+                }
+                path.segments.push_punct(separator);
+            }
+        }
+    }
+}
+
+fn parse_function_mappings(
+    input: syn::parse::ParseStream,
+) -> SyntaxResult<Punctuated<FunctionMapping, Comma>> {
+    let content;
+    if input.peek(syn::token::Bracket) {
+        syn::bracketed!(content in input);
+    } else {
+        syn::parenthesized!(content in input);
     }
+    content.parse_terminated(FunctionMapping::parse, Token![,])
 }
 
 impl Parse for MessageBundleAttr {
@@ -118,6 +173,10 @@ impl Parse for MessageBundleAttr {
         let mut function_mappings: Vec<FunctionMapping> = Vec::new();
         let mut lang_def: Option<LitStr> = None;
         let mut formatter: Option<LitStr> = None;
+        let mut embed_locale_data: Option<LitBool> = None;
+        let mut html: Option<LitBool> = None;
+        let mut isolation: Option<LitStr> = None;
+        let mut unisolated_messages: Vec<LitStr> = Vec::new();
 
         while !input.is_empty() {
             let ident: Ident = input.parse()?;
@@ -135,15 +194,46 @@ impl Parse for MessageBundleAttr {
                     lang_def = Some(input.parse()?);
                 }
                 "functions" => {
-                    let content;
-                    syn::parenthesized!(content in input);
-                    let fn_mappings: Punctuated<FunctionMapping, Comma> =
-                        content.parse_terminated(FunctionMapping::parse, Token![,])?;
-                    function_mappings.extend(fn_mappings);
+                    function_mappings.extend(parse_function_mappings(input)?);
+                }
+                "context_functions" => {
+                    for mapping in parse_function_mappings(input)? {
+                        match mapping {
+                            FunctionMapping::Function {
+                                fluent_id, fn_path, ..
+                            } => function_mappings.push(FunctionMapping::Function {
+                                fluent_id,
+                                fn_path,
+                                requires_context: true,
+                            }),
+                            FunctionMapping::Pack(path) => {
+                                return Err(syntax_err!(
+                                    path.span(),
+                                    "Function packs are not supported in 'context_functions', use 'functions' instead"
+                                ))
+                            }
+                        }
+                    }
                 }
                 "formatter" => {
                     formatter = Some(input.parse()?);
                 }
+                "embed_locale_data" => {
+                    embed_locale_data = Some(input.parse()?);
+                }
+                "html" => {
+                    html = Some(input.parse()?);
+                }
+                "isolation" => {
+                    isolation = Some(input.parse()?);
+                }
+                "unisolated_messages" => {
+                    let message_list;
+                    syn::bracketed!(message_list in input);
+                    let message_ids: Punctuated<LitStr, Comma> =
+                        message_list.parse_terminated(|input| input.parse(), Token![,])?;
+                    unisolated_messages.extend(message_ids);
+                }
                 attr => return Err(syntax_err!(ident.span(), "Unexpected attribute {attr}")),
             }
 
@@ -183,6 +273,32 @@ impl Parse for MessageBundleAttr {
                     })?;
             }
 
+            if let Some(embed_locale_data) = embed_locale_data {
+                #[cfg(feature = "cldr")]
+                builder.set_embed_locale_data(embed_locale_data.value);
+                #[cfg(not(feature = "cldr"))]
+                return Err(syntax_err!(
+                    embed_locale_data.span(),
+                    "Embedding locale data requires 'cldr' feature"
+                ));
+            }
+
+            if let Some(html) = html {
+                builder.set_html(html.value);
+            }
+
+            if let Some(isolation) = isolation {
+                builder.set_isolation(isolation.value().parse().map_err(|e| {
+                    syntax_err!(isolation.span(), "Error parsing isolation: {}", e)
+                })?);
+            }
+
+            builder
+                .set_unisolated_messages(unisolated_messages.iter().map(LitStr::value))
+                .map_err(|e| {
+                    syntax_err!(input.span(), "Error setting unisolated messages: {}", e)
+                })?;
+
             if !function_mappings.is_empty() {
                 builder.set_function_call_generator(BundleFunctionCallGenerator::new(
                     function_mappings,
@@ -201,31 +317,59 @@ impl Parse for MessageBundleAttr {
     }
 }
 
+enum BundleFunction {
+    /// Method of the bundle struct
+    Method {
+        ident: Ident,
+        requires_context: bool,
+    },
+    /// Function outside of the bundle struct, signature is resolved by `fluent_static::function::call`
+    Path(Path),
+}
+
 struct BundleFunctionCallGenerator {
-    fns: HashMap<String, TokenStream2>,
+    fns: HashMap<String, BundleFunction>,
+    packs: Vec<Path>,
     registry: FunctionRegistry,
 }
 
 impl BundleFunctionCallGenerator {
     pub fn new(fn_mappings: Vec<FunctionMapping>) -> Self {
-        let fns = fn_mappings
-            .into_iter()
-            .map(|mapping| {
-                let ident = mapping
-                    .fn_ident
-                    .unwrap_or_else(|| format_ident!("{}", mapping.fluent_id.value()));
-                (
-                    mapping.fluent_id.value(),
-                    quote! {
-                        #ident
-                    },
-                )
-            })
-            .collect();
+        let mut fns = HashMap::new();
+        let mut packs = Vec::new();
+        for mapping in fn_mappings {
+            match mapping {
+                FunctionMapping::Function {
+                    fluent_id,
+                    fn_path,
+                    requires_context,
+                } => {
+                    let function = match fn_path {
+                        Some(path) if path.leading_colon.is_some() || path.segments.len() > 1 => {
+                            BundleFunction::Path(path)
+                        }
+                        Some(path) => BundleFunction::Method {
+                            ident: path.segments[0].ident.clone(),
+                            requires_context,
+                        },
+                        None => BundleFunction::Method {
+                            ident: format_ident!("{}", fluent_id.value()),
+                            requires_context,
+                        },
+                    };
+                    fns.insert(fluent_id.value(), function);
+                }
+                FunctionMapping::Pack(path) => packs.push(path),
+            }
+        }
 
         let registry = FunctionRegistry::default();
 
-        Self { fns, registry }
+        Self {
+            fns,
+            packs,
+            registry,
+        }
     }
 }
 
@@ -236,13 +380,114 @@ impl FunctionCallGenerator for BundleFunctionCallGenerator {
         positional_args: &Ident,
         named_args: &Ident,
     ) -> Option<TokenStream2> {
-        if let Some(fn_ident) = self.fns.get(function_name) {
-            Some(quote! {
-                Self::#fn_ident(&#positional_args, &#named_args)
-            })
-        } else {
-            self.registry
+        match self.fns.get(function_name) {
+            Some(BundleFunction::Method {
+                ident,
+                requires_context: true,
+            }) => Some(quote! {
+                Self::#ident(&self._function_context_(), &#positional_args, &#named_args)
+            }),
+            Some(BundleFunction::Method { ident, .. }) => Some(quote! {
+                Self::#ident(&#positional_args, &#named_args)
+            }),
+            Some(BundleFunction::Path(path)) => Some(quote! {
+                ::fluent_static::function::call(#path, &self._function_context_(), &#positional_args, &#named_args)
+            }),
+            None => self
+                .registry
                 .generate(function_name, positional_args, named_args)
+                .or_else(|| {
+                    if self.packs.is_empty() {
+                        return None;
+                    }
+                    // glob imports of nested blocks shadow the outer ones, so the innermost
+                    // first pack takes precedence like in `find_function`
+                    let ident = format_ident!("{}", function_name.to_case(Case::Snake));
+                    let function = self.packs.iter().fold(quote! { #ident }, |inner, module| {
+                        quote! {
+                            {
+                                use #module::*;
+                                #inner
+                            }
+                        }
+                    });
+                    Some(quote! {
+                        ::fluent_static::function::call(
+                            #function,
+                            &self._function_context_(),
+                            &#positional_args,
+                            &#named_args,
+                        )
+                    })
+                }),
+        }
+    }
+
+    fn schema(&self, function_name: &str) -> Option<FunctionSchema> {
+        if self.fns.contains_key(function_name) {
+            None
+        } else {
+            self.registry.schema(function_name)
+        }
+    }
+
+    /// Pack constants can't be read during macro expansion, so calls of pack functions
+    /// are checked against the pack schema in const context, failing the build with a panic
+    fn generate_check(
+        &self,
+        function_name: &str,
+        arguments: &FunctionCallArgs,
+    ) -> Option<TokenStream2> {
+        if self.packs.is_empty()
+            || self.fns.contains_key(function_name)
+            || self.registry.schema(function_name).is_some()
+        {
+            return None;
         }
+        let packs = self
+            .packs
+            .iter()
+            .map(|module| quote! { #module::FUNCTIONS });
+        let name = function_name.to_case(Case::Snake);
+        let undefined = format!("Function '{function_name}' is not defined in function packs");
+        let misnamed =
+            format!("Function '{function_name}' of function pack must be named '{name}'");
+        let positional = arguments.positional;
+        let invalid_arity = format!(
+            "Function '{function_name}' is called with invalid number of positional arguments: {positional}"
+        );
+        let option_checks = arguments
+            .option_literals()
+            .into_iter()
+            .map(|(option, literal)| {
+                let unknown =
+                    format!("Function '{function_name}' is called with unknown option '{option}'");
+                let invalid = format!(
+                    "Function '{function_name}' is called with invalid value of option '{option}'"
+                );
+                quote! {
+                    if !function.schema.accepts_option(#option) {
+                        panic!(#unknown);
+                    }
+                    if !function.schema.accepts_option_value(#option, #literal) {
+                        panic!(#invalid);
+                    }
+                }
+            });
+        Some(quote! {
+            const _: () = {
+                let function = match ::fluent_static::function::find_function(&[#(&#packs),*], #function_name) {
+                    Some(function) => function,
+                    None => panic!(#undefined),
+                };
+                if !function.has_name(#name) {
+                    panic!(#misnamed);
+                }
+                if !function.schema.accepts_arity(#positional) {
+                    panic!(#invalid_arity);
+                }
+                #(#option_checks)*
+            };
+        })
     }
 }
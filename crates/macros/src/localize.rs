@@ -0,0 +1,221 @@
+use convert_case::{Case, Casing};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    spanned::Spanned, Attribute, Data, DeriveInput, Fields, Ident, LitStr, Path,
+    Result as SyntaxResult, Variant,
+};
+
+const FLUENT_ATTR: &str = "fluent";
+
+#[derive(Default)]
+struct LocalizeAttr {
+    bundle: Option<Path>,
+    message: Option<LitStr>,
+}
+
+impl LocalizeAttr {
+    fn parse(attrs: &[Attribute]) -> SyntaxResult<Self> {
+        let mut result = Self::default();
+        for attr in attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident(FLUENT_ATTR))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("bundle") {
+                    result.bundle = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("message") {
+                    result.message = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("Unexpected attribute, expected 'bundle' or 'message'"))
+                }
+            })?;
+        }
+        Ok(result)
+    }
+}
+
+enum FieldAttr {
+    Arg(Ident),
+    Skip,
+}
+
+impl FieldAttr {
+    fn parse(field: &syn::Field) -> SyntaxResult<Option<Self>> {
+        let mut result = None;
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident(FLUENT_ATTR))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    result = Some(FieldAttr::Skip);
+                    Ok(())
+                } else if meta.path.is_ident("arg") {
+                    let name: LitStr = meta.value()?.parse()?;
+                    result = Some(FieldAttr::Arg(format_ident!(
+                        "{}",
+                        name.value().to_case(Case::Snake),
+                        span = name.span()
+                    )));
+                    Ok(())
+                } else {
+                    Err(meta.error("Unexpected attribute, expected 'arg' or 'skip'"))
+                }
+            })?;
+        }
+        Ok(result)
+    }
+}
+
+pub fn derive_localize(input: DeriveInput) -> SyntaxResult<TokenStream2> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syntax_err!(
+            input.span(),
+            "Localize can only be derived for enums"
+        ));
+    };
+
+    let enum_attr = LocalizeAttr::parse(&input.attrs)?;
+    if let Some(message) = enum_attr.message.as_ref() {
+        return Err(syntax_err!(
+            message.span(),
+            "'message' must be set on enum variants"
+        ));
+    }
+
+    let mut bundle = enum_attr.bundle;
+    let mut arms = Vec::new();
+    for variant in data.variants.iter() {
+        let variant_attr = LocalizeAttr::parse(&variant.attrs)?;
+        match (&bundle, variant_attr.bundle) {
+            (Some(expected), Some(actual)) if !same_path(expected, &actual) => {
+                return Err(syntax_err!(
+                    actual.span(),
+                    "All variants must be localized with the same bundle"
+                ))
+            }
+            (None, actual @ Some(_)) => bundle = actual,
+            _ => (),
+        }
+        let (message_id, span) = variant_attr
+            .message
+            .map(|message| (message.value(), message.span()))
+            .unwrap_or_else(|| {
+                (
+                    variant.ident.to_string().to_case(Case::Kebab),
+                    variant.ident.span(),
+                )
+            });
+        arms.push((variant, message_id, span));
+    }
+
+    let bundle = bundle.ok_or_else(|| {
+        syntax_err!(
+            input.span(),
+            "Missing bundle, add #[fluent(bundle = MyBundle)] to the enum"
+        )
+    })?;
+    let message_id_type = message_id_type(&bundle);
+
+    let arms = arms
+        .into_iter()
+        .map(|(variant, message_id, span)| {
+            generate_arm(variant, &message_id, span, &message_id_type)
+        })
+        .collect::<SyntaxResult<Vec<_>>>()?;
+
+    // empty enums have no variants to match on
+    let scrutinee = if arms.is_empty() {
+        quote! { *self }
+    } else {
+        quote! { self }
+    };
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::fluent_static::Localize<#bundle> for #ident #ty_generics #where_clause {
+            fn localize(&self, bundle: &#bundle) -> ::fluent_static::Message {
+                match #scrutinee {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+fn generate_arm(
+    variant: &Variant,
+    message_id: &str,
+    span: Span,
+    message_id_type: &Path,
+) -> SyntaxResult<TokenStream2> {
+    let variant_ident = &variant.ident;
+    let message_variant = format_ident!(
+        "{}",
+        message_id.replace('.', "_").to_case(Case::Pascal),
+        span = span
+    );
+
+    let mut bindings = Vec::new();
+    let mut args = Vec::new();
+    for (index, field) in variant.fields.iter().enumerate() {
+        let binding = format_ident!("__field_{}", index);
+        let arg = match FieldAttr::parse(field)? {
+            Some(FieldAttr::Skip) => continue,
+            Some(FieldAttr::Arg(arg)) => arg,
+            None => field.ident.clone().ok_or_else(|| {
+                syntax_err!(
+                    field.span(),
+                    "Unnamed fields require #[fluent(arg = \"name\")] or #[fluent(skip)]"
+                )
+            })?,
+        };
+        args.push(quote_spanned! { field.span() =>
+            #arg: ::std::convert::Into::into(::std::clone::Clone::clone(#binding))
+        });
+        bindings.push((index, field.ident.as_ref(), binding));
+    }
+
+    let pattern = match &variant.fields {
+        Fields::Named(_) => {
+            let fields = bindings
+                .iter()
+                .map(|(_, ident, binding)| quote! { #ident: #binding });
+            quote! { Self::#variant_ident { #(#fields,)* .. } }
+        }
+        Fields::Unnamed(_) => {
+            let fields = (0..variant.fields.len()).map(|index| {
+                bindings
+                    .iter()
+                    .find(|(i, _, _)| *i == index)
+                    .map(|(_, _, binding)| quote! { #binding })
+                    .unwrap_or_else(|| quote! { _ })
+            });
+            quote! { Self::#variant_ident ( #(#fields),* ) }
+        }
+        Fields::Unit => quote! { Self::#variant_ident },
+    };
+
+    Ok(quote! {
+        #pattern => bundle.format(#message_id_type::#message_variant { #(#args,)* }),
+    })
+}
+
+/// `path::to::Bundle` -> `path::to::BundleMessageId`
+fn message_id_type(bundle: &Path) -> Path {
+    let mut path = bundle.clone();
+    if let Some(segment) = path.segments.last_mut() {
+        segment.ident = format_ident!("{}MessageId", segment.ident, span = segment.ident.span());
+    }
+    path
+}
+
+fn same_path(a: &Path, b: &Path) -> bool {
+    quote!(#a).to_string() == quote!(#b).to_string()
+}
@@ -0,0 +1,201 @@
+use std::{fmt::Write, str::FromStr};
+
+/// Base direction of a language or text
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TextDirection {
+    #[default]
+    Ltr,
+    Rtl,
+}
+
+/// Strategy of wrapping formatted placeables into Unicode bidi isolation
+/// marks FSI (U+2068) and PDI (U+2069)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Isolation {
+    /// Isolates every placeable
+    #[default]
+    Always,
+    /// Isolates only placeables which direction differs from the direction of the
+    /// surrounding text: the nearest letter of the adjacent literal text, preceding one
+    /// first, or the language direction if the pattern has no letters around the placeable
+    Auto,
+    Never,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid isolation: '{0}'")]
+pub struct InvalidIsolationError(String);
+
+impl FromStr for Isolation {
+    type Err = InvalidIsolationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "always" => Ok(Isolation::Always),
+            "auto" => Ok(Isolation::Auto),
+            "never" => Ok(Isolation::Never),
+            _ => Err(InvalidIsolationError(s.to_string())),
+        }
+    }
+}
+
+const RTL_SCRIPTS: &[&str] = &[
+    "adlm", "arab", "aran", "hebr", "mand", "mend", "nkoo", "rohg", "samr", "syrc", "syre", "syrj",
+    "syrn", "thaa", "yezi",
+];
+
+const RTL_LANGUAGES: &[&str] = &[
+    "ar", "ckb", "dv", "fa", "he", "iw", "ks", "ps", "sd", "syr", "ug", "ur", "yi",
+];
+
+/// Direction of a language ID, script subtag takes precedence over the language,
+/// e.g. `az-Arab` is right-to-left and `ug-Latn` is left-to-right
+pub fn language_direction(language_id: &str) -> TextDirection {
+    let mut subtags = language_id.split(['-', '_']);
+    let language = subtags.next().unwrap_or_default().to_lowercase();
+    let script = subtags
+        .next()
+        .filter(|subtag| subtag.len() == 4 && subtag.chars().all(|c| c.is_ascii_alphabetic()))
+        .map(str::to_lowercase);
+
+    let is_rtl = match script {
+        Some(script) => RTL_SCRIPTS.contains(&script.as_str()),
+        None => RTL_LANGUAGES.contains(&language.as_str()),
+    };
+    if is_rtl {
+        TextDirection::Rtl
+    } else {
+        TextDirection::Ltr
+    }
+}
+
+fn is_strong_rtl(c: char) -> bool {
+    matches!(c,
+        '\u{0590}'..='\u{065F}'
+        | '\u{066A}'..='\u{06EF}'
+        | '\u{06FA}'..='\u{08FF}'
+        | '\u{FB1D}'..='\u{FDFF}'
+        | '\u{FE70}'..='\u{FEFF}'
+        | '\u{10800}'..='\u{10FFF}'
+        | '\u{1E800}'..='\u{1EFFF}')
+        && c.is_alphabetic()
+}
+
+/// Direction of a strong (letter) character, `None` for other characters
+pub fn char_direction(c: char) -> Option<TextDirection> {
+    if !c.is_alphabetic() {
+        None
+    } else if is_strong_rtl(c) {
+        Some(TextDirection::Rtl)
+    } else {
+        Some(TextDirection::Ltr)
+    }
+}
+
+/// Direction of strong (letter) characters of the text,
+/// `None` if the text has no letters or mixes both directions
+pub fn text_direction(text: &str) -> Option<TextDirection> {
+    let mut direction = None;
+    for char_direction in text.chars().filter_map(char_direction) {
+        match direction {
+            None => direction = Some(char_direction),
+            Some(direction) if direction != char_direction => return None,
+            _ => (),
+        }
+    }
+    direction
+}
+
+/// Whether formatted placeable `text` must be isolated in a text of given direction.
+/// Text without letters, e.g. a number, needs no isolation only in left-to-right text
+pub fn needs_isolation(direction: TextDirection, text: &str) -> bool {
+    match text_direction(text) {
+        Some(text_direction) => text_direction != direction,
+        None => direction == TextDirection::Rtl || text.chars().any(char::is_alphabetic),
+    }
+}
+
+/// Writes placeable formatted by `format` according to the isolation strategy,
+/// `direction` is the direction of the surrounding text
+pub fn write_isolated(
+    isolation: Isolation,
+    direction: TextDirection,
+    out: &mut dyn Write,
+    format: impl FnOnce(&mut dyn Write) -> std::fmt::Result,
+) -> std::fmt::Result {
+    match isolation {
+        Isolation::Never => format(out),
+        Isolation::Always => {
+            out.write_char('\u{2068}')?;
+            format(out)?;
+            out.write_char('\u{2069}')
+        }
+        Isolation::Auto => {
+            let mut text = String::new();
+            format(&mut text)?;
+            if needs_isolation(direction, &text) {
+                out.write_char('\u{2068}')?;
+                out.write_str(&text)?;
+                out.write_char('\u{2069}')
+            } else {
+                out.write_str(&text)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        char_direction, language_direction, needs_isolation, text_direction, write_isolated,
+        Isolation, TextDirection,
+    };
+
+    #[test]
+    fn test_language_direction() {
+        assert_eq!(TextDirection::Ltr, language_direction("en-US"));
+        assert_eq!(TextDirection::Rtl, language_direction("ar"));
+        assert_eq!(TextDirection::Rtl, language_direction("he-IL"));
+        assert_eq!(TextDirection::Rtl, language_direction("az-Arab"));
+        assert_eq!(TextDirection::Ltr, language_direction("ug_Latn"));
+        assert_eq!(TextDirection::Ltr, language_direction("arn"));
+    }
+
+    #[test]
+    fn test_text_direction() {
+        assert_eq!(Some(TextDirection::Ltr), text_direction("Hello 42"));
+        assert_eq!(Some(TextDirection::Rtl), text_direction("שלום"));
+        assert_eq!(Some(TextDirection::Rtl), text_direction("مرحبا ٣"));
+        assert_eq!(None, text_direction("Hello שלום"));
+        assert_eq!(None, text_direction("1,234.5"));
+        assert_eq!(Some(TextDirection::Rtl), char_direction('ש'));
+        assert_eq!(Some(TextDirection::Ltr), char_direction('B'));
+        assert_eq!(None, char_direction('4'));
+
+        assert!(!needs_isolation(TextDirection::Ltr, "Bob"));
+        assert!(!needs_isolation(TextDirection::Ltr, "1,234.5"));
+        assert!(needs_isolation(TextDirection::Ltr, "שלום"));
+        assert!(!needs_isolation(TextDirection::Rtl, "שלום"));
+        assert!(needs_isolation(TextDirection::Rtl, "1,234.5"));
+        assert!(needs_isolation(TextDirection::Rtl, "Bob"));
+    }
+
+    #[test]
+    fn test_write_isolated() {
+        let write = |isolation, direction, text: &str| {
+            let mut out = String::new();
+            write_isolated(isolation, direction, &mut out, |out| out.write_str(text)).unwrap();
+            out
+        };
+        assert_eq!(
+            "\u{2068}Bob\u{2069}",
+            write(Isolation::Always, TextDirection::Ltr, "Bob")
+        );
+        assert_eq!("Bob", write(Isolation::Never, TextDirection::Rtl, "Bob"));
+        assert_eq!("Bob", write(Isolation::Auto, TextDirection::Ltr, "Bob"));
+        assert_eq!(
+            "\u{2068}Bob\u{2069}",
+            write(Isolation::Auto, TextDirection::Rtl, "Bob")
+        );
+    }
+}
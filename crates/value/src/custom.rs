@@ -0,0 +1,69 @@
+use std::{any::Any, borrow::Cow, fmt::Debug};
+
+/// Application defined type that can be passed to messages as [`Value::Custom`](crate::Value::Custom)
+pub trait FluentType: Any + Debug + Send + Sync {
+    /// Returns string representation of the value for the given locale
+    fn as_string(&self, locale: &str) -> Cow<'_, str>;
+
+    /// Returns key used to match selector variants, values without key
+    /// can only be matched by the default variant
+    fn selector_key(&self) -> Option<Cow<'_, str>> {
+        None
+    }
+}
+
+impl dyn FluentType {
+    /// Returns reference to the concrete value, e.g. to render it in a custom formatter
+    pub fn downcast_ref<T: FluentType>(&self) -> Option<&T> {
+        (self as &dyn Any).downcast_ref()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{borrow::Cow, sync::Arc};
+
+    use crate::Value;
+
+    use super::FluentType;
+
+    #[derive(Debug)]
+    struct Plan(&'static str);
+
+    impl FluentType for Plan {
+        fn as_string(&self, _locale: &str) -> Cow<'_, str> {
+            Cow::Borrowed(self.0)
+        }
+
+        fn selector_key(&self) -> Option<Cow<'_, str>> {
+            Some(Cow::Borrowed(self.0))
+        }
+    }
+
+    #[test]
+    fn test_custom_value() {
+        let value = Value::custom(Plan("premium"));
+        let Value::Custom(custom) = &value else {
+            panic!("Expected custom value");
+        };
+        assert_eq!("premium", custom.as_string("en"));
+        assert_eq!(Some("premium"), custom.selector_key().as_deref());
+        assert_eq!("premium", custom.downcast_ref::<Plan>().unwrap().0);
+
+        assert_eq!(value, value.clone());
+        assert_ne!(value, Value::custom(Plan("premium")));
+        assert_ne!(value, Value::Custom(Arc::new(Plan("premium"))));
+    }
+
+    #[test]
+    fn test_list_value() {
+        let value = Value::from(vec!["Alice", "Bob"]);
+        assert!(value.is_list());
+        assert_eq!(
+            value,
+            Value::list([Value::from("Alice"), Value::from("Bob")])
+        );
+        assert_eq!(Value::from(true), Value::Bool(true));
+        assert_ne!(Value::from(true), Value::from("true"));
+    }
+}
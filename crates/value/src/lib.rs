@@ -1,9 +1,16 @@
-use std::{borrow::Cow, str::FromStr};
+use std::{borrow::Cow, str::FromStr, sync::Arc, time::SystemTime};
 
+pub mod bidi;
+mod custom;
+pub mod list;
 pub mod number;
+pub mod relative_time;
 
+pub use custom::FluentType;
+pub use list::ListFormat;
 pub use number::format::NumberFormat;
 pub use number::Number;
+pub use relative_time::RelativeTimeFormat;
 
 #[derive(Debug, Clone)]
 pub enum Value<'a> {
@@ -12,6 +19,22 @@ pub enum Value<'a> {
         value: Number,
         format: Option<NumberFormat>,
     },
+    RelativeTime {
+        value: Number,
+        format: RelativeTimeFormat,
+    },
+    Bool(bool),
+    List(Vec<Value<'a>>),
+    /// List with explicit format, e.g. returned by `LIST` function
+    FormattedList {
+        items: Vec<Value<'a>>,
+        format: ListFormat,
+    },
+    /// Application defined value rendered by [`FluentType::as_string`] or by a custom formatter
+    Custom(Arc<dyn FluentType>),
+    /// Pre-rendered markup fragment, e.g. HTML link returned by a function.
+    /// Written as is, without formatting and bidi isolation, and not escaped by HTML renderers
+    Markup(Cow<'a, str>),
     // TODO datetime
     Empty,
     Error,
@@ -33,6 +56,42 @@ impl<'a> Value<'a> {
         }
     }
 
+    pub fn relative_time(value: impl Into<Number>, unit: relative_time::RelativeTimeUnit) -> Self {
+        Self::RelativeTime {
+            value: value.into(),
+            format: RelativeTimeFormat::new(unit),
+        }
+    }
+
+    /// Relative time of the timestamp, e.g. "3 hours ago", in the largest fitting unit
+    pub fn relative_time_from_now(timestamp: SystemTime) -> Self {
+        let (value, unit) =
+            relative_time::RelativeTimeUnit::best_fit(relative_time::seconds_from_now(timestamp));
+        Self::relative_time(value, unit)
+    }
+
+    pub fn list<T: Into<Value<'a>>>(items: impl IntoIterator<Item = T>) -> Self {
+        Self::List(items.into_iter().map(Into::into).collect())
+    }
+
+    pub fn formatted_list<T: Into<Value<'a>>>(
+        items: impl IntoIterator<Item = T>,
+        list_format: ListFormat,
+    ) -> Self {
+        Self::FormattedList {
+            items: items.into_iter().map(Into::into).collect(),
+            format: list_format,
+        }
+    }
+
+    pub fn custom(value: impl FluentType) -> Self {
+        Self::Custom(Arc::new(value))
+    }
+
+    pub fn markup(fragment: impl Into<Cow<'a, str>>) -> Self {
+        Self::Markup(fragment.into())
+    }
+
     pub fn is_string(&self) -> bool {
         match self {
             Value::String(_) => true,
@@ -46,6 +105,37 @@ impl<'a> Value<'a> {
             _ => false,
         }
     }
+
+    pub fn is_bool(&self) -> bool {
+        matches!(self, Value::Bool(_))
+    }
+
+    pub fn is_list(&self) -> bool {
+        matches!(self, Value::List(_) | Value::FormattedList { .. })
+    }
+
+    pub fn is_markup(&self) -> bool {
+        matches!(self, Value::Markup(_))
+    }
+
+    /// Converts value into one that doesn't borrow any data, e.g. to return it from a function
+    pub fn into_owned(self) -> Value<'static> {
+        match self {
+            Value::String(s) => Value::String(Cow::Owned(s.into_owned())),
+            Value::Number { value, format } => Value::Number { value, format },
+            Value::RelativeTime { value, format } => Value::RelativeTime { value, format },
+            Value::Bool(b) => Value::Bool(b),
+            Value::List(items) => Value::List(items.into_iter().map(Value::into_owned).collect()),
+            Value::FormattedList { items, format } => Value::FormattedList {
+                items: items.into_iter().map(Value::into_owned).collect(),
+                format,
+            },
+            Value::Custom(custom) => Value::Custom(custom),
+            Value::Markup(s) => Value::Markup(Cow::Owned(s.into_owned())),
+            Value::Empty => Value::Empty,
+            Value::Error => Value::Error,
+        }
+    }
 }
 
 impl<'a> PartialEq for Value<'a> {
@@ -60,6 +150,24 @@ impl<'a> PartialEq for Value<'a> {
                     value: other_value, ..
                 },
             ) => self_value == other_value,
+            (
+                Value::RelativeTime { value, format },
+                Value::RelativeTime {
+                    value: other_value,
+                    format: other_format,
+                },
+            ) => value == other_value && format.unit == other_format.unit,
+            (Value::Bool(b), Value::Bool(o)) => b == o,
+            (
+                Value::List(items) | Value::FormattedList { items, .. },
+                Value::List(other_items)
+                | Value::FormattedList {
+                    items: other_items, ..
+                },
+            ) => items == other_items,
+            // custom values are opaque, only the same instance is equal to itself
+            (Value::Custom(c), Value::Custom(o)) => Arc::ptr_eq(c, o),
+            (Value::Markup(s), Value::Markup(o)) => s == o,
             _ => false,
         }
     }
@@ -89,6 +197,27 @@ impl<'a> From<Cow<'a, str>> for Value<'a> {
     }
 }
 
+impl<'a> From<bool> for Value<'a> {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl<'a, T> From<Vec<T>> for Value<'a>
+where
+    T: Into<Value<'a>>,
+{
+    fn from(value: Vec<T>) -> Self {
+        Self::list(value)
+    }
+}
+
+impl<'a> From<Arc<dyn FluentType>> for Value<'a> {
+    fn from(value: Arc<dyn FluentType>) -> Self {
+        Self::Custom(value)
+    }
+}
+
 impl<'a, T> From<Option<T>> for Value<'a>
 where
     T: Into<Value<'a>>,
@@ -0,0 +1,66 @@
+use std::str::FromStr;
+
+/// List formatting options of the `LIST` function
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ListFormat {
+    pub list_type: ListType,
+    pub style: ListStyle,
+}
+
+impl ListFormat {
+    pub fn new(list_type: ListType, style: ListStyle) -> Self {
+        Self { list_type, style }
+    }
+}
+
+/// Type of the list, e.g. "A, B, and C" for conjunction, "A, B, or C" for disjunction
+/// and "A, B, C" for unit list
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ListType {
+    #[default]
+    Conjunction,
+    Disjunction,
+    Unit,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid list type: '{0}'")]
+pub struct InvalidListTypeError(String);
+
+impl FromStr for ListType {
+    type Err = InvalidListTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "conjunction" => Ok(ListType::Conjunction),
+            "disjunction" => Ok(ListType::Disjunction),
+            "unit" => Ok(ListType::Unit),
+            _ => Err(InvalidListTypeError(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ListStyle {
+    #[default]
+    Long,
+    Short,
+    Narrow,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid list style: '{0}'")]
+pub struct InvalidListStyleError(String);
+
+impl FromStr for ListStyle {
+    type Err = InvalidListStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "long" => Ok(ListStyle::Long),
+            "short" => Ok(ListStyle::Short),
+            "narrow" => Ok(ListStyle::Narrow),
+            _ => Err(InvalidListStyleError(s.to_string())),
+        }
+    }
+}
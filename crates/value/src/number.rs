@@ -1,7 +1,14 @@
-use std::str::FromStr;
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
 
+use intl_pluralrules::operands::PluralOperands;
+
+pub mod decimal;
 pub mod format;
+mod ops;
 use crate::Value;
+pub use decimal::{Decimal, RoundingMode};
+use format::{NumberFormat, TrailingZeroDisplay};
+pub use ops::TryFromNumberError;
 
 #[derive(Debug, Clone, Copy)]
 pub enum Number {
@@ -10,44 +17,224 @@ pub enum Number {
     I128(i128),
     U128(u128),
     F64(f64),
+    Decimal(Decimal),
 }
 
 impl PartialEq for Number {
     fn eq(&self, other: &Self) -> bool {
-        match self.ordered_tuple(other) {
-            (Number::I64(i1), Number::I64(i2)) => i1 == i2,
-            (Number::U64(u1), Number::I64(i2)) if *i2 >= 0 => *u1 == *i2 as u64,
-            (Number::U64(u1), Number::U64(u2)) => u1 == u2,
-            (Number::I128(i1), Number::I64(i2)) => *i1 == *i2 as i128,
-            (Number::I128(i1), Number::U64(u2)) => *i1 == *u2 as i128,
-            (Number::I128(i1), Number::I128(i2)) => i1 == i2,
-            (Number::U128(u1), Number::I64(i2)) if *i2 >= 0 => *u1 == *i2 as u128,
-            (Number::U128(u1), Number::U64(u2)) => *u1 == *u2 as u128,
-            (Number::U128(u1), Number::I128(i2)) if *i2 >= 0 => *u1 == *i2 as u128,
-            (Number::U128(u1), Number::U128(u2)) => u1 == u2,
-            (Number::F64(f1), n2) => f64::abs(f1 - n2.as_f64()) < f64::EPSILON,
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+/// Numbers are compared by their exact values regardless of the variant, floats are compared
+/// by their exact binary value, i.e. `0.1f64` is greater than decimal `0.1`.
+/// `NaN` is not comparable
+impl PartialOrd for Number {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.exact(), other.exact()) {
+            (Exact::Integer(n1, m1), Exact::Integer(n2, m2)) => {
+                Some(cmp_sign_magnitude((n1, m1), (n2, m2)))
+            }
+            (Exact::Decimal(d1), Exact::Decimal(d2)) => Some(d1.cmp(&d2)),
+            (Exact::Integer(negative, magnitude), Exact::Decimal(d)) => {
+                Some(cmp_integer_decimal(negative, magnitude, &d))
+            }
+            (Exact::Decimal(d), Exact::Integer(negative, magnitude)) => {
+                Some(cmp_integer_decimal(negative, magnitude, &d).reverse())
+            }
+            (Exact::Float(f1), Exact::Float(f2)) => f1.partial_cmp(&f2),
+            (Exact::Float(f), _) => cmp_float(f),
+            (_, Exact::Float(f)) => cmp_float(f).map(Ordering::reverse),
+            (Exact::Binary(b1), Exact::Binary(b2)) => b1.value.partial_cmp(&b2.value),
+            (Exact::Binary(b), other) => Some(b.cmp_exact(&other)),
+            (other, Exact::Binary(b)) => Some(b.cmp_exact(&other).reverse()),
+        }
+    }
+}
+
+/// Exact value of a number
+#[derive(Debug, Clone, Copy)]
+enum Exact {
+    /// Sign and magnitude of integers and integral floats
+    Integer(bool, u128),
+    Decimal(Decimal),
+    /// Floats with non-zero fraction
+    Binary(Binary),
+    /// Non-finite floats and floats out of `u128` range
+    Float(f64),
+}
+
+/// Non-integral float `value`, ±`mantissa` × 2<sup>-shift</sup>, mantissa is odd and `shift` is positive
+#[derive(Debug, Clone, Copy)]
+struct Binary {
+    value: f64,
+    negative: bool,
+    mantissa: u64,
+    shift: u32,
+}
+
+impl Binary {
+    /// Compares with an integer or a decimal
+    fn cmp_exact(&self, other: &Exact) -> Ordering {
+        let (negative, is_zero, magnitude) = match other {
+            Exact::Integer(negative, magnitude) => (
+                *negative,
+                *magnitude == 0,
+                self.cmp_magnitude(*magnitude, 0),
+            ),
+            Exact::Decimal(d) => (
+                d.is_negative(),
+                d.mantissa() == 0,
+                self.cmp_magnitude(d.mantissa().unsigned_abs(), d.scale()),
+            ),
+            Exact::Binary(_) | Exact::Float(_) => unreachable!("compared as floats"),
+        };
+        if is_zero || negative != self.negative {
+            // non-integral float is never zero
+            if self.negative {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        } else if self.negative {
+            magnitude.reverse()
+        } else {
+            magnitude
+        }
+    }
 
-            _ => false,
+    /// Compares absolute value with `mantissa` × 10<sup>-scale</sup>, that is
+    /// `self.mantissa` × 10<sup>scale</sup> with `mantissa` × 2<sup>shift</sup>
+    fn cmp_magnitude(&self, mantissa: u128, scale: u32) -> Ordering {
+        let lhs = mul_wide(self.mantissa, 10u128.pow(scale));
+        match shl_wide(mantissa, self.shift) {
+            Some(rhs) => lhs.cmp(&rhs),
+            None => Ordering::Less,
         }
     }
 }
 
+/// 256 bit product as `(high, low)` halves
+fn mul_wide(lhs: u64, rhs: u128) -> (u128, u128) {
+    let low = lhs as u128 * (rhs as u64 as u128);
+    let high = lhs as u128 * (rhs >> 64);
+    let (low, carry) = low.overflowing_add(high << 64);
+    ((high >> 64) + carry as u128, low)
+}
+
+/// 256 bit `value` × 2<sup>shift</sup> as `(high, low)` halves, `None` if it doesn't fit
+fn shl_wide(value: u128, shift: u32) -> Option<(u128, u128)> {
+    if value == 0 {
+        Some((0, 0))
+    } else if 128 - value.leading_zeros() + shift > 256 {
+        None
+    } else if shift >= 128 {
+        Some((value << (shift - 128), 0))
+    } else if shift == 0 {
+        Some((0, value))
+    } else {
+        Some((value >> (128 - shift), value << shift))
+    }
+}
+
+fn cmp_sign_magnitude(lhs: (bool, u128), rhs: (bool, u128)) -> Ordering {
+    // negative zero is equal to zero
+    let signum = |(negative, magnitude): (bool, u128)| match (negative, magnitude) {
+        (_, 0) => 0,
+        (true, _) => -1,
+        (false, _) => 1,
+    };
+    match signum(lhs).cmp(&signum(rhs)) {
+        Ordering::Equal if signum(lhs) < 0 => rhs.1.cmp(&lhs.1),
+        Ordering::Equal => lhs.1.cmp(&rhs.1),
+        ordering => ordering,
+    }
+}
+
+fn cmp_integer_decimal(negative: bool, magnitude: u128, d: &Decimal) -> Ordering {
+    match integer_to_decimal(negative, magnitude) {
+        Some(integer) => integer.cmp(d),
+        // decimals can't hold values out of `i128` range
+        None if negative => Ordering::Less,
+        None => Ordering::Greater,
+    }
+}
+
+/// Compares float which is out of `u128` range with any other number except floats
+fn cmp_float(f: f64) -> Option<Ordering> {
+    if f.is_nan() {
+        None
+    } else if f > 0.0 {
+        Some(Ordering::Greater)
+    } else {
+        Some(Ordering::Less)
+    }
+}
+
+fn integer_to_decimal(negative: bool, magnitude: u128) -> Option<Decimal> {
+    let mantissa = if negative {
+        0i128.checked_sub_unsigned(magnitude)?
+    } else {
+        i128::try_from(magnitude).ok()?
+    };
+    Some(Decimal::new(mantissa, 0))
+}
+
+/// Decomposes finite float into odd mantissa (or zero) and binary exponent
+fn exact_float(n: f64) -> Exact {
+    let bits = n.to_bits();
+    let negative = bits >> 63 == 1;
+    let exponent_bits = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    let (mantissa, exponent) = if exponent_bits == 0 {
+        // subnormal
+        (fraction, -1074)
+    } else {
+        (fraction | 1 << 52, exponent_bits - 1075)
+    };
+    if mantissa == 0 {
+        return Exact::Integer(negative, 0);
+    }
+    let zeros = mantissa.trailing_zeros();
+    let (mantissa, exponent) = (mantissa >> zeros, exponent + zeros as i32);
+
+    if exponent < 0 {
+        Exact::Binary(Binary {
+            value: n,
+            negative,
+            mantissa,
+            shift: exponent.unsigned_abs(),
+        })
+    } else if (mantissa as u128).leading_zeros() >= exponent as u32 {
+        Exact::Integer(negative, (mantissa as u128) << exponent)
+    } else {
+        Exact::Float(n)
+    }
+}
+
 impl Number {
-    fn ord(&self) -> usize {
-        match self {
-            Number::I64(_) => 0,
-            Number::U64(_) => 1,
-            Number::I128(_) => 2,
-            Number::U128(_) => 3,
-            Number::F64(_) => 4,
+    fn exact(&self) -> Exact {
+        match *self {
+            Number::I64(n) => Exact::Integer(n < 0, n.unsigned_abs() as u128),
+            Number::U64(n) => Exact::Integer(false, n as u128),
+            Number::I128(n) => Exact::Integer(n < 0, n.unsigned_abs()),
+            Number::U128(n) => Exact::Integer(false, n),
+            Number::Decimal(d) => Exact::Decimal(d),
+            Number::F64(n) if n.is_finite() => exact_float(n),
+            Number::F64(n) => Exact::Float(n),
         }
     }
 
-    fn ordered_tuple<'a>(&'a self, other: &'a Self) -> (&'a Self, &'a Self) {
-        if self.ord() > other.ord() {
-            (self, other)
-        } else {
-            (other, self)
+    /// Decimal value as the number is displayed, floats use their shortest decimal representation.
+    /// `None` for non-finite floats and numbers out of decimal range
+    pub fn to_decimal(&self) -> Option<Decimal> {
+        match *self {
+            Number::I64(n) => Some(Decimal::new(n as i128, 0)),
+            Number::U64(n) => Some(Decimal::new(n as i128, 0)),
+            Number::I128(n) => Some(Decimal::new(n, 0)),
+            Number::U128(n) => i128::try_from(n).ok().map(|n| Decimal::new(n, 0)),
+            Number::F64(n) => Decimal::from_f64(n),
+            Number::Decimal(d) => Some(d),
         }
     }
 
@@ -58,16 +245,153 @@ impl Number {
             Number::I128(v) => *v as f64,
             Number::U128(v) => *v as f64,
             Number::F64(v) => *v,
+            Number::Decimal(d) => d.as_f64(),
         }
     }
 
     pub fn as_string(&self) -> String {
+        self.to_string()
+    }
+
+    /// Plural operands of the exact number value, floats use the shortest decimal representation
+    pub fn plural_operands(&self) -> PluralOperands {
         match self {
-            Number::I64(n) => n.to_string(),
-            Number::U64(n) => n.to_string(),
-            Number::I128(n) => n.to_string(),
-            Number::U128(n) => n.to_string(),
-            Number::F64(n) => n.to_string(),
+            Number::I64(n) => integer_operands(n.unsigned_abs() as u128),
+            Number::U64(n) => integer_operands(*n as u128),
+            Number::I128(n) => integer_operands(n.unsigned_abs()),
+            Number::U128(n) => integer_operands(*n),
+            Number::Decimal(d) => decimal_operands(d),
+            Number::F64(n) => match Decimal::from_f64(*n) {
+                Some(d) => decimal_operands(&d),
+                None => PluralOperands {
+                    n: n.abs(),
+                    i: 0,
+                    v: 0,
+                    w: 0,
+                    f: 0,
+                    t: 0,
+                },
+            },
+        }
+    }
+
+    /// Whether the number selects a numeric variant key, floats are matched by their
+    /// shortest decimal representation, i.e. `0.1f64` selects `[0.1]` variant
+    pub fn matches_key(&self, key: &Number) -> bool {
+        match (self.to_decimal(), key.to_decimal()) {
+            (Some(value), Some(key)) => value == key,
+            _ => self == key,
+        }
+    }
+
+    /// Plural operands of the number as it is displayed with the format digit options applied,
+    /// i.e. `1` formatted with 2 minimum fraction digits is `1.00` and selects `other` in English
+    pub fn formatted_plural_operands(&self, format: Option<&NumberFormat>) -> PluralOperands {
+        format
+            .and_then(|format| {
+                self.to_decimal()
+                    .and_then(|d| apply_digit_options(d, format))
+            })
+            .map(|d| decimal_operands(&d))
+            .unwrap_or_else(|| self.plural_operands())
+    }
+}
+
+/// Same digit defaults as in ECMA-402 `Intl.NumberFormat`, but rounding defaults to half-to-even
+/// as in ICU rather than to half-expand of `Intl.NumberFormat`, see [`NumberFormat::rounding_mode`]
+fn apply_digit_options(value: Decimal, format: &NumberFormat) -> Option<Decimal> {
+    let value = if format.style.is_percent() {
+        value.mul_pow10(2)?
+    } else {
+        value
+    };
+    let (default_min_fraction, default_max_fraction) = format.style.default_fraction_digits();
+
+    let mode = format.rounding_mode;
+    let strip_zeros = |value: Decimal| {
+        format.trailing_zero_display == TrailingZeroDisplay::StripIfInteger && value.is_integer()
+    };
+    if format.minimum_significant_digits.is_some() || format.maximum_significant_digits.is_some() {
+        let min = format.minimum_significant_digits.unwrap_or(1) as u32;
+        let max = (format.maximum_significant_digits.unwrap_or(21) as u32).max(min);
+        let value = value.round_significant_with(max, mode)?.normalize();
+        if strip_zeros(value) {
+            return Some(value);
+        }
+        let padding = min.saturating_sub(value.precision());
+        value.pad(value.scale() + padding)
+    } else {
+        let (min, max) = match (
+            format.minimum_fraction_digits,
+            format.maximum_fraction_digits,
+        ) {
+            (Some(min), Some(max)) => (min, max.max(min)),
+            (Some(min), None) => (min, default_max_fraction.max(min)),
+            (None, Some(max)) => (default_min_fraction.min(max), max),
+            (None, None) => (default_min_fraction, default_max_fraction),
+        };
+        let increment = format.rounding_increment as u32;
+        let value = value
+            .round_increment_with(increment, max as u32, mode)?
+            .normalize();
+        if strip_zeros(value) {
+            Some(value)
+        } else {
+            value.pad(min as u32)
+        }
+    }
+}
+
+fn integer_operands(n: u128) -> PluralOperands {
+    PluralOperands {
+        n: n as f64,
+        i: operand_u64(n),
+        v: 0,
+        w: 0,
+        f: 0,
+        t: 0,
+    }
+}
+
+fn decimal_operands(d: &Decimal) -> PluralOperands {
+    let f = d.fraction();
+    let mut t = f;
+    let mut w = d.scale();
+    while t != 0 && t.is_multiple_of(10) {
+        t /= 10;
+        w -= 1;
+    }
+    if t == 0 {
+        w = 0;
+    }
+    PluralOperands {
+        n: d.as_f64().abs(),
+        i: operand_u64(d.trunc().unsigned_abs()),
+        v: d.scale() as usize,
+        w: w as usize,
+        f: operand_u64(f),
+        t: operand_u64(t),
+    }
+}
+
+/// Plural rules only check operands equality and remainders of division by powers of 10,
+/// so values too large for `u64` are replaced with a non-zero value with the same remainders
+fn operand_u64(n: u128) -> u64 {
+    const MODULUS: u128 = 10u128.pow(18);
+    u64::try_from(n).unwrap_or((n % MODULUS + MODULUS) as u64)
+}
+
+/// Floats are displayed without exponent, so that the output can be parsed back
+/// to an equal number, negative zero is displayed as `-0`
+impl Display for Number {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Number::I64(n) => n.fmt(f),
+            Number::U64(n) => n.fmt(f),
+            Number::I128(n) => n.fmt(f),
+            Number::U128(n) => n.fmt(f),
+            Number::F64(n) => n.fmt(f),
+            Number::Decimal(d) => d.fmt(f),
         }
     }
 }
@@ -76,8 +400,23 @@ impl FromStr for Number {
     type Err = std::num::ParseFloatError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.contains('.') {
-            // If the string contains a decimal point, parse as f64
+        let number = Self::parse(s)?;
+        if s.starts_with('-') && !matches!(number, Number::F64(_)) && number == Number::I64(0) {
+            // only floats can hold negative zero
+            Ok(Number::F64(-0.0))
+        } else {
+            Ok(number)
+        }
+    }
+}
+
+impl Number {
+    fn parse(s: &str) -> Result<Self, std::num::ParseFloatError> {
+        if s.contains(['.', 'e', 'E']) {
+            // If the string contains a decimal point or exponent, parse as exact decimal if possible
+            if let Ok(d) = s.parse::<Decimal>() {
+                return Ok(Number::Decimal(d));
+            }
             match s.parse::<f64>() {
                 Ok(f) => Ok(Number::F64(f)),
                 Err(e) => Err(e),
@@ -145,6 +484,65 @@ macro_rules! impl_from_for_number {
     };
 }
 
+impl From<Decimal> for Number {
+    fn from(value: Decimal) -> Self {
+        Number::Decimal(value)
+    }
+}
+
+impl From<Decimal> for Value<'_> {
+    fn from(value: Decimal) -> Self {
+        Number::Decimal(value).into()
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl From<rust_decimal::Decimal> for Number {
+    fn from(value: rust_decimal::Decimal) -> Self {
+        Number::Decimal(value.into())
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl From<rust_decimal::Decimal> for Value<'_> {
+    fn from(value: rust_decimal::Decimal) -> Self {
+        Number::from(value).into()
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl From<&rust_decimal::Decimal> for Value<'_> {
+    fn from(value: &rust_decimal::Decimal) -> Self {
+        Number::from(*value).into()
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl TryFrom<&bigdecimal::BigDecimal> for Number {
+    type Error = decimal::DecimalError;
+
+    fn try_from(value: &bigdecimal::BigDecimal) -> Result<Self, Self::Error> {
+        Decimal::try_from(value).map(Number::Decimal)
+    }
+}
+
+/// Values out of [`Decimal`] range are converted to [`Value::Error`]
+#[cfg(feature = "bigdecimal")]
+impl From<&bigdecimal::BigDecimal> for Value<'_> {
+    fn from(value: &bigdecimal::BigDecimal) -> Self {
+        Number::try_from(value)
+            .map(Value::from)
+            .unwrap_or(Value::Error)
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl From<bigdecimal::BigDecimal> for Value<'_> {
+    fn from(value: bigdecimal::BigDecimal) -> Self {
+        Value::from(&value)
+    }
+}
+
 impl_from_for_number! {
     i8 => I64,
     i16 => I64,
@@ -164,7 +562,106 @@ impl_from_for_number! {
 
 #[cfg(test)]
 mod test {
-    use super::Number;
+    use std::str::FromStr;
+
+    use super::{format, Decimal, Number, NumberFormat, RoundingMode, TryFromNumberError};
+
+    fn decimal(s: &str) -> Number {
+        Number::Decimal(s.parse().unwrap())
+    }
+
+    /// Every number is equal to the others in its group and less than numbers in the next groups
+    fn ordered_groups() -> Vec<Vec<Number>> {
+        let i128_max_plus_one = i128::MAX as u128 + 1;
+        vec![
+            vec![Number::F64(f64::NEG_INFINITY)],
+            vec![Number::F64(-1e300)],
+            vec![
+                Number::I128(i128::MIN),
+                Number::Decimal(Decimal::new(i128::MIN, 0)),
+            ],
+            vec![Number::I128(-9223372036854776000)],
+            vec![
+                Number::I64(i64::MIN),
+                Number::I128(i64::MIN as i128),
+                Number::Decimal(Decimal::new(i64::MIN as i128, 0)),
+                Number::F64(i64::MIN as f64),
+            ],
+            vec![decimal("-1.5"), decimal("-1.50"), Number::F64(-1.5)],
+            vec![
+                Number::I64(0),
+                Number::U64(0),
+                Number::I128(0),
+                Number::U128(0),
+                decimal("0.00"),
+                decimal("-0.0"),
+                Number::F64(0.0),
+                Number::F64(-0.0),
+            ],
+            vec![Number::F64(f64::from_bits(1))],
+            vec![Number::F64(1e-40)],
+            vec![decimal("0.1")],
+            vec![decimal("0.1000000000000000055511151231257827")],
+            // 0.1000000000000000055511151231257827021181583404541015625
+            vec![Number::F64(0.1)],
+            vec![decimal("0.1000000000000000055511151231257828")],
+            vec![decimal("0.3")],
+            vec![decimal("0.30000000000000004")],
+            vec![Number::F64(0.1 + 0.2)],
+            vec![decimal("0.5"), Number::F64(0.5)],
+            vec![
+                Number::I64(42),
+                Number::U64(42),
+                Number::I128(42),
+                Number::U128(42),
+                decimal("42.00"),
+                Number::F64(42.0),
+            ],
+            vec![
+                Number::U64(u64::MAX),
+                Number::I128(u64::MAX as i128),
+                Number::U128(u64::MAX as u128),
+                Number::Decimal(Decimal::new(u64::MAX as i128, 0)),
+            ],
+            vec![Number::U128(170141183460469230000000000000000000000)],
+            vec![
+                Number::I128(i128::MAX),
+                Number::U128(i128::MAX as u128),
+                Number::Decimal(Decimal::new(i128::MAX, 0)),
+            ],
+            vec![
+                Number::U128(i128_max_plus_one),
+                Number::F64(i128_max_plus_one as f64),
+            ],
+            vec![Number::F64(2f64.powi(128) - 2f64.powi(75))],
+            vec![Number::U128(u128::MAX)],
+            vec![Number::F64(2f64.powi(128))],
+            vec![Number::F64(1e39)],
+            vec![Number::F64(f64::INFINITY)],
+        ]
+    }
+
+    #[test]
+    fn test_cross_variant_ordering() {
+        let groups = ordered_groups();
+        for (i, group) in groups.iter().enumerate() {
+            for (j, other_group) in groups.iter().enumerate() {
+                for n1 in group {
+                    for n2 in other_group {
+                        assert_eq!(Some(i.cmp(&j)), n1.partial_cmp(n2), "{:?} {:?}", n1, n2);
+                        assert_eq!(i == j, n1 == n2, "{:?} {:?}", n1, n2);
+                    }
+                }
+            }
+        }
+
+        let nan = Number::F64(f64::NAN);
+        for n in groups.iter().flatten() {
+            assert_eq!(None, nan.partial_cmp(n), "{:?}", n);
+            assert_ne!(nan, *n);
+        }
+        assert_ne!(nan, nan);
+    }
 
     #[test]
     fn test_number_equality() {
@@ -172,4 +669,489 @@ mod test {
         let n2 = Number::F64(0f64);
         assert_eq!(n1, n2);
     }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        #[derive(Debug)]
+        enum Op {
+            Add,
+            Sub,
+            Mul,
+            Div,
+        }
+        let test_data: Vec<(Number, Op, Number, Option<Number>)> = vec![
+            (
+                Number::I64(i64::MAX),
+                Op::Add,
+                Number::I64(1),
+                Some(Number::U64(i64::MAX as u64 + 1)),
+            ),
+            (
+                Number::I64(i64::MIN),
+                Op::Sub,
+                Number::U64(1),
+                Some(Number::I128(i64::MIN as i128 - 1)),
+            ),
+            (
+                Number::U64(3),
+                Op::Sub,
+                Number::U128(5),
+                Some(Number::I64(-2)),
+            ),
+            (Number::U128(u128::MAX), Op::Add, Number::I64(1), None),
+            (
+                Number::U128(u128::MAX),
+                Op::Add,
+                Number::I64(-1),
+                Some(Number::U128(u128::MAX - 1)),
+            ),
+            (Number::I64(2), Op::Sub, Number::U128(u128::MAX), None),
+            (
+                Number::I128(i128::MIN),
+                Op::Add,
+                Number::U128(u128::MAX),
+                Some(Number::I128(i128::MAX)),
+            ),
+            (
+                Number::I64(-3),
+                Op::Mul,
+                Number::U64(4),
+                Some(Number::I64(-12)),
+            ),
+            (
+                Number::U64(u64::MAX),
+                Op::Mul,
+                Number::U64(u64::MAX),
+                Some(Number::U128(u64::MAX as u128 * u64::MAX as u128)),
+            ),
+            (Number::U128(u128::MAX), Op::Mul, Number::I64(2), None),
+            (
+                Number::I64(6),
+                Op::Div,
+                Number::U64(3),
+                Some(Number::I64(2)),
+            ),
+            (
+                Number::I64(-7),
+                Op::Div,
+                Number::I128(2),
+                Some(decimal("-3.5")),
+            ),
+            (Number::I64(1), Op::Div, Number::I64(0), None),
+            (
+                Number::U128(u128::MAX),
+                Op::Div,
+                Number::U128(u128::MAX),
+                Some(Number::I64(1)),
+            ),
+            (Number::U128(u128::MAX), Op::Div, Number::I64(2), None),
+            (
+                decimal("0.1"),
+                Op::Add,
+                decimal("0.2"),
+                Some(decimal("0.3")),
+            ),
+            (
+                Number::I64(1),
+                Op::Sub,
+                decimal("0.25"),
+                Some(decimal("0.75")),
+            ),
+            (
+                decimal("1.5"),
+                Op::Mul,
+                Number::U64(3),
+                Some(decimal("4.5")),
+            ),
+            (
+                decimal("1"),
+                Op::Div,
+                decimal("3"),
+                Some(decimal("0.33333333333333333333333333333333333333")),
+            ),
+            (decimal("0.5"), Op::Add, Number::U128(u128::MAX), None),
+            (
+                Number::Decimal(Decimal::new(1, 20)),
+                Op::Mul,
+                Number::Decimal(Decimal::new(1, 20)),
+                None,
+            ),
+            (
+                decimal("0.1"),
+                Op::Add,
+                Number::F64(0.2),
+                Some(Number::F64(0.1 + 0.2)),
+            ),
+            (
+                Number::F64(1.5),
+                Op::Mul,
+                Number::I64(2),
+                Some(Number::F64(3.0)),
+            ),
+            (Number::F64(f64::MAX), Op::Mul, Number::I64(2), None),
+            (Number::F64(1.0), Op::Div, Number::I64(0), None),
+            (Number::F64(f64::NAN), Op::Add, Number::I64(0), None),
+        ];
+        for (lhs, op, rhs, expected) in test_data {
+            let result = match op {
+                Op::Add => lhs.checked_add(&rhs),
+                Op::Sub => lhs.checked_sub(&rhs),
+                Op::Mul => lhs.checked_mul(&rhs),
+                Op::Div => lhs.checked_div(&rhs),
+            };
+            let message = format!("{:?} {:?} {:?} = {:?}", lhs, op, rhs, result);
+            match (result, expected) {
+                (Some(result), Some(expected)) => {
+                    assert_eq!(expected, result, "{}", message);
+                    assert_eq!(
+                        std::mem::discriminant(&expected),
+                        std::mem::discriminant(&result),
+                        "{}",
+                        message
+                    );
+                }
+                (result, expected) => {
+                    assert_eq!(expected.is_none(), result.is_none(), "{}", message)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_neg_abs() {
+        assert!(
+            matches!(Number::I64(i64::MIN).checked_neg(), Some(Number::U64(n)) if n == 1 << 63)
+        );
+        assert!(
+            matches!(Number::I128(i128::MIN).checked_neg(), Some(Number::U128(n)) if n == 1 << 127)
+        );
+        assert!(matches!(
+            Number::U64(5).checked_neg(),
+            Some(Number::I64(-5))
+        ));
+        assert!(Number::U128(u128::MAX).checked_neg().is_none());
+        assert_eq!(Some(decimal("1.50")), decimal("-1.50").checked_neg());
+        assert!(Number::F64(0.0)
+            .checked_neg()
+            .unwrap()
+            .as_f64()
+            .is_sign_negative());
+
+        assert!(matches!(Number::I64(i64::MIN).abs(), Number::U64(n) if n == 1 << 63));
+        assert_eq!(decimal("0.5"), decimal("-0.5").abs());
+        assert_eq!(Number::F64(2.5), Number::F64(-2.5).abs());
+        assert_eq!(Number::U128(u128::MAX), Number::U128(u128::MAX).abs());
+    }
+
+    #[test]
+    fn test_round() {
+        let test_data = vec![
+            (Number::F64(1.005), 2, RoundingMode::HalfExpand, "1.01"),
+            (Number::F64(1.005), 2, RoundingMode::HalfEven, "1"),
+            (Number::F64(-0.4), 0, RoundingMode::HalfEven, "-0"),
+            (decimal("-2.5"), 0, RoundingMode::Floor, "-3"),
+            (decimal("-2.5"), 0, RoundingMode::Ceil, "-2"),
+            (decimal("2.50"), 1, RoundingMode::Trunc, "2.5"),
+            (Number::I64(5), 0, RoundingMode::Expand, "5"),
+            (Number::F64(f64::INFINITY), 0, RoundingMode::HalfEven, "inf"),
+        ];
+        for (n, scale, mode, expected) in test_data {
+            assert_eq!(
+                expected,
+                n.round(scale, mode).to_string(),
+                "{:?} {} {:?}",
+                n,
+                scale,
+                mode
+            );
+        }
+    }
+
+    #[test]
+    fn test_try_from_number() {
+        assert_eq!(Ok(255u8), u8::try_from(Number::I64(255)));
+        assert_eq!(
+            Err(TryFromNumberError::OutOfRange),
+            u8::try_from(Number::I64(256))
+        );
+        assert_eq!(
+            Err(TryFromNumberError::OutOfRange),
+            u64::try_from(Number::I64(-1))
+        );
+        assert_eq!(Ok(i8::MIN), i8::try_from(Number::I128(-128)));
+        assert_eq!(Ok(42i64), i64::try_from(decimal("42.00")));
+        assert_eq!(Ok(-42i32), i32::try_from(&Number::F64(-42.0)));
+        assert_eq!(Ok(0i8), i8::try_from(Number::F64(-0.0)));
+        assert_eq!(
+            Ok(i128::MAX as u128 + 1),
+            u128::try_from(Number::F64(2f64.powi(127)))
+        );
+        assert_eq!(Ok(i64::MIN), i64::try_from(Number::F64(i64::MIN as f64)));
+        assert_eq!(
+            Ok(u128::MAX - (1 << 75) + 1),
+            u128::try_from(Number::F64(2f64.powi(128) - 2f64.powi(75)))
+        );
+        assert_eq!(
+            Err(TryFromNumberError::OutOfRange),
+            u128::try_from(Number::F64(2f64.powi(128)))
+        );
+        assert_eq!(
+            Err(TryFromNumberError::NotAnInteger),
+            i64::try_from(Number::F64(0.5))
+        );
+        assert_eq!(Ok(u128::MAX), u128::try_from(Number::U128(u128::MAX)));
+        assert_eq!(
+            Err(TryFromNumberError::OutOfRange),
+            i128::try_from(Number::U128(u128::MAX))
+        );
+        assert_eq!(
+            Ok(i128::MIN),
+            i128::try_from(Number::Decimal(Decimal::new(i128::MIN, 0)))
+        );
+        assert_eq!(
+            Err(TryFromNumberError::NotAnInteger),
+            i64::try_from(decimal("0.5"))
+        );
+        assert_eq!(
+            Err(TryFromNumberError::NotAnInteger),
+            i64::try_from(Number::F64(1e-40))
+        );
+        assert_eq!(
+            Err(TryFromNumberError::NotAnInteger),
+            i64::try_from(Number::F64(f64::NAN))
+        );
+        assert_eq!(
+            Err(TryFromNumberError::OutOfRange),
+            u128::try_from(Number::F64(f64::INFINITY))
+        );
+        assert_eq!(
+            Err(TryFromNumberError::OutOfRange),
+            u128::try_from(Number::F64(1e300))
+        );
+        assert_eq!(0.5, f64::from(decimal("0.50")));
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let mut numbers: Vec<Number> = ordered_groups().into_iter().flatten().collect();
+        numbers.extend([
+            Number::F64(f64::MAX),
+            Number::F64(f64::MIN_POSITIVE),
+            Number::F64(-1e-300),
+            Number::F64(123.456),
+            decimal("-0.050"),
+        ]);
+        for n in numbers {
+            let s = n.to_string();
+            let parsed = Number::from_str(&s).unwrap();
+            if let Number::F64(f) = n {
+                // shortest representation is parsed back into the same float,
+                // but not necessarily into a float variant
+                assert_eq!(f, parsed.as_f64(), "{:?} '{}' {:?}", n, s, parsed);
+                assert_eq!(
+                    f.is_sign_negative(),
+                    parsed.as_f64().is_sign_negative(),
+                    "{}",
+                    s
+                );
+            } else {
+                assert_eq!(n, parsed, "{:?} '{}' {:?}", n, s, parsed);
+            }
+        }
+        assert!(Number::from_str(&Number::F64(f64::NAN).to_string())
+            .unwrap()
+            .as_f64()
+            .is_nan());
+
+        assert_eq!("-0", Number::F64(-0.0).to_string());
+        assert_eq!("-0.050", decimal("-0.050").to_string());
+        for s in ["-0", "-0.00", "-0e5"] {
+            assert!(
+                matches!(Number::from_str(s), Ok(Number::F64(f)) if f == 0.0 && f.is_sign_negative()),
+                "{}",
+                s
+            );
+        }
+        let test_data = vec![
+            ("1e3", Number::I64(1000)),
+            ("1.5E-3", decimal("0.0015")),
+            ("-2.5e1", Number::I64(-25)),
+            ("1e400", Number::F64(f64::INFINITY)),
+            ("1e-400", Number::F64(0.0)),
+        ];
+        for (s, expected) in test_data {
+            assert_eq!(expected, Number::from_str(s).unwrap(), "{}", s);
+        }
+        assert!(Number::from_str("1e").is_err());
+        assert!(Number::from_str("abc").is_err());
+    }
+
+    #[test]
+    fn test_decimal_equality() {
+        let price = Number::from_str("19.99").unwrap();
+        assert!(matches!(price, Number::Decimal(_)));
+        // floats are compared by exact binary value, but match keys by displayed value
+        assert_ne!(price, Number::F64(19.99));
+        assert!(Number::F64(19.99).matches_key(&price));
+        assert!(!Number::F64(0.1 + 0.2).matches_key(&Number::from_str("0.3").unwrap()));
+        assert!(Number::U128(u128::MAX).matches_key(&Number::U128(u128::MAX)));
+        assert_eq!(Number::from_str("0.25").unwrap(), Number::F64(0.25));
+        assert_eq!(Number::from_str("20.00").unwrap(), Number::I64(20));
+        assert_ne!(Number::F64(0.1 + 0.2), Number::from_str("0.3").unwrap());
+        assert_ne!(Number::from_str("-1.0").unwrap(), Number::U64(1));
+        assert_ne!(
+            Number::Decimal(Decimal::new(i128::MAX, 0)),
+            Number::U128(i128::MAX as u128 + 1)
+        );
+    }
+
+    #[test]
+    fn test_formatted_plural_operands() {
+        let fraction_digits = |min, max| NumberFormat {
+            minimum_fraction_digits: min,
+            maximum_fraction_digits: max,
+            ..Default::default()
+        };
+        let significant_digits = |min, max| NumberFormat {
+            minimum_significant_digits: min,
+            maximum_significant_digits: max,
+            ..Default::default()
+        };
+
+        // (i, v, w, f, t)
+        type Operands = (u64, usize, usize, u64, u64);
+        let test_data: Vec<(Number, Option<NumberFormat>, Operands)> = vec![
+            (Number::I64(1), None, (1, 0, 0, 0, 0)),
+            (
+                Number::I64(1),
+                Some(fraction_digits(Some(2), None)),
+                (1, 2, 0, 0, 0),
+            ),
+            (
+                Number::U64(1),
+                Some(fraction_digits(Some(2), None)),
+                (1, 2, 0, 0, 0),
+            ),
+            (
+                Number::I128(-1),
+                Some(fraction_digits(Some(1), None)),
+                (1, 1, 0, 0, 0),
+            ),
+            (
+                Number::U128(1),
+                Some(NumberFormat::default()),
+                (1, 0, 0, 0, 0),
+            ),
+            (
+                Number::F64(1.2345),
+                Some(NumberFormat::default()),
+                (1, 3, 3, 234, 234),
+            ),
+            (
+                Number::F64(0.9999),
+                Some(NumberFormat::default()),
+                (1, 0, 0, 0, 0),
+            ),
+            (
+                Number::from_str("1.50").unwrap(),
+                Some(NumberFormat::default()),
+                (1, 1, 1, 5, 5),
+            ),
+            (
+                Number::F64(1.5),
+                Some(fraction_digits(None, Some(0))),
+                (2, 0, 0, 0, 0),
+            ),
+            (
+                Number::I64(1234),
+                Some(significant_digits(None, Some(2))),
+                (1200, 0, 0, 0, 0),
+            ),
+            (
+                Number::I64(1),
+                Some(significant_digits(Some(3), None)),
+                (1, 2, 0, 0, 0),
+            ),
+            (
+                Number::F64(0.01),
+                Some(NumberFormat::percent()),
+                (1, 0, 0, 0, 0),
+            ),
+            (
+                Number::I64(1),
+                Some(NumberFormat::currency(format::CurrencyCode::USD)),
+                (1, 2, 0, 0, 0),
+            ),
+            (
+                Number::I64(1),
+                Some(NumberFormat {
+                    trailing_zero_display: format::TrailingZeroDisplay::StripIfInteger,
+                    ..NumberFormat::currency(format::CurrencyCode::USD)
+                }),
+                (1, 0, 0, 0, 0),
+            ),
+            (
+                Number::F64(1.5),
+                Some(NumberFormat {
+                    rounding_mode: RoundingMode::Floor,
+                    ..fraction_digits(None, Some(0))
+                }),
+                (1, 0, 0, 0, 0),
+            ),
+            (
+                Number::F64(1.23),
+                Some(NumberFormat {
+                    rounding_increment: 5,
+                    ..fraction_digits(Some(2), Some(2))
+                }),
+                (1, 2, 2, 25, 25),
+            ),
+            (
+                Number::U128(u128::MAX),
+                Some(fraction_digits(Some(2), None)),
+                (
+                    (u128::MAX % 10u128.pow(18) + 10u128.pow(18)) as u64,
+                    0,
+                    0,
+                    0,
+                    0,
+                ),
+            ),
+        ];
+        for (number, format, expected) in test_data {
+            let operands = number.formatted_plural_operands(format.as_ref());
+            assert_eq!(
+                expected,
+                (operands.i, operands.v, operands.w, operands.f, operands.t),
+                "{:?} {:?}",
+                number,
+                format
+            );
+        }
+    }
+
+    #[test]
+    fn test_plural_operands() {
+        // (i, v, w, f, t)
+        type Operands = (u64, usize, usize, u64, u64);
+        let test_data: Vec<(Number, Operands)> = vec![
+            (Number::I64(-5), (5, 0, 0, 0, 0)),
+            (Number::from_str("1.50").unwrap(), (1, 2, 1, 50, 5)),
+            (Number::from_str("1.0").unwrap(), (1, 1, 0, 0, 0)),
+            (Number::F64(0.25), (0, 2, 2, 25, 25)),
+            (
+                Number::U128(10u128.pow(30) + 7),
+                (10u64.pow(18) + 7, 0, 0, 0, 0),
+            ),
+        ];
+        for (number, (i, v, w, f, t)) in test_data {
+            let operands = number.plural_operands();
+            assert_eq!(
+                (i, v, w, f, t),
+                (operands.i, operands.v, operands.w, operands.f, operands.t),
+                "{:?}",
+                number
+            );
+        }
+    }
 }
@@ -0,0 +1,755 @@
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
+
+/// Max number of fraction digits, so that `10^scale` fits into `i128`
+pub const MAX_SCALE: u32 = 38;
+
+/// Exact decimal number, `mantissa` × 10<sup>-scale</sup>.
+///
+/// Scale is preserved, i.e. `1.50` and `1.5` are equal but have different
+/// number of visible fraction digits
+#[derive(Debug, Clone, Copy)]
+pub struct Decimal {
+    mantissa: i128,
+    scale: u32,
+}
+
+/// Rounding modes as defined by ECMA-402 `roundingMode` option
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RoundingMode {
+    /// Towards positive infinity
+    Ceil,
+    /// Towards negative infinity
+    Floor,
+    /// Away from zero
+    Expand,
+    /// Towards zero
+    Trunc,
+    /// Ties towards positive infinity
+    HalfCeil,
+    /// Ties towards negative infinity
+    HalfFloor,
+    /// Ties away from zero
+    HalfExpand,
+    /// Ties towards zero
+    HalfTrunc,
+    /// Ties to the nearest even digit
+    #[default]
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// Whether the truncated value should be moved away from zero, `half` is
+    /// the dropped remainder compared to the half of the rounding unit
+    pub fn round_away(&self, negative: bool, inexact: bool, half: Ordering, odd: bool) -> bool {
+        let tie_away = match self {
+            RoundingMode::Ceil => return inexact && !negative,
+            RoundingMode::Floor => return inexact && negative,
+            RoundingMode::Expand => return inexact,
+            RoundingMode::Trunc => return false,
+            RoundingMode::HalfCeil => !negative,
+            RoundingMode::HalfFloor => negative,
+            RoundingMode::HalfExpand => true,
+            RoundingMode::HalfTrunc => false,
+            RoundingMode::HalfEven => odd,
+        };
+        half == Ordering::Greater || (half == Ordering::Equal && tie_away)
+    }
+}
+
+impl FromStr for RoundingMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ceil" => Ok(RoundingMode::Ceil),
+            "floor" => Ok(RoundingMode::Floor),
+            "expand" => Ok(RoundingMode::Expand),
+            "trunc" => Ok(RoundingMode::Trunc),
+            "halfCeil" => Ok(RoundingMode::HalfCeil),
+            "halfFloor" => Ok(RoundingMode::HalfFloor),
+            "halfExpand" => Ok(RoundingMode::HalfExpand),
+            "halfTrunc" => Ok(RoundingMode::HalfTrunc),
+            "halfEven" => Ok(RoundingMode::HalfEven),
+            _ => Err(format!("Unknown rounding mode: '{}'", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DecimalError {
+    #[error("Invalid decimal number: '{0}'")]
+    Invalid(String),
+    #[error("Decimal number is out of range")]
+    OutOfRange,
+}
+
+impl Decimal {
+    /// Panics if scale is greater than [`MAX_SCALE`]
+    pub const fn new(mantissa: i128, scale: u32) -> Self {
+        assert!(scale <= MAX_SCALE, "Decimal scale is out of range");
+        Self { mantissa, scale }
+    }
+
+    pub fn try_new(mantissa: i128, scale: u32) -> Result<Self, DecimalError> {
+        if scale <= MAX_SCALE {
+            Ok(Self { mantissa, scale })
+        } else {
+            Err(DecimalError::OutOfRange)
+        }
+    }
+
+    /// Returns shortest exact representation of the float, `None` for non-finite values
+    pub fn from_f64(value: f64) -> Option<Self> {
+        if value.is_finite() {
+            value.to_string().parse().ok()
+        } else {
+            None
+        }
+    }
+
+    pub fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.mantissa < 0
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.fraction() == 0
+    }
+
+    /// Integer part, truncated towards zero
+    pub fn trunc(&self) -> i128 {
+        self.mantissa / pow10(self.scale)
+    }
+
+    /// Fraction digits as integer, i.e. `42` for `-1.042`
+    pub fn fraction(&self) -> u128 {
+        (self.mantissa % pow10(self.scale)).unsigned_abs()
+    }
+
+    /// Removes trailing fraction zeros
+    pub fn normalize(&self) -> Self {
+        let mut result = *self;
+        while result.scale > 0 && result.mantissa % 10 == 0 {
+            result.mantissa /= 10;
+            result.scale -= 1;
+        }
+        result
+    }
+
+    /// Number of significant digits in the mantissa, `1` for zero
+    pub fn precision(&self) -> u32 {
+        self.mantissa.unsigned_abs().checked_ilog10().unwrap_or(0) + 1
+    }
+
+    /// Rounds half-to-even to at most `scale` fraction digits
+    pub fn round(&self, scale: u32) -> Self {
+        self.round_with(scale, RoundingMode::HalfEven)
+    }
+
+    /// Rounds to at most `scale` fraction digits
+    pub fn round_with(&self, scale: u32, mode: RoundingMode) -> Self {
+        self.round_at(scale as i64, mode)
+            .expect("rounding of fraction digits can't overflow")
+    }
+
+    /// Rounds half-to-even to at most `precision` significant digits, `None` on overflow
+    pub fn round_significant(&self, precision: u32) -> Option<Self> {
+        self.round_significant_with(precision, RoundingMode::HalfEven)
+    }
+
+    /// Rounds to at most `precision` significant digits, `None` on overflow,
+    /// i.e. if `i128::MAX` is rounded up to 10<sup>39</sup>
+    pub fn round_significant_with(&self, precision: u32, mode: RoundingMode) -> Option<Self> {
+        let drop = self.precision().saturating_sub(precision.max(1));
+        self.round_at(self.scale as i64 - drop as i64, mode)
+    }
+
+    /// Rounds to a multiple of `increment` × 10<sup>-scale</sup>, i.e. increment `5` with scale `2`
+    /// rounds to multiples of `0.05`. `None` on overflow
+    pub fn round_increment_with(
+        &self,
+        increment: u32,
+        scale: u32,
+        mode: RoundingMode,
+    ) -> Option<Self> {
+        if increment <= 1 {
+            return Some(self.round_with(scale, mode));
+        }
+        let unit = Self::try_new(increment as i128, scale).ok()?;
+        self.checked_div(&unit)?
+            .round_with(0, mode)
+            .checked_mul(&unit)?
+            .pad(scale)
+    }
+
+    /// Adds trailing zeros up to `scale` fraction digits, `None` on overflow
+    pub fn pad(&self, scale: u32) -> Option<Self> {
+        if scale <= self.scale {
+            return Some(*self);
+        }
+        let mantissa = 10i128
+            .checked_pow(scale - self.scale)
+            .and_then(|factor| self.mantissa.checked_mul(factor))?;
+        Self::try_new(mantissa, scale).ok()
+    }
+
+    /// Multiplies by 10<sup>exp</sup>, `None` on overflow
+    pub fn mul_pow10(&self, exp: u32) -> Option<Self> {
+        if exp <= self.scale {
+            Some(Self::new(self.mantissa, self.scale - exp))
+        } else {
+            10i128
+                .checked_pow(exp - self.scale)
+                .and_then(|factor| self.mantissa.checked_mul(factor))
+                .map(|mantissa| Self::new(mantissa, 0))
+        }
+    }
+
+    /// Divides by 10<sup>exp</sup>, `None` if the result needs more than [`MAX_SCALE`] fraction digits
+    pub fn div_pow10(&self, exp: u32) -> Option<Self> {
+        let normalized = self.normalize();
+        Self::try_new(normalized.mantissa, normalized.scale.checked_add(exp)?).ok()
+    }
+
+    /// Power of 10 of the most significant digit, i.e. `2` for `123.4` and `-2` for `0.05`,
+    /// `None` for zero
+    pub fn magnitude(&self) -> Option<i32> {
+        (self.mantissa != 0).then(|| self.precision() as i32 - 1 - self.scale as i32)
+    }
+
+    /// `None` on overflow or if the result needs more than [`MAX_SCALE`] fraction digits
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        let scale = self.scale.max(other.scale);
+        let mantissa = self
+            .pad(scale)?
+            .mantissa
+            .checked_add(other.pad(scale)?.mantissa)?;
+        Some(Self::new(mantissa, scale))
+    }
+
+    /// `None` on overflow or if the result needs more than [`MAX_SCALE`] fraction digits
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.checked_add(&other.checked_neg()?)
+    }
+
+    /// `None` on overflow or if the result needs more than [`MAX_SCALE`] fraction digits
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        let (lhs, rhs) = (self.normalize(), other.normalize());
+        let mantissa = lhs.mantissa.checked_mul(rhs.mantissa)?;
+        Self::try_new(mantissa, lhs.scale + rhs.scale).ok()
+    }
+
+    /// Exact quotient if it fits into [`MAX_SCALE`] fraction digits, otherwise rounded
+    /// half-to-even to as many fraction digits as possible.
+    /// `None` on division by zero or overflow
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        if other.mantissa == 0 {
+            return None;
+        }
+        let divisor = other.mantissa.unsigned_abs();
+        let mut quotient = self.mantissa.unsigned_abs() / divisor;
+        let mut remainder = self.mantissa.unsigned_abs() % divisor;
+        // result is quotient × 10^(other.scale - self.scale - digits)
+        let mut digits: i64 = 0;
+        while remainder != 0
+            && digits + (self.scale as i64) - (other.scale as i64) < MAX_SCALE as i64
+        {
+            let Some(next) = remainder.checked_mul(10) else {
+                break;
+            };
+            let Some(next_quotient) = quotient
+                .checked_mul(10)
+                .and_then(|q| q.checked_add(next / divisor))
+                .filter(|q| *q <= i128::MAX as u128)
+            else {
+                break;
+            };
+            quotient = next_quotient;
+            remainder = next % divisor;
+            digits += 1;
+        }
+        let negative = self.is_negative() != other.is_negative();
+        // remainder < divisor <= 2^127, so doubling can't overflow
+        let half = (remainder * 2).cmp(&divisor);
+        if RoundingMode::HalfEven.round_away(negative, remainder != 0, half, quotient % 2 == 1) {
+            quotient = quotient.checked_add(1)?;
+        }
+        let mut mantissa = i128::try_from(quotient).ok()?;
+        if negative {
+            mantissa = -mantissa;
+        }
+
+        let scale = digits + self.scale as i64 - other.scale as i64;
+        if scale < 0 {
+            u32::try_from(-scale)
+                .ok()
+                .and_then(|exp| 10i128.checked_pow(exp))
+                .and_then(|factor| mantissa.checked_mul(factor))
+                .map(|mantissa| Self::new(mantissa, 0))
+        } else {
+            Self::try_new(mantissa, scale as u32).ok()
+        }
+    }
+
+    /// `None` on overflow
+    pub fn checked_neg(&self) -> Option<Self> {
+        self.mantissa
+            .checked_neg()
+            .map(|mantissa| Self::new(mantissa, self.scale))
+    }
+
+    /// `None` on overflow
+    pub fn checked_abs(&self) -> Option<Self> {
+        self.mantissa
+            .checked_abs()
+            .map(|mantissa| Self::new(mantissa, self.scale))
+    }
+
+    /// Rounds to a multiple of 10<sup>-scale</sup>, negative scale rounds the integer part,
+    /// i.e. `-2` rounds to hundreds. `None` if the rounded value is out of range
+    pub fn round_at(&self, scale: i64, mode: RoundingMode) -> Option<Self> {
+        if scale >= self.scale as i64 {
+            return Some(*self);
+        }
+        let negative = self.is_negative();
+        let (mut quotient, inexact, half) = match u32::try_from(self.scale as i64 - scale)
+            .ok()
+            .and_then(|exp| 10i128.checked_pow(exp))
+        {
+            Some(divisor) => {
+                let remainder = (self.mantissa % divisor).unsigned_abs();
+                (
+                    self.mantissa / divisor,
+                    remainder != 0,
+                    (remainder * 2).cmp(&divisor.unsigned_abs()),
+                )
+            }
+            // the whole mantissa is less than half of the rounding unit
+            None => (0, self.mantissa != 0, Ordering::Less),
+        };
+        if mode.round_away(negative, inexact, half, quotient % 2 != 0) {
+            quotient += if negative { -1 } else { 1 };
+        }
+
+        if scale < 0 && quotient != 0 {
+            u32::try_from(-scale)
+                .ok()
+                .and_then(|exp| 10i128.checked_pow(exp))
+                .and_then(|factor| quotient.checked_mul(factor))
+                .map(|mantissa| Self::new(mantissa, 0))
+        } else {
+            Some(Self::new(quotient, scale.max(0) as u32))
+        }
+    }
+
+    pub fn as_f64(&self) -> f64 {
+        self.to_string().parse().unwrap_or(f64::NAN)
+    }
+}
+
+fn pow10(exp: u32) -> i128 {
+    10i128.pow(exp)
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // compare integer parts first, then fraction parts scaled to the same number of digits,
+        // both parts have the same sign as the number, so no overflow is possible
+        let scale = self.scale.max(other.scale);
+        let fraction = |d: &Decimal| (d.mantissa % pow10(d.scale)) * pow10(scale - d.scale);
+        self.trunc()
+            .cmp(&other.trunc())
+            .then_with(|| fraction(self).cmp(&fraction(other)))
+    }
+}
+
+impl Display for Decimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.scale == 0 {
+            return write!(f, "{}", self.mantissa);
+        }
+        if self.is_negative() {
+            f.write_str("-")?;
+        }
+        write!(
+            f,
+            "{}.{:0>width$}",
+            self.trunc().unsigned_abs(),
+            self.fraction(),
+            width = self.scale as usize
+        )
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = DecimalError;
+
+    /// Parses plain decimal numbers with optional exponent, i.e. `-12.50` or `1.5e-3`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || DecimalError::Invalid(s.to_string());
+
+        let (number, exponent) = match s.find(['e', 'E']) {
+            Some(index) => (
+                &s[..index],
+                s[index + 1..].parse::<i32>().map_err(|_| invalid())?,
+            ),
+            None => (s, 0),
+        };
+        let (negative, number) = match number.strip_prefix('-') {
+            Some(number) => (true, number),
+            None => (false, number.strip_prefix('+').unwrap_or(number)),
+        };
+        let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(invalid());
+        }
+
+        let mut mantissa: i128 = 0;
+        for c in integer.chars().chain(fraction.chars()) {
+            let digit = c.to_digit(10).ok_or_else(invalid)?;
+            mantissa = mantissa
+                .checked_mul(10)
+                .and_then(|m| m.checked_add(digit as i128))
+                .ok_or(DecimalError::OutOfRange)?;
+        }
+        if negative {
+            mantissa = -mantissa;
+        }
+
+        let scale = fraction.len() as i64 - exponent as i64;
+        if scale < 0 {
+            let mantissa = u32::try_from(-scale)
+                .ok()
+                .and_then(|exp| 10i128.checked_pow(exp))
+                .and_then(|factor| mantissa.checked_mul(factor))
+                .ok_or(DecimalError::OutOfRange)?;
+            Ok(Self::new(mantissa, 0))
+        } else {
+            u32::try_from(scale)
+                .map_err(|_| DecimalError::OutOfRange)
+                .and_then(|scale| Self::try_new(mantissa, scale))
+        }
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl From<rust_decimal::Decimal> for Decimal {
+    fn from(value: rust_decimal::Decimal) -> Self {
+        Self::new(value.mantissa(), value.scale())
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl TryFrom<&bigdecimal::BigDecimal> for Decimal {
+    type Error = DecimalError;
+
+    fn try_from(value: &bigdecimal::BigDecimal) -> Result<Self, Self::Error> {
+        use bigdecimal::ToPrimitive;
+
+        let (mantissa, exponent) = value.as_bigint_and_exponent();
+        let mantissa = mantissa.to_i128().ok_or(DecimalError::OutOfRange)?;
+        if exponent < 0 {
+            let mantissa = u32::try_from(-exponent)
+                .ok()
+                .and_then(|exp| 10i128.checked_pow(exp))
+                .and_then(|factor| mantissa.checked_mul(factor))
+                .ok_or(DecimalError::OutOfRange)?;
+            Ok(Self::new(mantissa, 0))
+        } else {
+            u32::try_from(exponent)
+                .map_err(|_| DecimalError::OutOfRange)
+                .and_then(|scale| Self::try_new(mantissa, scale))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Decimal, DecimalError, RoundingMode, MAX_SCALE};
+
+    fn decimal(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let test_data = vec![
+            ("19.99", 1999, 2, "19.99"),
+            ("-0.050", -50, 3, "-0.050"),
+            ("+7", 7, 0, "7"),
+            (".5", 5, 1, "0.5"),
+            ("1.5e-3", 15, 4, "0.0015"),
+            ("1.5E3", 1500, 0, "1500"),
+        ];
+        for (s, mantissa, scale, display) in test_data {
+            let d = decimal(s);
+            assert_eq!(mantissa, d.mantissa(), "{}", s);
+            assert_eq!(scale, d.scale(), "{}", s);
+            assert_eq!(display, d.to_string(), "{}", s);
+        }
+
+        assert!(matches!(
+            "1.2.3".parse::<Decimal>(),
+            Err(DecimalError::Invalid(_))
+        ));
+        assert!(matches!(
+            "-".parse::<Decimal>(),
+            Err(DecimalError::Invalid(_))
+        ));
+        assert_eq!(Err(DecimalError::OutOfRange), "1e40".parse::<Decimal>());
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert_eq!(decimal("1.50"), decimal("1.5"));
+        assert_eq!(decimal("0"), decimal("-0.00"));
+        assert!(decimal("-1.5") < decimal("-1.2"));
+        assert!(decimal("-0.5") < decimal("0.3"));
+        assert!(decimal("19.99") < decimal("20"));
+        assert!(decimal("0.1") > decimal("0.09999999999999999999999999999999999999"));
+    }
+
+    #[test]
+    fn test_round() {
+        let test_data = vec![
+            ("0.125", 2, "0.12"),
+            ("0.135", 2, "0.14"),
+            ("-0.125", 2, "-0.12"),
+            ("-0.1251", 2, "-0.13"),
+            ("9.999", 2, "10.00"),
+            ("1.5", 0, "2"),
+            ("2.5", 0, "2"),
+            ("1.5", 3, "1.5"),
+        ];
+        for (s, scale, expected) in test_data {
+            assert_eq!(expected, decimal(s).round(scale).to_string(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_round_increment() {
+        let test_data = vec![
+            ("1.23", 5, 2, RoundingMode::HalfExpand, "1.25"),
+            ("1.225", 5, 2, RoundingMode::HalfExpand, "1.25"),
+            ("1.225", 5, 2, RoundingMode::HalfEven, "1.20"),
+            ("1.275", 5, 2, RoundingMode::HalfEven, "1.30"),
+            ("-1.21", 5, 2, RoundingMode::Floor, "-1.25"),
+            ("1.21", 25, 2, RoundingMode::Ceil, "1.25"),
+            ("1234", 50, 0, RoundingMode::HalfExpand, "1250"),
+            ("1.3", 1, 0, RoundingMode::HalfExpand, "1"),
+        ];
+        for (s, increment, scale, mode, expected) in test_data {
+            assert_eq!(
+                expected,
+                decimal(s)
+                    .round_increment_with(increment, scale, mode)
+                    .unwrap()
+                    .to_string(),
+                "{} {:?}",
+                s,
+                mode
+            );
+        }
+    }
+
+    #[test]
+    fn test_rounding_modes() {
+        let values = [
+            "1.5", "2.5", "-1.5", "-2.5", "1.2", "-1.2", "1.7", "-1.7", "3",
+        ];
+        let test_data = vec![
+            (
+                RoundingMode::Ceil,
+                ["2", "3", "-1", "-2", "2", "-1", "2", "-1", "3"],
+            ),
+            (
+                RoundingMode::Floor,
+                ["1", "2", "-2", "-3", "1", "-2", "1", "-2", "3"],
+            ),
+            (
+                RoundingMode::Expand,
+                ["2", "3", "-2", "-3", "2", "-2", "2", "-2", "3"],
+            ),
+            (
+                RoundingMode::Trunc,
+                ["1", "2", "-1", "-2", "1", "-1", "1", "-1", "3"],
+            ),
+            (
+                RoundingMode::HalfCeil,
+                ["2", "3", "-1", "-2", "1", "-1", "2", "-2", "3"],
+            ),
+            (
+                RoundingMode::HalfFloor,
+                ["1", "2", "-2", "-3", "1", "-1", "2", "-2", "3"],
+            ),
+            (
+                RoundingMode::HalfExpand,
+                ["2", "3", "-2", "-3", "1", "-1", "2", "-2", "3"],
+            ),
+            (
+                RoundingMode::HalfTrunc,
+                ["1", "2", "-1", "-2", "1", "-1", "2", "-2", "3"],
+            ),
+            (
+                RoundingMode::HalfEven,
+                ["2", "2", "-2", "-2", "1", "-1", "2", "-2", "3"],
+            ),
+        ];
+        for (mode, expected) in test_data {
+            for (value, expected) in values.iter().zip(expected) {
+                assert_eq!(
+                    expected,
+                    decimal(value).round_with(0, mode).to_string(),
+                    "{} {:?}",
+                    value,
+                    mode
+                );
+            }
+        }
+
+        assert_eq!(
+            "0.01",
+            decimal("0.0000000000000000000000000000000000001")
+                .round_with(2, RoundingMode::Ceil)
+                .to_string()
+        );
+        assert_eq!(
+            "-1300",
+            decimal("-1234")
+                .round_significant_with(2, RoundingMode::Expand)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            Some(Decimal::new(0, 0)),
+            decimal("499").round_at(-3, RoundingMode::HalfExpand)
+        );
+        assert_eq!(
+            Some(Decimal::new(10i128.pow(38), 0)),
+            decimal("1").round_at(-38, RoundingMode::Ceil)
+        );
+        assert_eq!(None, decimal("1").round_at(-39, RoundingMode::Ceil));
+        assert_eq!(
+            None,
+            Decimal::new(i128::MAX, 0).round_significant_with(1, RoundingMode::Ceil)
+        );
+        assert_eq!(
+            Some(Decimal::new(i128::MIN / 10 * 10, 0)),
+            Decimal::new(i128::MIN, 0).round_at(-1, RoundingMode::Ceil)
+        );
+        assert_eq!(Ok(RoundingMode::HalfExpand), "halfExpand".parse());
+        assert!("half-expand".parse::<RoundingMode>().is_err());
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let add = |a: &str, b: &str| decimal(a).checked_add(&decimal(b)).map(|d| d.to_string());
+        let sub = |a: &str, b: &str| decimal(a).checked_sub(&decimal(b)).map(|d| d.to_string());
+        let mul = |a: &str, b: &str| decimal(a).checked_mul(&decimal(b)).map(|d| d.to_string());
+        let div = |a: &str, b: &str| decimal(a).checked_div(&decimal(b)).map(|d| d.to_string());
+
+        assert_eq!(Some("0.30".to_string()), add("0.1", "0.20"));
+        assert_eq!(Some("-0.05".to_string()), sub("0.15", "0.2"));
+        assert_eq!(None, add("170141183460469231731687303715884105727", "1"));
+        assert_eq!(Some("0.375".to_string()), mul("1.50", "0.25"));
+        assert_eq!(
+            None,
+            mul("0.00000000000000000001", "0.00000000000000000001")
+        );
+        assert_eq!(Some("0.25".to_string()), div("1", "4"));
+        assert_eq!(Some("2.50".to_string()), div("10.00", "4"));
+        assert_eq!(Some("400".to_string()), div("1", "0.0025"));
+        assert_eq!(Some("-2".to_string()), div("5", "-2.5"));
+        assert_eq!(
+            Some("0.66666666666666666666666666666666666667".to_string()),
+            div("2", "3")
+        );
+        assert_eq!(None, div("1", "0.0"));
+        assert_eq!(None, Decimal::new(i128::MIN, 0).checked_neg());
+    }
+
+    #[test]
+    fn test_round_significant() {
+        let test_data = vec![
+            ("123.45", 2, "120"),
+            ("12.345", 3, "12.3"),
+            ("0.001234", 3, "0.00123"),
+            ("999.9", 3, "1000"),
+            ("-999.9", 3, "-1000"),
+            ("0", 2, "0"),
+        ];
+        for (s, precision, expected) in test_data {
+            assert_eq!(
+                expected,
+                decimal(s).round_significant(precision).unwrap().to_string(),
+                "{}",
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn test_pad() {
+        assert_eq!("1.500", decimal("1.5").pad(3).unwrap().to_string());
+        assert_eq!("1.5", decimal("1.5").pad(0).unwrap().to_string());
+        assert_eq!("150", decimal("1.5").mul_pow10(2).unwrap().to_string());
+        assert_eq!("0.15", decimal("0.0015").mul_pow10(2).unwrap().to_string());
+        assert!(decimal("1").pad(MAX_SCALE + 1).is_none());
+        assert_eq!("0.012", decimal("12.000").div_pow10(3).unwrap().to_string());
+        assert!(decimal("1.5").div_pow10(MAX_SCALE).is_none());
+        assert_eq!(Some(2), decimal("123.4").magnitude());
+        assert_eq!(Some(-2), decimal("-0.050").magnitude());
+        assert_eq!(None, decimal("0.00").magnitude());
+    }
+
+    #[test]
+    fn test_from_f64() {
+        assert_eq!("19.99", Decimal::from_f64(19.99).unwrap().to_string());
+        assert_eq!(
+            "0.30000000000000004",
+            Decimal::from_f64(0.1 + 0.2).unwrap().to_string()
+        );
+        assert!(Decimal::from_f64(f64::NAN).is_none());
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_from_rust_decimal() {
+        let d = Decimal::from(rust_decimal::Decimal::new(-1250, 3));
+        assert_eq!("-1.250", d.to_string());
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    #[test]
+    fn test_from_bigdecimal() {
+        let d = Decimal::try_from(&"12.340".parse::<bigdecimal::BigDecimal>().unwrap()).unwrap();
+        assert_eq!("12.340", d.to_string());
+        let d = Decimal::try_from(&bigdecimal::BigDecimal::new(5.into(), -3)).unwrap();
+        assert_eq!("5000", d.to_string());
+        assert_eq!(
+            Err(DecimalError::OutOfRange),
+            Decimal::try_from(&"1e50".parse::<bigdecimal::BigDecimal>().unwrap())
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use std::fmt::Display;
 use std::str::FromStr;
 
+use super::RoundingMode;
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum NumberStyle {
     Decimal,
@@ -51,6 +53,17 @@ impl NumberStyle {
         }
     }
 
+    /// Minimum and maximum fraction digits used if the format doesn't set them, same as
+    /// in ECMA-402 `Intl.NumberFormat`. Formatters with currency data use fraction digits
+    /// of the currency instead of 2, e.g. 0 for JPY
+    pub fn default_fraction_digits(&self) -> (usize, usize) {
+        match self {
+            NumberStyle::Decimal | NumberStyle::Unit { .. } => (0, 3),
+            NumberStyle::Percent => (0, 0),
+            NumberStyle::Currency { .. } => (2, 2),
+        }
+    }
+
     pub fn set_currency_display_style(&mut self, new_style: CurrencyDisplayStyle) -> bool {
         if let NumberStyle::Currency { style, .. } = self {
             *style = new_style;
@@ -90,6 +103,10 @@ macro_rules! create_currency_code_enum {
             $($code),*
         }
 
+        impl CurrencyCode {
+            pub const VALUES: &'static [CurrencyCode] = &[$(CurrencyCode::$code),*];
+        }
+
         impl FromStr for CurrencyCode {
             type Err = InvalidCurrencyCode;
 
@@ -186,7 +203,7 @@ impl Default for CurrencySignMode {
 }
 
 #[derive(Debug, Clone, thiserror::Error)]
-#[error("Invalid currency sign mode: '{0}'")]
+#[error("Invalid unit identifier: '{0}'")]
 pub struct InvalidUnitIdentifierError(String);
 
 macro_rules! generate_unit_identifier {
@@ -199,6 +216,9 @@ macro_rules! generate_unit_identifier {
             }
 
             impl UnitIdentifier {
+                /// All units except derived ones
+                pub const SIMPLE: &'static [UnitIdentifier] = &[$(UnitIdentifier::[< $raw_id:camel >]),*];
+
                 pub fn per(&self, denominator: UnitIdentifier) -> UnitIdentifier {
                     Self::Derived(Box::new(self.clone()), Box::new(denominator))
                 }
@@ -346,17 +366,141 @@ impl FromStr for GroupingStyle {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Notation {
+    #[default]
+    Standard,
+    Scientific,
+    Engineering,
+    Compact,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid notation: '{0}'")]
+pub struct InvalidNotationError(String);
+
+impl FromStr for Notation {
+    type Err = InvalidNotationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "standard" => Ok(Self::Standard),
+            "scientific" => Ok(Self::Scientific),
+            "engineering" => Ok(Self::Engineering),
+            "compact" => Ok(Self::Compact),
+            _ => Err(InvalidNotationError(s.to_string())),
+        }
+    }
+}
+
+/// Style of compact notation, i.e. `1.2K` or `1.2 thousand`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CompactDisplay {
+    #[default]
+    Short,
+    Long,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid compact display: '{0}'")]
+pub struct InvalidCompactDisplayError(String);
+
+impl FromStr for CompactDisplay {
+    type Err = InvalidCompactDisplayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "short" => Ok(Self::Short),
+            "long" => Ok(Self::Long),
+            _ => Err(InvalidCompactDisplayError(s.to_string())),
+        }
+    }
+}
+
+/// When to display the sign of a number, as defined by ECMA-402 `signDisplay` option
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SignDisplay {
+    /// Negative numbers only, including negative zero
+    #[default]
+    Auto,
+    /// Both positive and negative numbers, including zero
+    Always,
+    /// Positive and negative numbers, but not zero
+    ExceptZero,
+    /// Negative numbers only, excluding negative zero
+    Negative,
+    Never,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid sign display: '{0}'")]
+pub struct InvalidSignDisplayError(String);
+
+impl FromStr for SignDisplay {
+    type Err = InvalidSignDisplayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            "exceptZero" => Ok(Self::ExceptZero),
+            "negative" => Ok(Self::Negative),
+            "never" => Ok(Self::Never),
+            _ => Err(InvalidSignDisplayError(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TrailingZeroDisplay {
+    /// Keep trailing zeros according to minimum fraction digits
+    #[default]
+    Auto,
+    /// Remove fraction zeros if the number is an integer, i.e. `$1` rather than `$1.00`
+    StripIfInteger,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid trailing zero display: '{0}'")]
+pub struct InvalidTrailingZeroDisplayError(String);
+
+impl FromStr for TrailingZeroDisplay {
+    type Err = InvalidTrailingZeroDisplayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "stripIfInteger" => Ok(Self::StripIfInteger),
+            _ => Err(InvalidTrailingZeroDisplayError(s.to_string())),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct NumberFormat {
     pub style: NumberStyle,
 
     pub use_grouping: GroupingStyle,
 
+    pub notation: Notation,
+    /// Used only with [`Notation::Compact`]
+    pub compact_display: CompactDisplay,
+
     pub minimum_integer_digits: Option<usize>,
     pub minimum_fraction_digits: Option<usize>,
     pub maximum_fraction_digits: Option<usize>,
     pub minimum_significant_digits: Option<usize>,
     pub maximum_significant_digits: Option<usize>,
+
+    /// Half-to-even by default, same as ICU. Note that ECMA-402 `Intl.NumberFormat`
+    /// (and so Fluent.js) rounds half-expand by default, e.g. with 0 maximum fraction digits
+    /// `2.5` is formatted as `3` there and as `2` here
+    pub rounding_mode: RoundingMode,
+    /// Rounds to multiples of the increment at the last fraction digit, i.e. `5` with
+    /// 2 maximum fraction digits rounds to multiples of `0.05`
+    pub rounding_increment: usize,
+    pub sign_display: SignDisplay,
+    pub trailing_zero_display: TrailingZeroDisplay,
 }
 
 impl NumberFormat {
@@ -394,11 +538,17 @@ impl Default for NumberFormat {
         Self {
             style: NumberStyle::Decimal,
             use_grouping: GroupingStyle::Auto,
+            notation: Notation::Standard,
+            compact_display: CompactDisplay::Short,
             minimum_integer_digits: None,
             minimum_fraction_digits: None,
             maximum_fraction_digits: None,
             minimum_significant_digits: None,
             maximum_significant_digits: None,
+            rounding_mode: RoundingMode::default(),
+            rounding_increment: 1,
+            sign_display: SignDisplay::Auto,
+            trailing_zero_display: TrailingZeroDisplay::Auto,
         }
     }
 }
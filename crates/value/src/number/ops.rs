@@ -0,0 +1,215 @@
+use super::{integer_to_decimal, Decimal, Exact, Number, RoundingMode};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum TryFromNumberError {
+    #[error("Number is not an integer")]
+    NotAnInteger,
+    #[error("Number is out of range")]
+    OutOfRange,
+}
+
+/// Operands promotion for arithmetic operations
+enum Operands {
+    Integer((bool, u128), (bool, u128)),
+    Decimal(Decimal, Decimal),
+    Float(f64, f64),
+}
+
+/// Arithmetic across number variants.
+///
+/// Integers stay integers, the result is the first variant of `I64`, `U64`, `I128`, `U128`
+/// that can hold the value. If either operand is a [`Decimal`] the result is exact decimal,
+/// and if either operand is a float the result is a float.
+/// All operations return `None` on overflow, division by zero or non-finite float result.
+impl Number {
+    pub fn checked_add(&self, other: &Number) -> Option<Number> {
+        match self.operands(other)? {
+            Operands::Integer(lhs, rhs) => {
+                add_sign_magnitude(lhs, rhs).and_then(from_sign_magnitude)
+            }
+            Operands::Decimal(lhs, rhs) => lhs.checked_add(&rhs).map(Number::Decimal),
+            Operands::Float(lhs, rhs) => finite(lhs + rhs),
+        }
+    }
+
+    pub fn checked_sub(&self, other: &Number) -> Option<Number> {
+        self.checked_add(&other.checked_neg()?)
+    }
+
+    pub fn checked_mul(&self, other: &Number) -> Option<Number> {
+        match self.operands(other)? {
+            Operands::Integer((n1, m1), (n2, m2)) => {
+                from_sign_magnitude((n1 != n2, m1.checked_mul(m2)?))
+            }
+            Operands::Decimal(lhs, rhs) => lhs.checked_mul(&rhs).map(Number::Decimal),
+            Operands::Float(lhs, rhs) => finite(lhs * rhs),
+        }
+    }
+
+    /// Division of integers is exact if possible, otherwise the quotient is a [`Decimal`],
+    /// see [`Decimal::checked_div`]
+    pub fn checked_div(&self, other: &Number) -> Option<Number> {
+        match self.operands(other)? {
+            Operands::Integer((n1, m1), (n2, m2)) => {
+                if m2 == 0 {
+                    None
+                } else if m1 % m2 == 0 {
+                    from_sign_magnitude((n1 != n2, m1 / m2))
+                } else {
+                    integer_to_decimal(n1, m1)?
+                        .checked_div(&integer_to_decimal(n2, m2)?)
+                        .map(Number::Decimal)
+                }
+            }
+            Operands::Decimal(lhs, rhs) => lhs.checked_div(&rhs).map(Number::Decimal),
+            Operands::Float(lhs, rhs) => finite(lhs / rhs),
+        }
+    }
+
+    pub fn checked_neg(&self) -> Option<Number> {
+        match *self {
+            Number::F64(n) => Some(Number::F64(-n)),
+            Number::Decimal(d) => d.checked_neg().map(Number::Decimal),
+            _ => match self.exact() {
+                Exact::Integer(negative, magnitude) => from_sign_magnitude((!negative, magnitude)),
+                _ => None,
+            },
+        }
+    }
+
+    /// Absolute value, integers are widened if needed, i.e. `I64(i64::MIN)` becomes `U64`
+    pub fn abs(&self) -> Number {
+        match *self {
+            Number::I64(n) => Number::from(n.unsigned_abs()),
+            Number::I128(n) => Number::from(n.unsigned_abs()),
+            Number::F64(n) => Number::F64(n.abs()),
+            Number::Decimal(d) => d
+                .checked_abs()
+                .map(Number::Decimal)
+                // only `i128::MIN` mantissa overflows
+                .unwrap_or_else(|| Number::F64(d.as_f64().abs())),
+            n => n,
+        }
+    }
+
+    /// Rounds to at most `scale` fraction digits, integers are returned as is,
+    /// floats are rounded by their shortest decimal representation, i.e. `1.005` is a tie
+    pub fn round(&self, scale: u32, mode: RoundingMode) -> Number {
+        match *self {
+            Number::Decimal(d) => Number::Decimal(d.round_with(scale, mode)),
+            Number::F64(n) => Decimal::from_f64(n)
+                .map(|d| Number::F64(d.round_with(scale, mode).as_f64().copysign(n)))
+                .unwrap_or(*self),
+            n => n,
+        }
+    }
+
+    fn operands(&self, other: &Number) -> Option<Operands> {
+        if let (Number::F64(_), _) | (_, Number::F64(_)) = (self, other) {
+            return Some(Operands::Float(self.as_f64(), other.as_f64()));
+        }
+        match (self.exact(), other.exact()) {
+            (Exact::Integer(n1, m1), Exact::Integer(n2, m2)) => {
+                Some(Operands::Integer((n1, m1), (n2, m2)))
+            }
+            (lhs, rhs) => Some(Operands::Decimal(
+                exact_to_decimal(lhs)?,
+                exact_to_decimal(rhs)?,
+            )),
+        }
+    }
+}
+
+fn exact_to_decimal(value: Exact) -> Option<Decimal> {
+    match value {
+        Exact::Integer(negative, magnitude) => integer_to_decimal(negative, magnitude),
+        Exact::Decimal(d) => Some(d),
+        Exact::Binary(_) | Exact::Float(_) => None,
+    }
+}
+
+fn finite(value: f64) -> Option<Number> {
+    value.is_finite().then_some(Number::F64(value))
+}
+
+fn add_sign_magnitude(lhs: (bool, u128), rhs: (bool, u128)) -> Option<(bool, u128)> {
+    let ((n1, m1), (n2, m2)) = (lhs, rhs);
+    if n1 == n2 {
+        m1.checked_add(m2).map(|m| (n1, m))
+    } else if m1 >= m2 {
+        Some((n1, m1 - m2))
+    } else {
+        Some((n2, m2 - m1))
+    }
+}
+
+fn from_sign_magnitude((negative, magnitude): (bool, u128)) -> Option<Number> {
+    if negative && magnitude != 0 {
+        let n = 0i128.checked_sub_unsigned(magnitude)?;
+        Some(i64::try_from(n).map(Number::I64).unwrap_or(Number::I128(n)))
+    } else if let Ok(n) = i64::try_from(magnitude) {
+        Some(Number::I64(n))
+    } else if let Ok(n) = u64::try_from(magnitude) {
+        Some(Number::U64(n))
+    } else if let Ok(n) = i128::try_from(magnitude) {
+        Some(Number::I128(n))
+    } else {
+        Some(Number::U128(magnitude))
+    }
+}
+
+macro_rules! impl_try_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl TryFrom<Number> for $t {
+                type Error = TryFromNumberError;
+
+                fn try_from(value: Number) -> Result<Self, Self::Error> {
+                    let (negative, magnitude) = value.integer_parts()?;
+                    let result = if negative {
+                        0i128
+                            .checked_sub_unsigned(magnitude)
+                            .and_then(|n| <$t>::try_from(n).ok())
+                    } else {
+                        <$t>::try_from(magnitude).ok()
+                    };
+                    result.ok_or(TryFromNumberError::OutOfRange)
+                }
+            }
+
+            impl TryFrom<&Number> for $t {
+                type Error = TryFromNumberError;
+
+                fn try_from(value: &Number) -> Result<Self, Self::Error> {
+                    <$t>::try_from(*value)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_number!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl Number {
+    fn integer_parts(&self) -> Result<(bool, u128), TryFromNumberError> {
+        match self.exact() {
+            Exact::Integer(negative, magnitude) => Ok((negative, magnitude)),
+            Exact::Decimal(d) if d.is_integer() => Ok((d.is_negative(), d.trunc().unsigned_abs())),
+            Exact::Float(n) if !n.is_nan() => Err(TryFromNumberError::OutOfRange),
+            _ => Err(TryFromNumberError::NotAnInteger),
+        }
+    }
+}
+
+/// Nearest float, precision may be lost
+impl From<Number> for f64 {
+    fn from(value: Number) -> Self {
+        value.as_f64()
+    }
+}
+
+impl From<&Number> for f64 {
+    fn from(value: &Number) -> Self {
+        value.as_f64()
+    }
+}
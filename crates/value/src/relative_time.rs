@@ -0,0 +1,237 @@
+use std::{fmt::Display, str::FromStr, time::SystemTime};
+
+/// Relative time formatting options of the `RELATIVE_TIME` function
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct RelativeTimeFormat {
+    pub unit: RelativeTimeUnit,
+    pub numeric: RelativeTimeNumeric,
+    pub style: RelativeTimeStyle,
+}
+
+impl RelativeTimeFormat {
+    pub fn new(unit: RelativeTimeUnit) -> Self {
+        Self {
+            unit,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum RelativeTimeUnit {
+    #[default]
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+const MINUTE: f64 = 60.0;
+const HOUR: f64 = 60.0 * MINUTE;
+const DAY: f64 = 24.0 * HOUR;
+const WEEK: f64 = 7.0 * DAY;
+const MONTH: f64 = 30.0 * DAY;
+const YEAR: f64 = 365.0 * DAY;
+
+impl RelativeTimeUnit {
+    pub const VALUES: &'static [RelativeTimeUnit] = &[
+        RelativeTimeUnit::Second,
+        RelativeTimeUnit::Minute,
+        RelativeTimeUnit::Hour,
+        RelativeTimeUnit::Day,
+        RelativeTimeUnit::Week,
+        RelativeTimeUnit::Month,
+        RelativeTimeUnit::Quarter,
+        RelativeTimeUnit::Year,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RelativeTimeUnit::Second => "second",
+            RelativeTimeUnit::Minute => "minute",
+            RelativeTimeUnit::Hour => "hour",
+            RelativeTimeUnit::Day => "day",
+            RelativeTimeUnit::Week => "week",
+            RelativeTimeUnit::Month => "month",
+            RelativeTimeUnit::Quarter => "quarter",
+            RelativeTimeUnit::Year => "year",
+        }
+    }
+
+    /// Unit symbol of CLDR root relative time patterns, i.e. `d` in `+{0} d`
+    pub fn root_symbol(&self) -> &'static str {
+        match self {
+            RelativeTimeUnit::Second => "s",
+            RelativeTimeUnit::Minute => "min",
+            RelativeTimeUnit::Hour => "h",
+            RelativeTimeUnit::Day => "d",
+            RelativeTimeUnit::Week => "w",
+            RelativeTimeUnit::Month => "m",
+            RelativeTimeUnit::Quarter => "Q",
+            RelativeTimeUnit::Year => "y",
+        }
+    }
+
+    /// Selects the largest unit for the offset in seconds, months and years are approximated
+    /// as 30 and 365 days. Returns the offset rounded to whole units
+    pub fn best_fit(seconds: f64) -> (i64, RelativeTimeUnit) {
+        let (unit, unit_seconds) = match seconds.abs() {
+            s if s < MINUTE => (RelativeTimeUnit::Second, 1.0),
+            s if s < HOUR => (RelativeTimeUnit::Minute, MINUTE),
+            s if s < DAY => (RelativeTimeUnit::Hour, HOUR),
+            s if s < WEEK => (RelativeTimeUnit::Day, DAY),
+            s if s < MONTH => (RelativeTimeUnit::Week, WEEK),
+            s if s < YEAR => (RelativeTimeUnit::Month, MONTH),
+            _ => (RelativeTimeUnit::Year, YEAR),
+        };
+        ((seconds / unit_seconds).round() as i64, unit)
+    }
+}
+
+impl Display for RelativeTimeUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid relative time unit: '{0}'")]
+pub struct InvalidRelativeTimeUnitError(String);
+
+/// Accepts both singular and plural unit names, e.g. `day` and `days`
+impl FromStr for RelativeTimeUnit {
+    type Err = InvalidRelativeTimeUnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unit = s.to_lowercase();
+        let unit = unit.strip_suffix('s').unwrap_or(&unit);
+        RelativeTimeUnit::VALUES
+            .iter()
+            .find(|value| value.as_str() == unit)
+            .copied()
+            .ok_or_else(|| InvalidRelativeTimeUnitError(s.to_string()))
+    }
+}
+
+/// `Auto` allows phrases like "yesterday" or "next week" instead of "1 day ago" or "in 1 week"
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum RelativeTimeNumeric {
+    #[default]
+    Always,
+    Auto,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid relative time numeric option: '{0}'")]
+pub struct InvalidRelativeTimeNumericError(String);
+
+impl FromStr for RelativeTimeNumeric {
+    type Err = InvalidRelativeTimeNumericError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "always" => Ok(RelativeTimeNumeric::Always),
+            "auto" => Ok(RelativeTimeNumeric::Auto),
+            _ => Err(InvalidRelativeTimeNumericError(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum RelativeTimeStyle {
+    #[default]
+    Long,
+    Short,
+    Narrow,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid relative time style: '{0}'")]
+pub struct InvalidRelativeTimeStyleError(String);
+
+impl FromStr for RelativeTimeStyle {
+    type Err = InvalidRelativeTimeStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "long" => Ok(RelativeTimeStyle::Long),
+            "short" => Ok(RelativeTimeStyle::Short),
+            "narrow" => Ok(RelativeTimeStyle::Narrow),
+            _ => Err(InvalidRelativeTimeStyleError(s.to_string())),
+        }
+    }
+}
+
+/// Returns offset of the timestamp from now in seconds, negative for timestamps in the past
+pub fn seconds_from_now(timestamp: SystemTime) -> f64 {
+    let now = SystemTime::now();
+    match timestamp.duration_since(now) {
+        Ok(duration) => duration.as_secs_f64(),
+        Err(err) => -err.duration().as_secs_f64(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::RelativeTimeUnit;
+
+    #[test]
+    fn test_unit_from_str() {
+        assert_eq!(
+            RelativeTimeUnit::Day,
+            RelativeTimeUnit::from_str("day").unwrap()
+        );
+        assert_eq!(
+            RelativeTimeUnit::Day,
+            RelativeTimeUnit::from_str("days").unwrap()
+        );
+        assert_eq!(
+            RelativeTimeUnit::Quarter,
+            RelativeTimeUnit::from_str("Quarters").unwrap()
+        );
+        assert!(RelativeTimeUnit::from_str("decade").is_err());
+        assert!(RelativeTimeUnit::from_str("s").is_err());
+    }
+
+    #[test]
+    fn test_best_fit() {
+        assert_eq!(
+            (0, RelativeTimeUnit::Second),
+            RelativeTimeUnit::best_fit(0.0)
+        );
+        assert_eq!(
+            (-45, RelativeTimeUnit::Second),
+            RelativeTimeUnit::best_fit(-45.0)
+        );
+        assert_eq!(
+            (3, RelativeTimeUnit::Minute),
+            RelativeTimeUnit::best_fit(180.0)
+        );
+        assert_eq!(
+            (-2, RelativeTimeUnit::Hour),
+            RelativeTimeUnit::best_fit(-7200.0)
+        );
+        assert_eq!(
+            (2, RelativeTimeUnit::Day),
+            RelativeTimeUnit::best_fit(2.0 * 86400.0)
+        );
+        assert_eq!(
+            (3, RelativeTimeUnit::Week),
+            RelativeTimeUnit::best_fit(21.0 * 86400.0)
+        );
+        assert_eq!(
+            (-4, RelativeTimeUnit::Month),
+            RelativeTimeUnit::best_fit(-120.0 * 86400.0)
+        );
+        assert_eq!(
+            (2, RelativeTimeUnit::Year),
+            RelativeTimeUnit::best_fit(730.0 * 86400.0)
+        );
+    }
+}